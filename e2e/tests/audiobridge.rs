@@ -309,12 +309,8 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
 
         assert_eq!(room, room_id, "Eve should join correct room");
         assert_eq!(participants.len(), 2, "Alice and Bob should be in room");
-        assert_eq!(
-            participants.contains(&alice),
-            true,
-            "Alice should be in room"
-        );
-        assert_eq!(participants.contains(&bob), true, "Bob should be in room");
+        assert!(participants.contains(&alice), "Alice should be in room");
+        assert!(participants.contains(&bob), "Bob should be in room");
 
         AudioBridgeParticipant {
             id,
@@ -386,13 +382,12 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             panic!("Alice received unexpected event")
         };
 
-        assert_eq!(
+        assert!(
             participants
                 .iter()
                 .find(|p| p.id == alice.id)
                 .expect("Alice not found")
-                .muted,
-            true
+                .muted
         );
 
         // Bob should receive the mute event of Alice
@@ -407,13 +402,12 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             panic!("Bob received unexpected event")
         };
 
-        assert_eq!(
+        assert!(
             participants
                 .iter()
                 .find(|p| p.id == alice.id)
                 .expect("Alice not found")
-                .muted,
-            true
+                .muted
         );
 
         // Eve should receive the mute event of Alice
@@ -428,13 +422,12 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             panic!("Eve received unexpected event")
         };
 
-        assert_eq!(
+        assert!(
             participants
                 .iter()
                 .find(|p| p.id == alice.id)
                 .expect("Alice not found")
-                .muted,
-            true
+                .muted
         );
     }
 
@@ -460,13 +453,12 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             panic!("Alice received unexpected event")
         };
 
-        assert_eq!(
-            participants
+        assert!(
+            !participants
                 .iter()
                 .find(|p| p.id == alice.id)
                 .expect("Alice not found")
-                .muted,
-            false
+                .muted
         );
 
         // Bob should receive the unmute event of Alice
@@ -481,13 +473,12 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             panic!("Bob received unexpected event")
         };
 
-        assert_eq!(
-            participants
+        assert!(
+            !participants
                 .iter()
                 .find(|p| p.id == alice.id)
                 .expect("Alice not found")
-                .muted,
-            false
+                .muted
         );
 
         // Eve should receive the unmute event of Alice
@@ -502,13 +493,12 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             panic!("Eve received unexpected event")
         };
 
-        assert_eq!(
-            participants
+        assert!(
+            !participants
                 .iter()
                 .find(|p| p.id == alice.id)
                 .expect("Alice not found")
-                .muted,
-            false
+                .muted
         );
     }
 
@@ -544,7 +534,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             .find(|p| p.id == eve.id)
             .expect("Eve not found");
 
-        assert_eq!(eve.muted, true);
+        assert!(eve.muted);
         assert_eq!(eve.display, Some(new_display.clone()));
 
         // Bob should receive the mute event of Eve
@@ -564,7 +554,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             .find(|p| p.id == eve.id)
             .expect("Eve not found");
 
-        assert_eq!(eve.muted, true);
+        assert!(eve.muted);
         assert_eq!(eve.display, Some(new_display.clone()));
 
         // Eve should not receive muted event, instead it receives `"result": "ok"`
@@ -597,7 +587,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
         else {
             panic!("Alice received unexpected event")
         };
-        assert_eq!(muted, true);
+        assert!(muted);
 
         // Bob should receive the mute event of all participants
         let PluginEvent::AudioBridgeEvent(AudioBridgeEvent::RoomMuteUpdated { muted, .. }) =
@@ -608,7 +598,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
         else {
             panic!("Bob received unexpected event")
         };
-        assert_eq!(muted, true);
+        assert!(muted);
 
         // Eve should receive the mute event of all participants
         let PluginEvent::AudioBridgeEvent(AudioBridgeEvent::RoomMuteUpdated { muted, .. }) =
@@ -619,7 +609,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
         else {
             panic!("Eve received unexpected event")
         };
-        assert_eq!(muted, true);
+        assert!(muted);
     }
 
     'unmute_room: {
@@ -640,7 +630,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
         else {
             panic!("Alice received unexpected event")
         };
-        assert_eq!(muted, false);
+        assert!(!muted);
 
         // Bob should receive the unmute event of all participants
         let PluginEvent::AudioBridgeEvent(AudioBridgeEvent::RoomMuteUpdated { muted, .. }) =
@@ -651,7 +641,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
         else {
             panic!("Bob received unexpected event")
         };
-        assert_eq!(muted, false);
+        assert!(!muted);
 
         // Eve should receive the unmute event of all participants
         let PluginEvent::AudioBridgeEvent(AudioBridgeEvent::RoomMuteUpdated { muted, .. }) =
@@ -662,7 +652,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
         else {
             panic!("Eve received unexpected event")
         };
-        assert_eq!(muted, false);
+        assert!(!muted);
     }
 
     'list_participants: {
@@ -678,15 +668,10 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             .participants;
 
         assert_eq!(participants.len(), 3);
-        assert_eq!(
-            participants.contains(&alice),
-            true,
-            "Alice should be in room"
-        );
-        assert_eq!(participants.contains(&bob), true, "Bob should be in room");
-        assert_eq!(
+        assert!(participants.contains(&alice), "Alice should be in room");
+        assert!(participants.contains(&bob), "Bob should be in room");
+        assert!(
             participants.iter().any(|p| p.id == eve.id),
-            true,
             "Eve should be in room"
         );
     }
@@ -794,7 +779,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             .await
             .expect("Failed to list participants");
         assert_eq!(participants.participants.len(), 2);
-        assert_eq!(participants.participants.contains(&bob), false);
+        assert!(!participants.participants.contains(&bob));
     }
 
     // Bob rejoins
@@ -894,7 +879,7 @@ async fn audiobridge_participants_e2e(#[case] testing_env: TestingEnv) {
             .await
             .expect("Failed to list participants");
         assert_eq!(participants.participants.len(), 2);
-        assert_eq!(participants.participants.contains(&bob), false);
+        assert!(!participants.participants.contains(&bob));
     }
 
     // kick_all is only available in janus multistream
@@ -945,6 +930,10 @@ async fn make_audiobridge_attachment(
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection =
         jarust::core::connect(config, testing_env.api(), RandomTransactionGenerator)
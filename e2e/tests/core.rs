@@ -25,6 +25,10 @@ async fn core_test(#[case] testing_env: TestingEnv) {
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection = connect(config, testing_env.api(), RandomTransactionGenerator)
         .await
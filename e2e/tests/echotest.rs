@@ -21,6 +21,10 @@ async fn echotest_e2e(#[case] testing_env: TestingEnv) {
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection =
         jarust::core::connect(config, testing_env.api(), RandomTransactionGenerator)
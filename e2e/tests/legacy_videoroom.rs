@@ -69,6 +69,10 @@ async fn make_legacy_videoroom_attachment(
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection =
         jarust::core::connect(config, testing_env.api(), RandomTransactionGenerator)
@@ -39,12 +39,8 @@ async fn streaming_crud_e2e(#[case] testing_env: TestingEnv) {
         let mp_id = handle
             .create_mountpoint(
                 StreamingCreateParams {
-                    mountpoint_type: StreamingMountpointType::RTP,
-                    optional: StreamingCreateParamsOptional {
-                        id: Some(stream_id.clone()),
-                        name: Some(String::from("stream name")),
-                        description: Some(String::from("stream description")),
-                        media: Some(vec![StreamingRtpMedia {
+                    mountpoint: StreamingMountpoint::Rtp(StreamingRtpParams {
+                        media: vec![StreamingRtpMedia {
                             required: StreamingRtpMediaRequired {
                                 media_type: StreamingRtpMediaType::VIDEO,
                                 mid: String::from("v"),
@@ -55,7 +51,12 @@ async fn streaming_crud_e2e(#[case] testing_env: TestingEnv) {
                                 codec: Some(String::from("vp8")),
                                 ..Default::default()
                             },
-                        }]),
+                        }],
+                    }),
+                    optional: StreamingCreateParamsOptional {
+                        id: Some(stream_id.clone()),
+                        name: Some(String::from("stream name")),
+                        description: Some(String::from("stream description")),
                         ..Default::default()
                     },
                 },
@@ -114,6 +115,10 @@ async fn make_streaming_attachment(
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection =
         jarust::core::connect(config, testing_env.api(), RandomTransactionGenerator)
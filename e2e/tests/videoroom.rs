@@ -8,14 +8,24 @@ use jarust::plugins::video_room::events::PluginEvent;
 use jarust::plugins::video_room::events::VideoRoomEvent;
 use jarust::plugins::video_room::handle::VideoRoomHandle;
 use jarust::plugins::video_room::jahandle_ext::VideoRoom;
+use jarust::plugins::video_room::params::VideoRoomAllowedAction;
+use jarust::plugins::video_room::params::VideoRoomAllowedParams;
 use jarust::plugins::video_room::params::VideoRoomDestroyParams;
 use jarust::plugins::video_room::params::VideoRoomEditParams;
 use jarust::plugins::video_room::params::VideoRoomEditParamsOptional;
+use jarust::plugins::video_room::params::VideoRoomEnableRecordingParams;
 use jarust::plugins::video_room::params::VideoRoomExistsParams;
+use jarust::plugins::video_room::params::VideoRoomKickParams;
+use jarust::plugins::video_room::params::VideoRoomListForwardersParams;
+use jarust::plugins::video_room::params::VideoRoomListParticipantsParams;
 use jarust::plugins::video_room::params::VideoRoomPublisherConfigureParams;
 use jarust::plugins::video_room::params::VideoRoomPublisherJoinAndConfigureParams;
 use jarust::plugins::video_room::params::VideoRoomPublisherJoinParams;
 use jarust::plugins::video_room::params::VideoRoomPublisherJoinParamsOptional;
+use jarust::plugins::video_room::params::VideoRoomSubscriberJoinParams;
+use jarust::plugins::video_room::params::VideoRoomSubscriberJoinParamsOptional;
+use jarust::plugins::video_room::params::VideoRoomSubscriberJoinStream;
+use jarust::plugins::video_room::params::VideoRoomSubscriberJoinStreamOptional;
 use jarust::plugins::video_room::responses::VideoRoomParticipant;
 use jarust::plugins::JanusId;
 use rstest::*;
@@ -135,6 +145,71 @@ async fn videoroom_room_crud_e2e(#[case] testing_env: TestingEnv) {
         assert!(exists, "Room should exist after setting to private");
     }
 
+    'recording: {
+        handle
+            .enable_recording(
+                VideoRoomEnableRecordingParams {
+                    room: room_id.clone(),
+                    record: true,
+                    secret: None,
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Failed to enable recording; recording");
+        handle
+            .enable_recording(
+                VideoRoomEnableRecordingParams {
+                    room: room_id.clone(),
+                    record: false,
+                    secret: None,
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Failed to disable recording; recording");
+    }
+
+    'allowed: {
+        let allowed = handle
+            .allowed(
+                VideoRoomAllowedParams {
+                    room: room_id.clone(),
+                    action: VideoRoomAllowedAction::Add,
+                    allowed: vec!["token".to_string()],
+                    secret: None,
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Failed to add allowed token; allowed");
+        assert_eq!(
+            allowed.room, room_id,
+            "Allowed response should be for the room"
+        );
+        assert!(
+            allowed.allowed.contains(&"token".to_string()),
+            "Token should be in the allowed list"
+        );
+    }
+
+    'forwarders: {
+        let forwarders = handle
+            .list_forwarders(
+                VideoRoomListForwardersParams {
+                    room: room_id.clone(),
+                    secret: None,
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Failed to list forwarders; forwarders");
+        assert!(
+            forwarders.publishers.is_empty(),
+            "Room without publishers should have no forwarders"
+        );
+    }
+
     'destroy: {
         handle
             .destroy_room(
@@ -170,6 +245,7 @@ async fn videoroom_participants_e2e(#[case] testing_env: TestingEnv) {
     let (alice_handle, mut alice_events) = make_videoroom_attachment(testing_env).await;
     let (bob_handle, mut bob_events) = make_videoroom_attachment(testing_env).await;
     let (eve_handle, mut eve_events) = make_videoroom_attachment(testing_env).await;
+    let (subscriber_handle, mut subscriber_events) = make_videoroom_attachment(testing_env).await;
 
     admin
         .create_room(Some(room_id.clone()), default_timeout)
@@ -177,7 +253,7 @@ async fn videoroom_participants_e2e(#[case] testing_env: TestingEnv) {
         .expect("Admin failed to create room; creation");
 
     // Alice joins the room
-    let _alice = {
+    let alice = {
         let display = Some("Alice".to_string());
         alice_handle
             .publisher_join_and_configure(
@@ -226,7 +302,7 @@ async fn videoroom_participants_e2e(#[case] testing_env: TestingEnv) {
     };
 
     // Bob joins the room
-    let _bob = {
+    let bob = {
         let display = Some("Bob".to_string());
         bob_handle
             .publisher_join_and_configure(
@@ -275,7 +351,7 @@ async fn videoroom_participants_e2e(#[case] testing_env: TestingEnv) {
     };
 
     // Eve joins the room
-    let _eve = {
+    let eve = {
         let display = Some("Eve".to_string());
         eve_handle
             .join_as_publisher(
@@ -315,6 +391,90 @@ async fn videoroom_participants_e2e(#[case] testing_env: TestingEnv) {
             talking: Some(false),
         }
     };
+
+    'subscribe_to_inactive_feed: {
+        subscriber_handle
+            .join_as_subscriber(
+                VideoRoomSubscriberJoinParams {
+                    room: room_id.clone(),
+                    optional: VideoRoomSubscriberJoinParamsOptional {
+                        use_msid: Some(true),
+                        autoupdate: Some(false),
+                        streams: Some(vec![VideoRoomSubscriberJoinStream {
+                            feed: alice.id.clone(),
+                            optional: VideoRoomSubscriberJoinStreamOptional::default(),
+                        }]),
+                        ..Default::default()
+                    },
+                },
+                None,
+                default_timeout,
+            )
+            .await
+            .expect("Subscriber failed to send join; subscribe_to_inactive_feed");
+
+        let event = subscriber_events
+            .recv()
+            .await
+            .expect("Subscriber failed to receive event; subscribe_to_inactive_feed");
+        assert!(
+            matches!(
+                event,
+                PluginEvent::VideoRoomEvent(VideoRoomEvent::Error { .. })
+            ),
+            "Subscribing to a feed that isn't publishing should fail"
+        );
+    }
+
+    'list_participants: {
+        let participants = admin
+            .list_participants(
+                VideoRoomListParticipantsParams {
+                    room: room_id.clone(),
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Admin failed to list participants; list_participants")
+            .participants;
+        assert_eq!(participants.len(), 3, "Room should have 3 participants");
+        for participant in [&alice, &bob, &eve] {
+            assert!(
+                participants
+                    .iter()
+                    .any(|p| p.id == participant.id && p.display == participant.display),
+                "Participant should be listed"
+            );
+        }
+    }
+
+    'kick: {
+        admin
+            .kick(
+                VideoRoomKickParams {
+                    room: room_id.clone(),
+                    participant: eve.id.clone(),
+                    secret: None,
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Admin failed to kick Eve; kick");
+        let participants = admin
+            .list_participants(
+                VideoRoomListParticipantsParams {
+                    room: room_id.clone(),
+                },
+                default_timeout,
+            )
+            .await
+            .expect("Admin failed to list participants; kick")
+            .participants;
+        assert!(
+            !participants.iter().any(|p| p.id == eve.id),
+            "Eve should not be listed after being kicked"
+        );
+    }
 }
 
 async fn make_videoroom_attachment(
@@ -325,6 +485,10 @@ async fn make_videoroom_attachment(
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection =
         jarust::core::connect(config, testing_env.api(), RandomTransactionGenerator)
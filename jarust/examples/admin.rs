@@ -0,0 +1,42 @@
+use jarust::core::connect_admin;
+use jarust::core::jaconfig::JaAdminConfig;
+use jarust::core::jaconfig::JanusAPI;
+use jarust::interface::tgenerator::RandomTransactionGenerator;
+use std::path::Path;
+use std::time::Duration;
+use tracing_subscriber::EnvFilter;
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() -> anyhow::Result<()> {
+    let filename = Path::new(file!()).file_stem().unwrap().to_str().unwrap();
+    let env_filter = EnvFilter::from_default_env()
+        .add_directive("jarust_core=trace".parse()?)
+        .add_directive(format!("{filename}=trace").parse()?);
+    tracing_subscriber::fmt().with_env_filter(env_filter).init();
+
+    let config = JaAdminConfig {
+        url: "ws://localhost:7188/ws".to_string(),
+        admin_secret: Some("janusoverlord".to_string()),
+        server_root: "admin".to_string(),
+        capacity: 32,
+        tls: None,
+        transport: None,
+    };
+    let admin = connect_admin(config, JanusAPI::WebSocket, RandomTransactionGenerator).await?;
+    let timeout = Duration::from_secs(10);
+
+    tracing::info!("status: {:#?}", admin.get_status(timeout).await?);
+
+    let sessions = admin.list_sessions(timeout).await?.sessions;
+    for session_id in sessions {
+        let handles = admin.list_handles(session_id, timeout).await?.handles;
+        for handle_id in handles {
+            let info = admin
+                .handle_info(session_id, handle_id, false, timeout)
+                .await?;
+            tracing::info!("handle info: {:#?}", info);
+        }
+    }
+
+    Ok(())
+}
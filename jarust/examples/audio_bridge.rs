@@ -28,6 +28,10 @@ async fn main() -> anyhow::Result<()> {
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection = connect(config, JanusAPI::WebSocket, RandomTransactionGenerator).await?;
     let session = connection
@@ -16,6 +16,10 @@ async fn main() -> anyhow::Result<()> {
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let tgenerator = || uuid::Uuid::new_v4().to_string();
     let mut connection = connect(config, JanusAPI::WebSocket, tgenerator).await?;
@@ -26,6 +26,10 @@ async fn main() -> anyhow::Result<()> {
         apisecret: None,
         server_root: "janus".to_string(),
         capacity: 32,
+        reconnect: None,
+        tls: None,
+        transport: None,
+        token: None,
     };
     let mut connection = connect(config, JanusAPI::WebSocket, RandomTransactionGenerator).await?;
     let session = connection
@@ -42,12 +46,8 @@ async fn main() -> anyhow::Result<()> {
     let mountpoint_id = handle
         .create_mountpoint(
             StreamingCreateParams {
-                mountpoint_type: StreamingMountpointType::RTP,
-                optional: StreamingCreateParamsOptional {
-                    id: Some(JanusId::Uint(1337.into())),
-                    name: Some(String::from("stream name")),
-                    description: Some(String::from("stream description")),
-                    media: Some(vec![StreamingRtpMedia {
+                mountpoint: StreamingMountpoint::Rtp(StreamingRtpParams {
+                    media: vec![StreamingRtpMedia {
                         required: StreamingRtpMediaRequired {
                             media_type: StreamingRtpMediaType::VIDEO,
                             mid: String::from("v"),
@@ -58,7 +58,12 @@ async fn main() -> anyhow::Result<()> {
                             codec: Some(String::from("vp8")),
                             ..Default::default()
                         },
-                    }]),
+                    }],
+                }),
+                optional: StreamingCreateParamsOptional {
+                    id: Some(JanusId::Uint(1337.into())),
+                    name: Some(String::from("stream name")),
+                    description: Some(String::from("stream description")),
                     ..Default::default()
                 },
             },
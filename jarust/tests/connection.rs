@@ -24,6 +24,10 @@ mod tests {
             capacity: 10,
             apisecret: None,
             server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
         };
         let transaction_generator = MockGenerateTransaction::new();
         let interface = MockInterface::make_interface(conn_params, transaction_generator)
@@ -40,6 +44,10 @@ mod tests {
             capacity: 10,
             apisecret: None,
             server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
         };
         let transaction_generator = MockGenerateTransaction::new();
         let interface = MockInterface::make_interface(conn_params, transaction_generator)
@@ -71,6 +79,10 @@ mod tests {
             capacity: 10,
             apisecret: None,
             server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
         };
         let transaction_generator = MockGenerateTransaction::new();
         let interface = MockInterface::make_interface(conn_params, transaction_generator)
@@ -128,6 +140,10 @@ mod tests {
             capacity: 10,
             apisecret: None,
             server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
         };
         let transaction_generator = MockGenerateTransaction::new();
         let interface = MockInterface::make_interface(conn_params, transaction_generator)
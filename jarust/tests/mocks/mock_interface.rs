@@ -4,8 +4,12 @@ use jarust::core::GenerateTransaction;
 use jarust::interface::error::Error;
 use jarust::interface::handle_msg::HandleMessage;
 use jarust::interface::handle_msg::HandleMessageWithJsep;
+use jarust::interface::janus_interface::AttachParams;
 use jarust::interface::janus_interface::ConnectionParams;
 use jarust::interface::janus_interface::JanusInterface;
+use jarust::interface::japrotocol::ErrorResponse;
+use jarust::interface::japrotocol::GenericEvent;
+use jarust::interface::japrotocol::JaHandleEvent;
 use jarust::interface::japrotocol::JaSuccessProtocol;
 use jarust::interface::japrotocol::ResponseType;
 use jarust::interface::japrotocol::ServerInfoRsp;
@@ -22,6 +26,7 @@ pub struct Exclusive {
     create_rsp: Option<JaResponse>,
     attach_rsp: Option<JaResponse>,
     server_info_rsp: Option<ServerInfoRsp>,
+    keep_alive_error: Option<ErrorResponse>,
     handles_rx: HashMap<u64, UnboundedSender<JaResponse>>,
 }
 
@@ -49,6 +54,10 @@ impl MockInterface {
         self.inner.exclusive.lock().await.server_info_rsp = Some(rsp);
     }
 
+    pub async fn mock_keep_alive_error(&self, error: ErrorResponse) {
+        self.inner.exclusive.lock().await.keep_alive_error = Some(error);
+    }
+
     pub async fn mock_event(&self, handle_id: u64, rsp: JaResponse) {
         if let Some(tx) = self.inner.exclusive.lock().await.handles_rx.get(&handle_id) {
             tx.send(rsp).unwrap();
@@ -106,8 +115,9 @@ impl JanusInterface for MockInterface {
 
     async fn attach(
         &self,
-        _session_id: u64,
+        session_id: u64,
         _plugin_id: String,
+        _params: AttachParams,
         _timeout: Duration,
     ) -> Result<(u64, mpsc::UnboundedReceiver<JaResponse>), jarust::interface::Error> {
         let Some(rsp) = self.inner.exclusive.lock().await.attach_rsp.clone() else {
@@ -128,14 +138,8 @@ impl JanusInterface for MockInterface {
                 return Err(Error::UnexpectedResponse);
             }
         };
-        let (tx, rx) = mpsc::unbounded_channel();
-        self.inner
-            .exclusive
-            .lock()
-            .await
-            .handles_rx
-            .insert(handle_id, tx);
-        Ok((handle_id, rx))
+        let receiver = self.register_handle(session_id, handle_id).await;
+        Ok((handle_id, receiver))
     }
 
     fn has_keep_alive(&self) -> bool {
@@ -147,7 +151,13 @@ impl JanusInterface for MockInterface {
         _session_id: u64,
         _timeout: Duration,
     ) -> Result<(), jarust::interface::Error> {
-        todo!("Keep alive is not implemented");
+        match self.inner.exclusive.lock().await.keep_alive_error.clone() {
+            Some(error) => Err(Error::JanusError {
+                code: error.code,
+                reason: error.reason,
+            }),
+            None => Ok(()),
+        }
     }
 
     async fn destroy(
@@ -158,6 +168,29 @@ impl JanusInterface for MockInterface {
         todo!("Destroy is not implemented");
     }
 
+    async fn claim(
+        &self,
+        _session_id: u64,
+        _timeout: Duration,
+    ) -> Result<(), jarust::interface::Error> {
+        Ok(())
+    }
+
+    async fn register_handle(
+        &self,
+        _session_id: u64,
+        handle_id: u64,
+    ) -> mpsc::UnboundedReceiver<JaResponse> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        self.inner
+            .exclusive
+            .lock()
+            .await
+            .handles_rx
+            .insert(handle_id, tx);
+        rx
+    }
+
     async fn fire_and_forget_msg(
         &self,
         _message: HandleMessage,
@@ -207,4 +240,18 @@ impl JanusInterface for MockInterface {
     ) -> Result<String, Error> {
         todo!("Send handle request and waiting on ack is not implemented");
     }
+
+    async fn notify_session_expired(&self, session_id: u64) {
+        for tx in self.inner.exclusive.lock().await.handles_rx.values() {
+            let _ = tx.send(JaResponse {
+                janus: ResponseType::Event(JaHandleEvent::GenericEvent(
+                    GenericEvent::SessionExpired,
+                )),
+                transaction: None,
+                session_id: Some(session_id),
+                sender: None,
+                jsep: None,
+            });
+        }
+    }
 }
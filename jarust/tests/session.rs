@@ -6,12 +6,16 @@ mod tests {
     use crate::mocks::mock_generate_transaction::MockGenerateTransaction;
     use crate::mocks::mock_interface::MockInterface;
     use jarust::core::custom_connect;
+    use jarust::core::jaconfig::KeepAlivePolicy;
     use jarust::core::prelude::Attach;
+    use jarust::core::prelude::AttachParams;
     use jarust::core::prelude::JaResponse;
     use jarust::interface::janus_interface::ConnectionParams;
     use jarust::interface::janus_interface::JanusInterface;
     use jarust::interface::japrotocol::ErrorResponse;
+    use jarust::interface::japrotocol::GenericEvent;
     use jarust::interface::japrotocol::JaData;
+    use jarust::interface::japrotocol::JaHandleEvent;
     use jarust::interface::japrotocol::JaSuccessProtocol;
     use jarust::interface::japrotocol::ResponseType;
     use std::time::Duration;
@@ -23,6 +27,10 @@ mod tests {
             capacity: 10,
             apisecret: None,
             server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
         };
         let transaction_generator = MockGenerateTransaction::new();
         let interface = MockInterface::make_interface(conn_params, transaction_generator)
@@ -65,6 +73,121 @@ mod tests {
             .unwrap();
     }
 
+    #[tokio::test]
+    async fn it_keeps_the_attach_params_on_the_handle() {
+        let conn_params = ConnectionParams {
+            url: "mock://some.janus.com".to_string(),
+            capacity: 10,
+            apisecret: None,
+            server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
+        };
+        let transaction_generator = MockGenerateTransaction::new();
+        let interface = MockInterface::make_interface(conn_params, transaction_generator)
+            .await
+            .unwrap();
+        let mut connection = custom_connect(interface.clone()).await.unwrap();
+
+        let session_id = 73;
+
+        let response = JaResponse {
+            janus: ResponseType::Success(JaSuccessProtocol::Data {
+                data: JaData { id: session_id },
+            }),
+            transaction: Some("abc123".to_string()),
+            session_id: None,
+            sender: None,
+            jsep: None,
+        };
+        interface.mock_create_rsp(response).await;
+
+        let session = connection
+            .create_session(10, Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        let response = JaResponse {
+            janus: ResponseType::Success(JaSuccessProtocol::Data {
+                data: JaData { id: 3 },
+            }),
+            transaction: Some("mock-attach-plugin-transaction".to_string()),
+            session_id: Some(session_id),
+            sender: None,
+            jsep: None,
+        };
+        interface.mock_attach_rsp(response).await;
+
+        let params = AttachParams {
+            opaque_id: Some("echotest-a1b2c3".to_string()),
+            loop_index: Some(1),
+            force_bundle: true,
+            force_rtcp_mux: false,
+        };
+        let (handle, _) = session
+            .attach_with_params(
+                "mock.plugin.test".to_string(),
+                params.clone(),
+                Duration::from_secs(5),
+            )
+            .await
+            .unwrap();
+        assert_eq!(handle.opaque_id(), Some("echotest-a1b2c3"));
+        assert_eq!(handle.attach_params(), &params);
+    }
+
+    #[tokio::test]
+    async fn it_rebuilds_session_and_handle_from_ids() {
+        let conn_params = ConnectionParams {
+            url: "mock://some.janus.com".to_string(),
+            capacity: 10,
+            apisecret: None,
+            server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
+        };
+        let transaction_generator = MockGenerateTransaction::new();
+        let interface = MockInterface::make_interface(conn_params, transaction_generator)
+            .await
+            .unwrap();
+        let mut connection = custom_connect(interface.clone()).await.unwrap();
+
+        let session = connection
+            .claim_session(73, 10, Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert_eq!(session.id(), 73);
+
+        let (handle, mut receiver) = session
+            .handle_from_id(
+                3,
+                "mock.plugin.test".to_string(),
+                AttachParams {
+                    opaque_id: Some("echotest-a1b2c3".to_string()),
+                    ..Default::default()
+                },
+            )
+            .await;
+        assert_eq!(handle.id(), 3);
+        assert_eq!(handle.session_id(), 73);
+        assert_eq!(handle.plugin_id(), "mock.plugin.test");
+        assert_eq!(handle.opaque_id(), Some("echotest-a1b2c3"));
+
+        let event = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::GenericEvent(GenericEvent::WebrtcUp)),
+            transaction: None,
+            session_id: Some(73),
+            sender: Some(3),
+            jsep: None,
+        };
+        interface.mock_event(3, event.clone()).await;
+        assert_eq!(receiver.recv().await.unwrap(), event);
+    }
+
     #[tokio::test]
     async fn it_fails_to_attach_session() {
         let conn_params = ConnectionParams {
@@ -72,6 +195,10 @@ mod tests {
             capacity: 10,
             apisecret: None,
             server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
         };
         let transaction_generator = MockGenerateTransaction::new();
         let interface = MockInterface::make_interface(conn_params, transaction_generator)
@@ -119,4 +246,130 @@ mod tests {
             Err(jarust::interface::Error::JanusError { .. })
         ));
     }
+
+    #[tokio::test]
+    async fn it_notifies_session_expiration() {
+        let conn_params = ConnectionParams {
+            url: "mock://some.janus.com".to_string(),
+            capacity: 10,
+            apisecret: None,
+            server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
+        };
+        let transaction_generator = MockGenerateTransaction::new();
+        let interface = MockInterface::make_interface(conn_params, transaction_generator)
+            .await
+            .unwrap();
+        let mut connection = custom_connect(interface.clone()).await.unwrap();
+
+        let session_id = 73;
+
+        let response = JaResponse {
+            janus: ResponseType::Success(JaSuccessProtocol::Data {
+                data: JaData { id: session_id },
+            }),
+            transaction: Some("abc123".to_string()),
+            session_id: None,
+            sender: None,
+            jsep: None,
+        };
+        interface.mock_create_rsp(response).await;
+
+        let session = connection
+            .create_session(1, Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        let response = JaResponse {
+            janus: ResponseType::Success(JaSuccessProtocol::Data {
+                data: JaData { id: 3 },
+            }),
+            transaction: Some("mock-attach-plugin-transaction".to_string()),
+            session_id: Some(session_id),
+            sender: None,
+            jsep: None,
+        };
+        interface.mock_attach_rsp(response).await;
+
+        let (_handle, mut event_receiver) = session
+            .attach("mock.plugin.test".to_string(), Duration::from_secs(5))
+            .await
+            .unwrap();
+        assert!(!session.is_expired());
+
+        interface
+            .mock_keep_alive_error(ErrorResponse {
+                code: 458,
+                reason: "No such session".to_string(),
+            })
+            .await;
+
+        tokio::time::timeout(Duration::from_secs(5), session.expired())
+            .await
+            .unwrap();
+        assert!(session.is_expired());
+
+        let event = event_receiver.recv().await.unwrap();
+        assert_eq!(
+            event.janus,
+            ResponseType::Event(JaHandleEvent::GenericEvent(GenericEvent::SessionExpired))
+        );
+    }
+
+    #[tokio::test]
+    async fn it_expires_after_max_consecutive_keep_alive_failures() {
+        let conn_params = ConnectionParams {
+            url: "mock://some.janus.com".to_string(),
+            capacity: 10,
+            apisecret: None,
+            server_root: "mock".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: None,
+        };
+        let transaction_generator = MockGenerateTransaction::new();
+        let interface = MockInterface::make_interface(conn_params, transaction_generator)
+            .await
+            .unwrap();
+        let mut connection = custom_connect(interface.clone()).await.unwrap();
+        connection.set_keep_alive_policy(KeepAlivePolicy {
+            max_consecutive_failures: Some(3),
+        });
+
+        let response = JaResponse {
+            janus: ResponseType::Success(JaSuccessProtocol::Data {
+                data: JaData { id: 73 },
+            }),
+            transaction: Some("abc123".to_string()),
+            session_id: None,
+            sender: None,
+            jsep: None,
+        };
+        interface.mock_create_rsp(response).await;
+        // Not a 458, the session only expires once the policy gives up
+        interface
+            .mock_keep_alive_error(ErrorResponse {
+                code: 490,
+                reason: "Unknown error".to_string(),
+            })
+            .await;
+
+        // Keep-alives are sent right away then every second
+        let session = connection
+            .create_session(1, Duration::from_secs(10))
+            .await
+            .unwrap();
+
+        tokio::time::sleep(Duration::from_millis(1500)).await;
+        assert!(!session.is_expired(), "Only 2 keep-alives failed so far");
+
+        tokio::time::timeout(Duration::from_secs(5), session.expired())
+            .await
+            .unwrap();
+        assert!(session.is_expired());
+    }
 }
@@ -0,0 +1,646 @@
+use jarust_interface::admin_protocol::AcceptNewSessionsRsp;
+use jarust_interface::admin_protocol::AdminMessage;
+use jarust_interface::admin_protocol::GetStatusRsp;
+use jarust_interface::admin_protocol::HandleInfoRsp;
+use jarust_interface::admin_protocol::LibniceDebugRsp;
+use jarust_interface::admin_protocol::ListHandlesRsp;
+use jarust_interface::admin_protocol::ListSessionsRsp;
+use jarust_interface::admin_protocol::ListTokensRsp;
+use jarust_interface::admin_protocol::LockingDebugRsp;
+use jarust_interface::admin_protocol::LogColorsRsp;
+use jarust_interface::admin_protocol::LogLevelRsp;
+use jarust_interface::admin_protocol::LogTimestampsRsp;
+use jarust_interface::admin_protocol::MinNackQueueRsp;
+use jarust_interface::admin_protocol::NoMediaTimerRsp;
+use jarust_interface::admin_protocol::QueryRsp;
+use jarust_interface::admin_protocol::RefcountDebugRsp;
+use jarust_interface::admin_protocol::ResolveAddressRsp;
+use jarust_interface::admin_protocol::SessionTimeoutRsp;
+use jarust_interface::admin_protocol::SlowlinkThresholdRsp;
+use jarust_interface::admin_protocol::StartPcapParams;
+use jarust_interface::admin_protocol::TestStunRsp;
+use jarust_interface::admin_protocol::TokenPluginsRsp;
+use jarust_interface::janus_admin_interface::JanusAdminInterface;
+use jarust_interface::janus_admin_interface::JanusAdminInterfaceImpl;
+use jarust_interface::japrotocol::ServerInfoRsp;
+use serde_json::json;
+use serde_json::Value;
+use std::time::Duration;
+
+/// Client for the janus admin/monitor API.
+///
+/// Full docs: <https://janus.conf.meetecho.com/docs/admin.html>
+#[derive(Clone, Debug)]
+pub struct JaAdmin {
+    interface: JanusAdminInterfaceImpl,
+}
+
+impl JaAdmin {
+    pub(crate) async fn open(
+        interface: impl JanusAdminInterface,
+    ) -> Result<Self, jarust_interface::Error> {
+        tracing::info!("Creating new admin connection");
+        Ok(Self {
+            interface: JanusAdminInterfaceImpl::new(interface),
+        })
+    }
+
+    async fn set_value<R>(
+        &self,
+        request: &str,
+        key: &str,
+        value: Value,
+        timeout: Duration,
+    ) -> Result<R, jarust_interface::Error>
+    where
+        R: serde::de::DeserializeOwned,
+    {
+        let mut body = json!({ "janus": request });
+        body[key] = value;
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+}
+
+/// Server requests
+impl JaAdmin {
+    /// Retrieve Janus server info
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn info(&self, timeout: Duration) -> Result<ServerInfoRsp, jarust_interface::Error> {
+        self.interface
+            .send_admin_request(AdminMessage::server(json!({"janus": "info"})), timeout)
+            .await
+    }
+
+    /// Ping the server, useful to check the admin connection and latency
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn ping(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        let _: Value = self
+            .interface
+            .send_admin_request(AdminMessage::server(json!({"janus": "ping"})), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Retrieve the current values of the runtime configurable settings
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn get_status(
+        &self,
+        timeout: Duration,
+    ) -> Result<GetStatusRsp, jarust_interface::Error> {
+        self.interface
+            .send_admin_request(
+                AdminMessage::server(json!({"janus": "get_status"})),
+                timeout,
+            )
+            .await
+    }
+
+    /// Change the session timeout value (in seconds)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_session_timeout(
+        &self,
+        session_timeout: u64,
+        timeout: Duration,
+    ) -> Result<SessionTimeoutRsp, jarust_interface::Error> {
+        self.set_value(
+            "set_session_timeout",
+            "timeout",
+            session_timeout.into(),
+            timeout,
+        )
+        .await
+    }
+
+    /// Change the log level (0-7)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_log_level(
+        &self,
+        level: u8,
+        timeout: Duration,
+    ) -> Result<LogLevelRsp, jarust_interface::Error> {
+        self.set_value("set_log_level", "level", level.into(), timeout)
+            .await
+    }
+
+    /// Enable or disable locking debug
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_locking_debug(
+        &self,
+        debug: bool,
+        timeout: Duration,
+    ) -> Result<LockingDebugRsp, jarust_interface::Error> {
+        self.set_value("set_locking_debug", "debug", debug.into(), timeout)
+            .await
+    }
+
+    /// Enable or disable reference counters debug
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_refcount_debug(
+        &self,
+        debug: bool,
+        timeout: Duration,
+    ) -> Result<RefcountDebugRsp, jarust_interface::Error> {
+        self.set_value("set_refcount_debug", "debug", debug.into(), timeout)
+            .await
+    }
+
+    /// Enable or disable log timestamps
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_log_timestamps(
+        &self,
+        timestamps: bool,
+        timeout: Duration,
+    ) -> Result<LogTimestampsRsp, jarust_interface::Error> {
+        self.set_value(
+            "set_log_timestamps",
+            "timestamps",
+            timestamps.into(),
+            timeout,
+        )
+        .await
+    }
+
+    /// Enable or disable log colors
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_log_colors(
+        &self,
+        colors: bool,
+        timeout: Duration,
+    ) -> Result<LogColorsRsp, jarust_interface::Error> {
+        self.set_value("set_log_colors", "colors", colors.into(), timeout)
+            .await
+    }
+
+    /// Enable or disable libnice debugging
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_libnice_debug(
+        &self,
+        debug: bool,
+        timeout: Duration,
+    ) -> Result<LibniceDebugRsp, jarust_interface::Error> {
+        self.set_value("set_libnice_debug", "debug", debug.into(), timeout)
+            .await
+    }
+
+    /// Change the minimum NACK queue size (in milliseconds)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_min_nack_queue(
+        &self,
+        min_nack_queue: u64,
+        timeout: Duration,
+    ) -> Result<MinNackQueueRsp, jarust_interface::Error> {
+        self.set_value(
+            "set_min_nack_queue",
+            "min_nack_queue",
+            min_nack_queue.into(),
+            timeout,
+        )
+        .await
+    }
+
+    /// Change the no media timer value (in seconds)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_no_media_timer(
+        &self,
+        no_media_timer: u64,
+        timeout: Duration,
+    ) -> Result<NoMediaTimerRsp, jarust_interface::Error> {
+        self.set_value(
+            "set_no_media_timer",
+            "no_media_timer",
+            no_media_timer.into(),
+            timeout,
+        )
+        .await
+    }
+
+    /// Change the slowlink threshold value (number of lost packets)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set_slowlink_threshold(
+        &self,
+        slowlink_threshold: u64,
+        timeout: Duration,
+    ) -> Result<SlowlinkThresholdRsp, jarust_interface::Error> {
+        self.set_value(
+            "set_slowlink_threshold",
+            "slowlink_threshold",
+            slowlink_threshold.into(),
+            timeout,
+        )
+        .await
+    }
+
+    /// Configure whether janus should accept new sessions or not
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn accept_new_sessions(
+        &self,
+        accept: bool,
+        timeout: Duration,
+    ) -> Result<AcceptNewSessionsRsp, jarust_interface::Error> {
+        self.set_value("accept_new_sessions", "accept", accept.into(), timeout)
+            .await
+    }
+
+    /// List the sessions in the server
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_sessions(
+        &self,
+        timeout: Duration,
+    ) -> Result<ListSessionsRsp, jarust_interface::Error> {
+        self.interface
+            .send_admin_request(
+                AdminMessage::server(json!({"janus": "list_sessions"})),
+                timeout,
+            )
+            .await
+    }
+
+    /// Send a synchronous request to a plugin and return its response
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn message_plugin(
+        &self,
+        plugin: &str,
+        request: Value,
+        timeout: Duration,
+    ) -> Result<QueryRsp, jarust_interface::Error> {
+        let body = json!({
+            "janus": "message_plugin",
+            "plugin": plugin,
+            "request": request
+        });
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+
+    /// Send a synchronous request to an event handler and return its response
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn query_eventhandler(
+        &self,
+        handler: &str,
+        request: Value,
+        timeout: Duration,
+    ) -> Result<QueryRsp, jarust_interface::Error> {
+        let body = json!({
+            "janus": "query_eventhandler",
+            "handler": handler,
+            "request": request
+        });
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+
+    /// Send a synchronous request to a logger and return its response
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn query_logger(
+        &self,
+        logger: &str,
+        request: Value,
+        timeout: Duration,
+    ) -> Result<QueryRsp, jarust_interface::Error> {
+        let body = json!({
+            "janus": "query_logger",
+            "logger": logger,
+            "request": request
+        });
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+
+    /// Push a custom event to the event handlers
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn custom_event(
+        &self,
+        schema: &str,
+        data: Value,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        let body = json!({
+            "janus": "custom_event",
+            "schema": schema,
+            "data": data
+        });
+        let _: Value = self
+            .interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Push a custom line to the janus logs
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn custom_logline(
+        &self,
+        line: &str,
+        level: Option<u8>,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        let mut body = json!({
+            "janus": "custom_logline",
+            "line": line
+        });
+        if let Some(level) = level {
+            body["level"] = level.into();
+        }
+        let _: Value = self
+            .interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Resolve an address using the server DNS resolver
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn resolve_address(
+        &self,
+        address: &str,
+        timeout: Duration,
+    ) -> Result<ResolveAddressRsp, jarust_interface::Error> {
+        let body = json!({
+            "janus": "resolve_address",
+            "address": address
+        });
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+
+    /// Send a STUN request to the provided server and return the public address
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn test_stun(
+        &self,
+        address: &str,
+        port: u16,
+        localport: Option<u16>,
+        timeout: Duration,
+    ) -> Result<TestStunRsp, jarust_interface::Error> {
+        let mut body = json!({
+            "janus": "test_stun",
+            "address": address,
+            "port": port
+        });
+        if let Some(localport) = localport {
+            body["localport"] = localport.into();
+        }
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+}
+
+/// Stored token requests, available when janus has `token_auth` enabled
+impl JaAdmin {
+    /// Add a token, giving it access to the provided plugins or to all of them if `None`
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn add_token(
+        &self,
+        token: &str,
+        plugins: Option<Vec<String>>,
+        timeout: Duration,
+    ) -> Result<TokenPluginsRsp, jarust_interface::Error> {
+        let mut body = json!({
+            "janus": "add_token",
+            "token": token
+        });
+        if let Some(plugins) = plugins {
+            body["plugins"] = plugins.into();
+        }
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+
+    /// List the stored tokens and the plugins each of them has access to
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_tokens(
+        &self,
+        timeout: Duration,
+    ) -> Result<ListTokensRsp, jarust_interface::Error> {
+        self.interface
+            .send_admin_request(
+                AdminMessage::server(json!({"janus": "list_tokens"})),
+                timeout,
+            )
+            .await
+    }
+
+    /// Remove a token, sessions using it are not affected but new requests are rejected
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn remove_token(
+        &self,
+        token: &str,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        let _: Value = self
+            .set_value("remove_token", "token", token.into(), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Give a token access to more plugins
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn allow_token(
+        &self,
+        token: &str,
+        plugins: Vec<String>,
+        timeout: Duration,
+    ) -> Result<TokenPluginsRsp, jarust_interface::Error> {
+        self.token_plugins_request("allow_token", token, plugins, timeout)
+            .await
+    }
+
+    /// Revoke a token access to some plugins
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn disallow_token(
+        &self,
+        token: &str,
+        plugins: Vec<String>,
+        timeout: Duration,
+    ) -> Result<TokenPluginsRsp, jarust_interface::Error> {
+        self.token_plugins_request("disallow_token", token, plugins, timeout)
+            .await
+    }
+
+    async fn token_plugins_request(
+        &self,
+        request: &str,
+        token: &str,
+        plugins: Vec<String>,
+        timeout: Duration,
+    ) -> Result<TokenPluginsRsp, jarust_interface::Error> {
+        let body = json!({
+            "janus": request,
+            "token": token,
+            "plugins": plugins
+        });
+        self.interface
+            .send_admin_request(AdminMessage::server(body), timeout)
+            .await
+    }
+}
+
+/// Session requests
+impl JaAdmin {
+    /// Destroy a session
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id))]
+    pub async fn destroy_session(
+        &self,
+        session_id: u64,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        let _: Value = self
+            .interface
+            .send_admin_request(
+                AdminMessage::session(session_id, json!({"janus": "destroy_session"})),
+                timeout,
+            )
+            .await?;
+        Ok(())
+    }
+
+    /// List the handles of a session
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id))]
+    pub async fn list_handles(
+        &self,
+        session_id: u64,
+        timeout: Duration,
+    ) -> Result<ListHandlesRsp, jarust_interface::Error> {
+        self.interface
+            .send_admin_request(
+                AdminMessage::session(session_id, json!({"janus": "list_handles"})),
+                timeout,
+            )
+            .await
+    }
+}
+
+/// Handle requests
+impl JaAdmin {
+    /// Retrieve the info of a handle, `plugin_only` limits the info to the plugin specific part
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn handle_info(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        plugin_only: bool,
+        timeout: Duration,
+    ) -> Result<HandleInfoRsp, jarust_interface::Error> {
+        let body = json!({
+            "janus": "handle_info",
+            "plugin_only": plugin_only
+        });
+        self.interface
+            .send_admin_request(AdminMessage::handle(session_id, handle_id, body), timeout)
+            .await
+    }
+
+    /// Start a pcap capture of the handle traffic
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn start_pcap(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        params: StartPcapParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        self.handle_capture_request("start_pcap", session_id, handle_id, params, timeout)
+            .await
+    }
+
+    /// Stop a pcap capture of the handle traffic
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn stop_pcap(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        self.handle_request("stop_pcap", session_id, handle_id, timeout)
+            .await
+    }
+
+    /// Start a text2pcap capture of the handle traffic
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn start_text2pcap(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        params: StartPcapParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        self.handle_capture_request("start_text2pcap", session_id, handle_id, params, timeout)
+            .await
+    }
+
+    /// Stop a text2pcap capture of the handle traffic
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn stop_text2pcap(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        self.handle_request("stop_text2pcap", session_id, handle_id, timeout)
+            .await
+    }
+
+    /// Hangup the PeerConnection of a handle
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn hangup_webrtc(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        self.handle_request("hangup_webrtc", session_id, handle_id, timeout)
+            .await
+    }
+
+    /// Detach a handle from its plugin
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id, handle_id = handle_id))]
+    pub async fn detach_handle(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        self.handle_request("detach_handle", session_id, handle_id, timeout)
+            .await
+    }
+
+    async fn handle_request(
+        &self,
+        request: &str,
+        session_id: u64,
+        handle_id: u64,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        let body = json!({ "janus": request });
+        let _: Value = self
+            .interface
+            .send_admin_request(AdminMessage::handle(session_id, handle_id, body), timeout)
+            .await?;
+        Ok(())
+    }
+
+    async fn handle_capture_request(
+        &self,
+        request: &str,
+        session_id: u64,
+        handle_id: u64,
+        params: StartPcapParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        let mut body = serde_json::to_value(params)?;
+        body["janus"] = request.into();
+        let _: Value = self
+            .interface
+            .send_admin_request(AdminMessage::handle(session_id, handle_id, body), timeout)
+            .await?;
+        Ok(())
+    }
+}
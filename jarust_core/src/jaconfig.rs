@@ -1,3 +1,7 @@
+use jarust_interface::janus_interface::ReconnectParams;
+use jarust_interface::janus_interface::TlsParams;
+use jarust_interface::janus_interface::TransportParams;
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub struct JaConfig {
     /// Url to janus server
@@ -9,6 +13,14 @@ pub struct JaConfig {
     pub server_root: String,
     /// Ring buffer capacity, used when picking WebSocket janus api
     pub capacity: usize,
+    /// Reconnection policy, used when picking WebSocket janus api, `None` disables reconnection
+    pub reconnect: Option<ReconnectParams>,
+    /// TLS settings for `wss://` and `https://` urls, `None` uses the platform defaults
+    pub tls: Option<TlsParams>,
+    /// Extra headers, proxy, connect timeout and websocket limits, `None` uses the defaults
+    pub transport: Option<TransportParams>,
+    /// Stored auth token when janus has `token_auth` enabled, can be rotated with `JaConnection::set_token`
+    pub token: Option<String>,
 }
 
 #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
@@ -16,3 +28,29 @@ pub enum JanusAPI {
     WebSocket,
     Restful,
 }
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct JaAdminConfig {
+    /// Url to janus admin endpoint
+    pub url: String,
+    /// Janus admin secret if any
+    pub admin_secret: Option<String>,
+    /// root path for janus admin API, when using HTTP it should be `admin` unless it was changed
+    /// in janus config
+    pub server_root: String,
+    /// Ring buffer capacity, used when picking WebSocket janus api
+    pub capacity: usize,
+    /// TLS settings for `wss://` and `https://` urls, `None` uses the platform defaults
+    pub tls: Option<TlsParams>,
+    /// Extra headers, proxy, connect timeout and websocket limits, `None` uses the defaults
+    pub transport: Option<TransportParams>,
+}
+
+/// Controls when a session keep-alive gives up.
+///
+/// The keep-alive always stops when janus reports the session as not found (458).
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct KeepAlivePolicy {
+    /// Stop after this many consecutive failures, `None` means never give up
+    pub max_consecutive_failures: Option<u32>,
+}
@@ -1,13 +1,17 @@
+use crate::jaconfig::KeepAlivePolicy;
 use crate::jasession::JaSession;
 use crate::jasession::NewSessionParams;
+use jarust_interface::janus_interface::ConnectionState;
 use jarust_interface::janus_interface::JanusInterface;
 use jarust_interface::janus_interface::JanusInterfaceImpl;
 use jarust_interface::japrotocol::ServerInfoRsp;
 use std::time::Duration;
+use tokio::sync::watch;
 
 #[derive(Clone, Debug)]
 pub struct JaConnection {
     interface: JanusInterfaceImpl,
+    ka_policy: KeepAlivePolicy,
 }
 
 impl JaConnection {
@@ -17,9 +21,15 @@ impl JaConnection {
         tracing::info!("Creating new connection");
         Ok(Self {
             interface: JanusInterfaceImpl::new(interface),
+            ka_policy: KeepAlivePolicy::default(),
         })
     }
 
+    /// Sets the keep-alive policy of the sessions created afterward.
+    pub fn set_keep_alive_policy(&mut self, policy: KeepAlivePolicy) {
+        self.ka_policy = policy;
+    }
+
     /// Creates a new session with janus server.
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn create_session(
@@ -32,6 +42,7 @@ impl JaConnection {
         let session = JaSession::new(NewSessionParams {
             session_id,
             ka_interval,
+            ka_policy: self.ka_policy,
             interface: self.interface.clone(),
         })
         .await;
@@ -39,6 +50,31 @@ impl JaConnection {
         Ok(session)
     }
 
+    /// Takes over an existing session from its id, e.g. after a process restart.
+    ///
+    /// The session is claimed so its events are delivered to this connection, then a
+    /// keep-alive is started like for a created session. Use [`JaSession::handle_from_id`]
+    /// to rebuild its handles.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = session_id))]
+    pub async fn claim_session(
+        &mut self,
+        session_id: u64,
+        ka_interval: u32,
+        timeout: Duration,
+    ) -> Result<JaSession, jarust_interface::Error> {
+        tracing::info!("Claiming session");
+        self.interface.claim(session_id, timeout).await?;
+        let session = JaSession::new(NewSessionParams {
+            session_id,
+            ka_interval,
+            ka_policy: self.ka_policy,
+            interface: self.interface.clone(),
+        })
+        .await;
+        tracing::info!(id = session_id, "Session claimed");
+        Ok(session)
+    }
+
     /// Retrieve Janus server info
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn server_info(
@@ -48,4 +84,18 @@ impl JaConnection {
         let res = self.interface.server_info(timeout).await?;
         Ok(res)
     }
+
+    /// Subscribe to the connection state (connecting, connected, disconnected, reconnecting, ...)
+    ///
+    /// The receiver starts with the current state, so subscribing late doesn't miss it.
+    pub fn subscribe_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.interface.subscribe_connection_state()
+    }
+
+    /// Replace the auth token sent with every request, e.g. before the current one expires
+    ///
+    /// Sessions and handles created from this connection share its interface, so they use the new token too.
+    pub fn set_token(&self, token: Option<String>) {
+        self.interface.set_token(token);
+    }
 }
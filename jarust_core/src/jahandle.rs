@@ -1,5 +1,6 @@
 use jarust_interface::handle_msg::HandleMessage;
 use jarust_interface::handle_msg::HandleMessageWithJsep;
+use jarust_interface::janus_interface::AttachParams;
 use jarust_interface::janus_interface::JanusInterfaceImpl;
 use jarust_interface::japrotocol::Candidate;
 use jarust_interface::japrotocol::Jsep;
@@ -7,11 +8,15 @@ use serde::de::DeserializeOwned;
 use serde_json::json;
 use serde_json::Value;
 use std::time::Duration;
+use std::time::SystemTime;
 
 struct InnerHandle {
     id: u64,
     session_id: u64,
+    plugin_id: String,
     interface: JanusInterfaceImpl,
+    attach_params: AttachParams,
+    attached_at: SystemTime,
 }
 
 pub struct JaHandle {
@@ -21,7 +26,9 @@ pub struct JaHandle {
 pub struct NewHandleParams {
     pub handle_id: u64,
     pub session_id: u64,
+    pub plugin_id: String,
     pub interface: JanusInterfaceImpl,
+    pub attach_params: AttachParams,
 }
 
 impl JaHandle {
@@ -30,11 +37,44 @@ impl JaHandle {
             inner: InnerHandle {
                 id: params.handle_id,
                 session_id: params.session_id,
+                plugin_id: params.plugin_id,
                 interface: params.interface,
+                attach_params: params.attach_params,
+                attached_at: SystemTime::now(),
             },
         }
     }
 
+    /// Returns the handle id
+    pub fn id(&self) -> u64 {
+        self.inner.id
+    }
+
+    /// Returns the id of the session the handle belongs to
+    pub fn session_id(&self) -> u64 {
+        self.inner.session_id
+    }
+
+    /// Returns the plugin the handle is attached to, e.g. `janus.plugin.echotest`
+    pub fn plugin_id(&self) -> &str {
+        &self.inner.plugin_id
+    }
+
+    /// Returns when the handle was attached, or rebuilt with [`JaSession::handle_from_id`](crate::prelude::JaSession::handle_from_id)
+    pub fn attached_at(&self) -> SystemTime {
+        self.inner.attached_at
+    }
+
+    /// Opaque id the handle was attached with, if any
+    pub fn opaque_id(&self) -> Option<&str> {
+        self.inner.attach_params.opaque_id.as_deref()
+    }
+
+    /// Parameters the handle was attached with
+    pub fn attach_params(&self) -> &AttachParams {
+        &self.inner.attach_params
+    }
+
     /// Send a one-shot message
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = self.inner.session_id, handle_id = self.inner.id))]
     pub async fn fire_and_forget(&self, body: Value) -> Result<String, jarust_interface::Error> {
@@ -1,3 +1,5 @@
+use crate::jaconfig::KeepAlivePolicy;
+use jarust_interface::error::JanusErrorCode;
 use jarust_interface::janus_interface::JanusInterfaceImpl;
 use std::time::Duration;
 use tokio::time;
@@ -6,17 +8,26 @@ pub struct JaKeepAlive {
     interface: JanusInterfaceImpl,
     session_id: u64,
     ka_interval: u32,
+    policy: KeepAlivePolicy,
 }
 
 impl JaKeepAlive {
-    pub fn new(interface: JanusInterfaceImpl, session_id: u64, ka_interval: u32) -> Self {
+    pub fn new(
+        interface: JanusInterfaceImpl,
+        session_id: u64,
+        ka_interval: u32,
+        policy: KeepAlivePolicy,
+    ) -> Self {
         Self {
             interface,
             session_id,
             ka_interval,
+            policy,
         }
     }
 
+    /// Sends keep-alive messages until the session is considered expired, the error that caused
+    /// the expiration is returned.
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = self.session_id))]
     pub async fn start(&self) -> Result<(), jarust_interface::Error> {
         if !self.interface.has_keep_alive() {
@@ -25,12 +36,31 @@ impl JaKeepAlive {
         }
         let duration = Duration::from_secs(self.ka_interval.into());
         let mut interval = time::interval(duration);
+        let mut failures = 0;
         loop {
             interval.tick().await;
             tracing::debug!("Sending keep-alive");
             match self.interface.keep_alive(self.session_id, duration).await {
-                Ok(_) => tracing::debug!("Keep-alive success"),
-                Err(e) => tracing::error!("Keep-alive failed: {:?}", e),
+                Ok(_) => {
+                    tracing::debug!("Keep-alive success");
+                    failures = 0;
+                }
+                Err(e) if e.janus_error_code() == Some(JanusErrorCode::SessionNotFound) => {
+                    tracing::error!("Keep-alive failed, session not found");
+                    return Err(e);
+                }
+                Err(e) => {
+                    failures += 1;
+                    tracing::error!(failures, "Keep-alive failed: {:?}", e);
+                    if self
+                        .policy
+                        .max_consecutive_failures
+                        .is_some_and(|max| failures >= max)
+                    {
+                        tracing::error!("Too many consecutive keep-alive failures");
+                        return Err(e);
+                    }
+                }
             };
         }
     }
@@ -15,5 +15,16 @@ pub trait Attach {
         &self,
         plugin_id: String,
         timeout: Duration,
+    ) -> Result<(JaHandle, mpsc::UnboundedReceiver<JaResponse>), jarust_interface::Error> {
+        self.attach_with_params(plugin_id, AttachParams::default(), timeout)
+            .await
+    }
+
+    /// Attach with an opaque id, a static loop index or forced bundle/rtcp-mux
+    async fn attach_with_params(
+        &self,
+        plugin_id: String,
+        params: AttachParams,
+        timeout: Duration,
     ) -> Result<(JaHandle, mpsc::UnboundedReceiver<JaResponse>), jarust_interface::Error>;
 }
@@ -1,3 +1,4 @@
+use crate::jaconfig::KeepAlivePolicy;
 use crate::jahandle::JaHandle;
 use crate::jahandle::NewHandleParams;
 use crate::jakeepalive::JaKeepAlive;
@@ -8,12 +9,14 @@ use jarust_rt::JaTask;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::sync::watch;
 use tokio::sync::Mutex;
 
 #[derive(Debug)]
 pub struct Shared {
     id: u64,
     interface: JanusInterfaceImpl,
+    expired: Arc<watch::Sender<bool>>,
 }
 
 #[derive(Debug, Default)]
@@ -35,24 +38,42 @@ pub struct JaSession {
 pub struct NewSessionParams {
     pub session_id: u64,
     pub ka_interval: u32,
+    pub ka_policy: KeepAlivePolicy,
     pub interface: JanusInterfaceImpl,
 }
 
 impl JaSession {
     pub(crate) async fn new(params: NewSessionParams) -> Self {
+        let (expired, _) = watch::channel(false);
+        let expired = Arc::new(expired);
         let shared = Shared {
             id: params.session_id,
             interface: params.interface.clone(),
+            expired: expired.clone(),
         };
         let exclusive = Mutex::new(Exclusive::default());
         let session = Self {
             inner: Arc::new(InnerSession { shared, exclusive }),
         };
 
-        let jakeepalive = JaKeepAlive::new(params.interface, params.session_id, params.ka_interval);
+        let jakeepalive = JaKeepAlive::new(
+            params.interface.clone(),
+            params.session_id,
+            params.ka_interval,
+            params.ka_policy,
+        );
 
-        let keepalive_task =
-            jarust_rt::spawn("KeepAlive task", async move { jakeepalive.start().await });
+        let keepalive_task = jarust_rt::spawn("KeepAlive task", {
+            let interface = params.interface;
+            let session_id = params.session_id;
+            async move {
+                if let Err(what) = jakeepalive.start().await {
+                    tracing::error!(session_id, "Session expired: {what}");
+                    expired.send_replace(true);
+                    interface.notify_session_expired(session_id).await;
+                }
+            }
+        });
 
         session.inner.exclusive.lock().await.task = Some(keepalive_task);
 
@@ -61,6 +82,54 @@ impl JaSession {
 }
 
 impl JaSession {
+    /// Returns the session id
+    pub fn id(&self) -> u64 {
+        self.inner.shared.id
+    }
+
+    /// Rebuilds a handle already attached to this session from its id, e.g. after a process restart.
+    ///
+    /// Janus is not contacted, the plugin and attach params are only kept as the handle metadata.
+    /// The returned receiver gets the handle events from now on.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = self.inner.shared.id, handle_id = handle_id))]
+    pub async fn handle_from_id(
+        &self,
+        handle_id: u64,
+        plugin_id: String,
+        params: AttachParams,
+    ) -> (JaHandle, mpsc::UnboundedReceiver<JaResponse>) {
+        let session_id = self.inner.shared.id;
+        let event_receiver = self
+            .inner
+            .shared
+            .interface
+            .register_handle(session_id, handle_id)
+            .await;
+        let handle = JaHandle::new(NewHandleParams {
+            handle_id,
+            session_id,
+            plugin_id,
+            interface: self.inner.shared.interface.clone(),
+            attach_params: params,
+        })
+        .await;
+        (handle, event_receiver)
+    }
+
+    /// Returns true if the keep-alive found out the session has expired
+    pub fn is_expired(&self) -> bool {
+        *self.inner.shared.expired.borrow()
+    }
+
+    /// Waits until the keep-alive finds out the session has expired.
+    ///
+    /// When it happens, the handles event receivers also get a
+    /// [`GenericEvent::SessionExpired`](jarust_interface::japrotocol::GenericEvent::SessionExpired).
+    pub async fn expired(&self) {
+        let mut receiver = self.inner.shared.expired.subscribe();
+        let _ = receiver.wait_for(|expired| *expired).await;
+    }
+
     /// Destroy the current session
     ///
     /// Similar to [`destroy`](Self::destroy) but it borrows the session instead of consuming it
@@ -96,24 +165,31 @@ impl JaSession {
 impl Attach for JaSession {
     /// Attach a plugin to the current session
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all, fields(session_id = self.inner.shared.id))]
-    async fn attach(
+    async fn attach_with_params(
         &self,
         plugin_id: String,
+        params: AttachParams,
         timeout: Duration,
     ) -> Result<(JaHandle, mpsc::UnboundedReceiver<JaResponse>), jarust_interface::Error> {
-        tracing::info!(plugin = &plugin_id, "Attaching new handle");
+        tracing::info!(
+            plugin = &plugin_id,
+            opaque_id = params.opaque_id,
+            "Attaching new handle"
+        );
         let session_id = self.inner.shared.id;
         let (handle_id, event_receiver) = self
             .inner
             .shared
             .interface
-            .attach(session_id, plugin_id, timeout)
+            .attach(session_id, plugin_id.clone(), params.clone(), timeout)
             .await?;
 
         let handle = JaHandle::new(NewHandleParams {
             handle_id,
             session_id,
+            plugin_id,
             interface: self.inner.shared.interface.clone(),
+            attach_params: params,
         })
         .await;
         tracing::info!(id = handle_id, "Handle created");
@@ -22,6 +22,7 @@
 //! We have a separate crate for Janus plugins, [`jarust_plugins`](https://crates.io/crates/jarust_plugins).
 //!
 
+pub mod jaadmin;
 pub mod jaconfig;
 pub mod jaconnection;
 pub mod jahandle;
@@ -32,12 +33,18 @@ pub mod prelude;
 
 pub use jarust_interface::tgenerator::GenerateTransaction;
 
+use jaadmin::JaAdmin;
+use jaconfig::JaAdminConfig;
 use jaconfig::JaConfig;
 use jaconfig::JanusAPI;
 use jaconnection::JaConnection;
+use jarust_interface::janus_admin_interface::AdminConnectionParams;
+use jarust_interface::janus_admin_interface::JanusAdminInterface;
 use jarust_interface::janus_interface::ConnectionParams;
 use jarust_interface::janus_interface::JanusInterface;
+use jarust_interface::restful::RestfulAdminInterface;
 use jarust_interface::restful::RestfulInterface;
+use jarust_interface::websocket::WebSocketAdminInterface;
 use jarust_interface::websocket::WebSocketInterface;
 use tracing::Level;
 
@@ -63,6 +70,10 @@ pub async fn connect(
         capacity: jaconfig.capacity,
         apisecret: jaconfig.apisecret,
         server_root: jaconfig.server_root,
+        reconnect: jaconfig.reconnect,
+        tls: jaconfig.tls,
+        transport: jaconfig.transport,
+        token: jaconfig.token,
     };
     match api_interface {
         JanusAPI::WebSocket => {
@@ -97,3 +108,46 @@ pub async fn custom_connect(
 ) -> Result<JaConnection, jarust_interface::Error> {
     JaConnection::open(interface).await
 }
+
+/// Creates a new admin connection with janus server from the provided configs.
+///
+/// Not available on wasm, use [`custom_connect_admin`] with a custom interface instead.
+#[cfg(not(target_family = "wasm"))]
+pub async fn connect_admin(
+    jaconfig: JaAdminConfig,
+    api_interface: JanusAPI,
+    transaction_generator: impl GenerateTransaction,
+) -> Result<JaAdmin, jarust_interface::Error> {
+    let conn_params = AdminConnectionParams {
+        url: jaconfig.url,
+        capacity: jaconfig.capacity,
+        admin_secret: jaconfig.admin_secret,
+        server_root: jaconfig.server_root,
+        tls: jaconfig.tls,
+        transport: jaconfig.transport,
+    };
+    match api_interface {
+        JanusAPI::WebSocket => {
+            custom_connect_admin(
+                WebSocketAdminInterface::make_admin_interface(conn_params, transaction_generator)
+                    .await?,
+            )
+            .await
+        }
+        JanusAPI::Restful => {
+            custom_connect_admin(
+                RestfulAdminInterface::make_admin_interface(conn_params, transaction_generator)
+                    .await?,
+            )
+            .await
+        }
+    }
+}
+
+/// Creates a new customized admin connection with janus servers.
+#[tracing::instrument(level = Level::TRACE, skip_all)]
+pub async fn custom_connect_admin(
+    interface: impl JanusAdminInterface,
+) -> Result<JaAdmin, jarust_interface::Error> {
+    JaAdmin::open(interface).await
+}
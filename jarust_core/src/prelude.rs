@@ -3,4 +3,5 @@ pub use crate::japlugin::Attach;
 pub use crate::japlugin::PluginTask;
 pub use crate::jasession::JaSession;
 
+pub use jarust_interface::janus_interface::AttachParams;
 pub use jarust_interface::japrotocol::JaResponse;
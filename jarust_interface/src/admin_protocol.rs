@@ -0,0 +1,364 @@
+use serde::Deserialize;
+use serde::Serialize;
+use serde_json::Value;
+
+/// A request sent to the admin/monitor API.
+///
+/// Admin requests can target the server, a session or a specific handle, the interfaces
+/// use the ids to build the request path (restful) or to decorate the request (websocket).
+#[derive(Clone, Debug, PartialEq)]
+pub struct AdminMessage {
+    pub session_id: Option<u64>,
+    pub handle_id: Option<u64>,
+    pub body: Value,
+}
+
+impl AdminMessage {
+    /// Server level request, e.g: `list_sessions`, `set_log_level`.
+    pub fn server(body: Value) -> Self {
+        Self {
+            session_id: None,
+            handle_id: None,
+            body,
+        }
+    }
+
+    /// Session level request, e.g: `list_handles`.
+    pub fn session(session_id: u64, body: Value) -> Self {
+        Self {
+            session_id: Some(session_id),
+            handle_id: None,
+            body,
+        }
+    }
+
+    /// Handle level request, e.g: `handle_info`, `start_pcap`.
+    pub fn handle(session_id: u64, handle_id: u64, body: Value) -> Self {
+        Self {
+            session_id: Some(session_id),
+            handle_id: Some(handle_id),
+            body,
+        }
+    }
+}
+
+/// Options for `start_pcap` and `start_text2pcap`.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct StartPcapParams {
+    /// Folder to save the capture in, defaults to the current folder
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub folder: Option<String>,
+    /// Capture file name, janus generates one if missing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub filename: Option<String>,
+    /// Number of bytes to truncate the packets to, 0 or missing means don't truncate
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub truncate: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct ListSessionsRsp {
+    pub sessions: Vec<u64>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct ListHandlesRsp {
+    pub session_id: u64,
+    pub handles: Vec<u64>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct HandleInfoRsp {
+    pub session_id: u64,
+    pub handle_id: u64,
+    pub info: Value,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct AdminStatus {
+    pub token_auth: bool,
+    pub api_secret: Option<bool>,
+    pub session_timeout: u64,
+    pub reclaim_session_timeout: u64,
+    pub candidates_timeout: u64,
+    pub log_level: u8,
+    pub log_timestamps: bool,
+    pub log_colors: bool,
+    pub locking_debug: bool,
+    pub refcount_debug: bool,
+    pub libnice_debug: bool,
+    pub min_nack_queue: u64,
+    #[serde(rename = "nack-optimizations")]
+    pub nack_optimizations: bool,
+    pub no_media_timer: u64,
+    pub slowlink_threshold: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct GetStatusRsp {
+    pub status: AdminStatus,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct SessionTimeoutRsp {
+    pub timeout: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct LogLevelRsp {
+    pub level: u8,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct AcceptNewSessionsRsp {
+    pub accept: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct LockingDebugRsp {
+    pub locking_debug: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct RefcountDebugRsp {
+    pub refcount_debug: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct LogTimestampsRsp {
+    pub log_timestamps: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct LogColorsRsp {
+    pub log_colors: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct LibniceDebugRsp {
+    pub libnice_debug: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct MinNackQueueRsp {
+    pub min_nack_queue: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct NoMediaTimerRsp {
+    pub no_media_timer: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct SlowlinkThresholdRsp {
+    pub slowlink_threshold: u64,
+}
+
+/// Generic response carrying an opaque payload, used by `message_plugin`, `query_eventhandler`
+/// and `query_logger`.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct QueryRsp {
+    pub response: Option<Value>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct ResolveAddressRsp {
+    pub ip: String,
+    pub elapsed: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct TestStunRsp {
+    pub public_ip: String,
+    pub public_port: u16,
+    pub elapsed: u64,
+}
+
+/// Plugins a stored token has access to.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct TokenPlugins {
+    pub plugins: Vec<String>,
+}
+
+/// Response of `add_token`, `allow_token` and `disallow_token`.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct TokenPluginsRsp {
+    pub data: TokenPlugins,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct StoredToken {
+    pub token: String,
+    pub allowed_plugins: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct StoredTokens {
+    pub tokens: Vec<StoredToken>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+pub struct ListTokensRsp {
+    pub data: StoredTokens,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdminStatus;
+    use super::GetStatusRsp;
+    use super::HandleInfoRsp;
+    use super::ListHandlesRsp;
+    use super::ListSessionsRsp;
+    use super::ListTokensRsp;
+    use super::StoredToken;
+    use super::StoredTokens;
+    use super::TokenPlugins;
+    use super::TokenPluginsRsp;
+    use serde_json::json;
+
+    #[test]
+    fn it_parse_list_sessions() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "sessions": [8786475896541857u64, 5453285264361957u64]
+        });
+        let actual = serde_json::from_value::<ListSessionsRsp>(rsp).unwrap();
+        let expected = ListSessionsRsp {
+            sessions: vec![8786475896541857, 5453285264361957],
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_parse_list_handles() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "session_id": 8786475896541857u64,
+            "handles": [3410583398062218u64]
+        });
+        let actual = serde_json::from_value::<ListHandlesRsp>(rsp).unwrap();
+        let expected = ListHandlesRsp {
+            session_id: 8786475896541857,
+            handles: vec![3410583398062218],
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_parse_handle_info() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "session_id": 8786475896541857u64,
+            "handle_id": 3410583398062218u64,
+            "info": {
+                "plugin": "janus.plugin.echotest",
+                "created": 2346275813u64
+            }
+        });
+        let actual = serde_json::from_value::<HandleInfoRsp>(rsp).unwrap();
+        let expected = HandleInfoRsp {
+            session_id: 8786475896541857,
+            handle_id: 3410583398062218,
+            info: json!({
+                "plugin": "janus.plugin.echotest",
+                "created": 2346275813u64
+            }),
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_parse_get_status() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "status": {
+                "token_auth": false,
+                "session_timeout": 60,
+                "reclaim_session_timeout": 0,
+                "candidates_timeout": 45,
+                "log_level": 4,
+                "log_timestamps": false,
+                "log_colors": true,
+                "locking_debug": false,
+                "refcount_debug": false,
+                "libnice_debug": false,
+                "min_nack_queue": 200,
+                "nack-optimizations": false,
+                "no_media_timer": 1,
+                "slowlink_threshold": 0
+            }
+        });
+        let actual = serde_json::from_value::<GetStatusRsp>(rsp).unwrap();
+        let expected = GetStatusRsp {
+            status: AdminStatus {
+                token_auth: false,
+                api_secret: None,
+                session_timeout: 60,
+                reclaim_session_timeout: 0,
+                candidates_timeout: 45,
+                log_level: 4,
+                log_timestamps: false,
+                log_colors: true,
+                locking_debug: false,
+                refcount_debug: false,
+                libnice_debug: false,
+                min_nack_queue: 200,
+                nack_optimizations: false,
+                no_media_timer: 1,
+                slowlink_threshold: 0,
+            },
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_parse_token_plugins() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "data": {
+                "plugins": ["janus.plugin.echotest", "janus.plugin.videoroom"]
+            }
+        });
+        let actual = serde_json::from_value::<TokenPluginsRsp>(rsp).unwrap();
+        let expected = TokenPluginsRsp {
+            data: TokenPlugins {
+                plugins: vec![
+                    "janus.plugin.echotest".to_string(),
+                    "janus.plugin.videoroom".to_string(),
+                ],
+            },
+        };
+        assert_eq!(actual, expected);
+    }
+
+    #[test]
+    fn it_parse_list_tokens() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "data": {
+                "tokens": [
+                    {
+                        "token": "a1b2c3",
+                        "allowed_plugins": ["janus.plugin.echotest"]
+                    }
+                ]
+            }
+        });
+        let actual = serde_json::from_value::<ListTokensRsp>(rsp).unwrap();
+        let expected = ListTokensRsp {
+            data: StoredTokens {
+                tokens: vec![StoredToken {
+                    token: "a1b2c3".to_string(),
+                    allowed_plugins: vec!["janus.plugin.echotest".to_string()],
+                }],
+            },
+        };
+        assert_eq!(actual, expected);
+    }
+}
@@ -9,12 +9,19 @@ pub enum Error {
     #[error("InvalidHeaderValue: {0}")]
     InvalidHeaderValue(#[from] tokio_tungstenite::tungstenite::http::header::InvalidHeaderValue),
 
+    #[cfg(not(target_family = "wasm"))]
+    #[error("InvalidHeaderName: {0}")]
+    InvalidHeaderName(#[from] tokio_tungstenite::tungstenite::http::header::InvalidHeaderName),
+
     #[error("Failed to parse json: {0}")]
     JsonParsingFailure(#[from] serde_json::Error),
     #[error("IO: {0}")]
     IO(#[from] std::io::Error),
     #[error("Reqwest error: {0}")]
     Reqwest(#[from] reqwest::Error),
+    #[cfg(feature = "sdp")]
+    #[error("Invalid SDP: {0}")]
+    InvalidSdp(#[from] crate::japrotocol::sdp::SdpError),
 
     /* Custom Errors */
     #[error("Error while parsing an incomplete packet")]
@@ -33,4 +40,157 @@ pub enum Error {
     PluginResponseError { error_code: u16, error: String },
     #[error("Request timeout")]
     RequestTimeout,
+    #[error("Invalid TLS configuration {{ reason: {reason} }}")]
+    InvalidTlsConfig { reason: String },
+    #[error("Proxy error {{ reason: {reason} }}")]
+    ProxyError { reason: String },
+}
+
+impl Error {
+    /// Returns the typed janus error code if this is a [`Error::JanusError`]
+    pub fn janus_error_code(&self) -> Option<JanusErrorCode> {
+        match self {
+            Self::JanusError { code, .. } => Some(JanusErrorCode::from(*code)),
+            _ => None,
+        }
+    }
+
+    /// Returns the typed plugin error code if this is a [`Error::PluginResponseError`]
+    ///
+    /// ```ignore
+    /// let code = error.plugin_error_code::<VideoRoomErrorCode>();
+    /// ```
+    pub fn plugin_error_code<C>(&self) -> Option<C>
+    where
+        C: From<u16>,
+    {
+        match self {
+            Self::PluginResponseError { error_code, .. } => Some(C::from(*error_code)),
+            _ => None,
+        }
+    }
+}
+
+/// Generates an error codes enum with conversions from and to the raw code,
+/// unknown codes are kept in the `Other` variant.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! error_codes {
+    (
+        $(#[$meta:meta])*
+        $name:ident {
+            $($(#[$variant_meta:meta])* $variant:ident = $code:literal),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        #[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+        pub enum $name {
+            $($(#[$variant_meta])* $variant,)*
+            /// A code that is not part of the known error table
+            Other(u16),
+        }
+
+        impl $name {
+            /// The raw error code
+            pub fn code(&self) -> u16 {
+                match self {
+                    $(Self::$variant => $code,)*
+                    Self::Other(code) => *code,
+                }
+            }
+        }
+
+        impl From<u16> for $name {
+            fn from(code: u16) -> Self {
+                match code {
+                    $($code => Self::$variant,)*
+                    other => Self::Other(other),
+                }
+            }
+        }
+
+        impl From<$name> for u16 {
+            fn from(value: $name) -> Self {
+                value.code()
+            }
+        }
+    };
+}
+
+error_codes! {
+    /// Janus core error codes, see <https://janus.conf.meetecho.com/docs/rest.html#errors>
+    JanusErrorCode {
+        Unauthorized = 403,
+        UnauthorizedPlugin = 405,
+        TransportSpecific = 450,
+        MissingRequest = 452,
+        UnknownRequest = 453,
+        InvalidJson = 454,
+        InvalidJsonObject = 455,
+        MissingMandatoryElement = 456,
+        InvalidRequestPath = 457,
+        SessionNotFound = 458,
+        HandleNotFound = 459,
+        PluginNotFound = 460,
+        PluginAttach = 461,
+        PluginMessage = 462,
+        PluginDetach = 463,
+        JsepUnknownType = 464,
+        JsepInvalidSdp = 465,
+        TrickleInvalidStream = 466,
+        InvalidElementType = 467,
+        SessionConflict = 468,
+        UnexpectedAnswer = 469,
+        TokenNotFound = 470,
+        WebrtcState = 471,
+        NotAcceptingSessions = 472,
+        Unknown = 490,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Error;
+    use super::JanusErrorCode;
+
+    #[test]
+    fn it_converts_known_codes() {
+        assert_eq!(JanusErrorCode::from(458), JanusErrorCode::SessionNotFound);
+        assert_eq!(JanusErrorCode::SessionNotFound.code(), 458);
+    }
+
+    #[test]
+    fn it_keeps_unknown_codes() {
+        assert_eq!(JanusErrorCode::from(999), JanusErrorCode::Other(999));
+        assert_eq!(u16::from(JanusErrorCode::Other(999)), 999);
+    }
+
+    #[test]
+    fn it_extracts_typed_codes_from_errors() {
+        let error = Error::JanusError {
+            code: 459,
+            reason: "No such handle".to_string(),
+        };
+        assert_eq!(
+            error.janus_error_code(),
+            Some(JanusErrorCode::HandleNotFound)
+        );
+
+        let error = Error::PluginResponseError {
+            error_code: 426,
+            error: "No such room".to_string(),
+        };
+        assert_eq!(error.plugin_error_code::<u16>(), Some(426));
+        assert_eq!(error.janus_error_code(), None);
+    }
+
+    #[cfg(feature = "sdp")]
+    #[test]
+    fn it_prefixes_sdp_errors() {
+        let error = Error::from(crate::japrotocol::sdp::SdpError {
+            line: 3,
+            reason: "missing v= line".to_string(),
+        });
+        assert_eq!(error.to_string(), "Invalid SDP: line 3: missing v= line");
+    }
 }
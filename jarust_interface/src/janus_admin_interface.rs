@@ -0,0 +1,158 @@
+use crate::admin_protocol::AdminMessage;
+use crate::janus_interface::TlsParams;
+use crate::janus_interface::TransportParams;
+use crate::japrotocol::ErrorResponse;
+use crate::tgenerator::GenerateTransaction;
+use crate::Error;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::fmt::Debug;
+use std::ops::Deref;
+use std::sync::Arc;
+use std::time::Duration;
+
+pub struct AdminConnectionParams {
+    /// The url of the janus admin endpoint.
+    pub url: String,
+    /// The capacity of the connection (for the websocket interface).
+    pub capacity: usize,
+    /// The admin secret (if any).
+    pub admin_secret: Option<String>,
+    /// The admin server root, it should match the admin base path of the janus server when choosing the restful interface.
+    pub server_root: String,
+    /// TLS settings for `wss://` and `https://` urls, `None` uses the platform defaults.
+    pub tls: Option<TlsParams>,
+    /// Extra headers, proxy, connect timeout and websocket limits, `None` uses the defaults.
+    pub transport: Option<TransportParams>,
+}
+
+/// [`JanusAdminInterface`] defines the interface for the janus admin/monitor API.
+///
+/// Full docs: <https://janus.conf.meetecho.com/docs/admin.html>
+#[async_trait::async_trait]
+pub trait JanusAdminInterface: Debug + Send + Sync + 'static {
+    /// Constructs a new admin interface with the given connection parameters and transaction generator.
+    async fn make_admin_interface(
+        conn_params: AdminConnectionParams,
+        transaction_generator: impl GenerateTransaction,
+    ) -> Result<Self, Error>
+    where
+        Self: Sized;
+
+    /// Internal method to send an admin request and wait for the raw response.
+    ///
+    /// It's internal for the same reason as [`JanusInterface::internal_send_msg_waiton_rsp`](crate::janus_interface::JanusInterface::internal_send_msg_waiton_rsp),
+    /// see [`JanusAdminInterfaceImpl::send_admin_request`] for the public method.
+    async fn internal_send_admin_request(
+        &self,
+        message: AdminMessage,
+        timeout: Duration,
+    ) -> Result<Value, Error>;
+
+    /// Returns the name of the interface (for the debug trait)
+    fn name(&self) -> Box<str> {
+        "Janus Admin Interface".to_string().into_boxed_str()
+    }
+}
+
+impl dyn JanusAdminInterface {
+    /// Sends an admin request and waits for the response.
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    pub async fn send_admin_request<R>(
+        &self,
+        message: AdminMessage,
+        timeout: Duration,
+    ) -> Result<R, Error>
+    where
+        R: DeserializeOwned,
+    {
+        let response = self.internal_send_admin_request(message, timeout).await?;
+        if let Some(error) = admin_error(&response) {
+            let what = Error::JanusError {
+                code: error.code,
+                reason: error.reason,
+            };
+            tracing::error!("{what}");
+            return Err(what);
+        }
+        match serde_json::from_value::<R>(response) {
+            Ok(result) => Ok(result),
+            Err(error) => {
+                tracing::error!("Failed to parse with error {error:#?}");
+                Err(Error::UnexpectedResponse)
+            }
+        }
+    }
+}
+
+/// Extracts the janus error from an admin response, if any.
+fn admin_error(response: &Value) -> Option<ErrorResponse> {
+    if response["janus"] != "error" {
+        return None;
+    }
+    serde_json::from_value::<ErrorResponse>(response["error"].clone()).ok()
+}
+
+#[derive(Clone)]
+pub struct JanusAdminInterfaceImpl {
+    inner: Arc<dyn JanusAdminInterface>,
+}
+
+impl Deref for JanusAdminInterfaceImpl {
+    type Target = Arc<dyn JanusAdminInterface>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.inner
+    }
+}
+
+impl JanusAdminInterfaceImpl {
+    pub fn new(interface: impl JanusAdminInterface) -> Self {
+        Self {
+            inner: Arc::new(interface),
+        }
+    }
+}
+
+impl Debug for JanusAdminInterfaceImpl {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_tuple("AdminInterface")
+            .field(&self.inner.name())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::admin_error;
+    use crate::japrotocol::ErrorResponse;
+    use serde_json::json;
+
+    #[test]
+    fn it_should_extract_admin_errors() {
+        let rsp = json!({
+            "janus": "error",
+            "transaction": "abc123",
+            "error": {
+                "code": 403,
+                "reason": "Unauthorized request (wrong or missing secret/token)"
+            }
+        });
+        let actual = admin_error(&rsp);
+        let expected = ErrorResponse {
+            code: 403,
+            reason: "Unauthorized request (wrong or missing secret/token)".to_string(),
+        };
+        assert_eq!(actual, Some(expected));
+    }
+
+    #[test]
+    fn it_should_ignore_successful_responses() {
+        let rsp = json!({
+            "janus": "success",
+            "transaction": "abc123",
+            "level": 4
+        });
+        assert_eq!(admin_error(&rsp), None);
+    }
+}
@@ -8,11 +8,13 @@ use crate::japrotocol::ServerInfoRsp;
 use crate::tgenerator::GenerateTransaction;
 use crate::Error;
 use serde::de::DeserializeOwned;
+use serde::Serialize;
 use std::fmt::Debug;
 use std::ops::Deref;
 use std::sync::Arc;
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::sync::watch;
 
 pub struct ConnectionParams {
     /// The url of the janus server.
@@ -23,6 +25,128 @@ pub struct ConnectionParams {
     pub apisecret: Option<String>,
     /// The server root, it should match the server root of the janus server when choosing the restful interface.
     pub server_root: String,
+    /// Reconnection policy (for the websocket interface), `None` disables reconnection.
+    pub reconnect: Option<ReconnectParams>,
+    /// TLS settings for secure urls (`wss://` and `https://`), `None` uses the platform defaults.
+    pub tls: Option<TlsParams>,
+    /// Headers, proxy and timeouts applied when connecting, `None` uses the defaults.
+    pub transport: Option<TransportParams>,
+    /// Stored auth token attached to every request (when janus has `token_auth` enabled), can be rotated with
+    /// [`JanusInterface::set_token`].
+    pub token: Option<String>,
+}
+
+/// Connection options shared by the websocket and the restful interfaces.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct TransportParams {
+    /// Extra headers sent with the websocket handshake and with every http request, e.g. `Authorization` or `Cookie`.
+    pub headers: Vec<(String, String)>,
+    /// Proxy url, either `http://` (CONNECT tunnel) or `socks5://`, credentials can be provided as `user:password@`.
+    pub proxy: Option<String>,
+    /// Maximum time allowed to establish the connection.
+    pub connect_timeout: Option<Duration>,
+    /// Maximum size of an incoming websocket message, `None` keeps the default (64 MiB).
+    pub max_message_size: Option<usize>,
+    /// Maximum size of a single incoming websocket frame, `None` keeps the default (16 MiB).
+    pub max_frame_size: Option<usize>,
+}
+
+/// Optional parameters of the `attach` request.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+pub struct AttachParams {
+    /// Opaque string janus forwards to event handlers and the admin API, useful to correlate handles.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub opaque_id: Option<String>,
+    /// Index of the static event loop to use for the handle, when janus is configured with static loops.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub loop_index: Option<u64>,
+    /// Forces the PeerConnection to bundle the media.
+    #[serde(rename = "force-bundle", skip_serializing_if = "std::ops::Not::not")]
+    pub force_bundle: bool,
+    /// Forces the PeerConnection to multiplex RTP and RTCP.
+    #[serde(rename = "force-rtcp-mux", skip_serializing_if = "std::ops::Not::not")]
+    pub force_rtcp_mux: bool,
+}
+
+/// TLS settings shared by the websocket and the restful interfaces.
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct TlsParams {
+    /// PEM encoded certificates trusted on top of the platform root certificates.
+    pub root_certificates: Vec<Vec<u8>>,
+    /// Client certificate presented to the server for mutual TLS.
+    pub identity: Option<TlsIdentity>,
+    /// Server name used for SNI and certificate verification instead of the url host.
+    pub server_name: Option<String>,
+    /// Accept any server certificate, only meant for test setups using self-signed certificates.
+    pub danger_accept_invalid_certs: bool,
+}
+
+/// PEM encoded client certificate chain and its PKCS#8 private key.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct TlsIdentity {
+    pub certificate_chain: Vec<u8>,
+    pub private_key: Vec<u8>,
+}
+
+impl Debug for TlsIdentity {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TlsIdentity")
+            .field("certificate_chain", &self.certificate_chain.len())
+            .field("private_key", &"<redacted>")
+            .finish()
+    }
+}
+
+/// Reconnection policy used when the underlying transport drops.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub struct ReconnectParams {
+    /// Delay before the first reconnection attempt, it doubles after each failed attempt.
+    pub initial_backoff: Duration,
+    /// Upper bound for the delay between two attempts.
+    pub max_backoff: Duration,
+    /// Maximum number of attempts before giving up, `None` means retry forever.
+    pub max_attempts: Option<u32>,
+    /// Should match janus `reclaim_session_timeout`, sessions are only claimed if the
+    /// connection was restored within this window.
+    pub reclaim_timeout: Duration,
+}
+
+impl Default for ReconnectParams {
+    fn default() -> Self {
+        Self {
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(10),
+            max_attempts: None,
+            reclaim_timeout: Duration::from_secs(60),
+        }
+    }
+}
+
+impl ReconnectParams {
+    /// Delay before the given attempt (starting at 0), doubling each time up to `max_backoff`.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2u32.saturating_pow(attempt);
+        std::cmp::min(
+            self.initial_backoff.saturating_mul(factor),
+            self.max_backoff,
+        )
+    }
+}
+
+/// Health of the underlying transport.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum ConnectionState {
+    /// Dialing the server, either initially or during a reconnection attempt.
+    Connecting,
+    Connected,
+    /// The connection dropped, the first reconnection attempt (if enabled) is pending.
+    Disconnected {
+        reason: String,
+    },
+    /// A reconnection attempt failed, waiting before the next one.
+    Reconnecting,
+    /// The connection is gone for good.
+    Closed,
 }
 
 /// [`JanusInterface`] is the main trait that defines the interface for the janus server.
@@ -50,6 +174,7 @@ pub trait JanusInterface: Debug + Send + Sync + 'static {
         &self,
         session_id: u64,
         plugin_id: String,
+        params: AttachParams,
         timeout: Duration,
     ) -> Result<(u64, mpsc::UnboundedReceiver<JaResponse>), Error>;
 
@@ -62,6 +187,18 @@ pub trait JanusInterface: Debug + Send + Sync + 'static {
     /// Destroys the session.
     async fn destroy(&self, session_id: u64, timeout: Duration) -> Result<(), Error>;
 
+    /// Claims an existing session, so its events are delivered through this interface.
+    ///
+    /// Used to take over a session created by another connection, e.g. before a process restart.
+    async fn claim(&self, session_id: u64, timeout: Duration) -> Result<(), Error>;
+
+    /// Starts routing the events of an already attached handle and returns its event receiver.
+    async fn register_handle(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+    ) -> mpsc::UnboundedReceiver<JaResponse>;
+
     /// Sends a one-shot message
     async fn fire_and_forget_msg(&self, message: HandleMessage) -> Result<String, Error>;
 
@@ -114,6 +251,29 @@ pub trait JanusInterface: Debug + Send + Sync + 'static {
         timeout: Duration,
     ) -> Result<String, Error>;
 
+    /// Notifies the handles of a session that it has expired, by sending
+    /// [`GenericEvent::SessionExpired`](crate::japrotocol::GenericEvent::SessionExpired) to their event receivers.
+    ///
+    /// The default implementation does nothing.
+    async fn notify_session_expired(&self, session_id: u64) {
+        let _ = session_id;
+    }
+
+    /// Subscribes to the connection state.
+    ///
+    /// The receiver starts with the current state and is notified of the changes. The default
+    /// implementation always reports [`ConnectionState::Connected`] for interfaces that don't track their state.
+    fn subscribe_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        watch::channel(ConnectionState::Connected).1
+    }
+
+    /// Replaces the auth token attached to the subsequent requests, `None` stops sending a token.
+    ///
+    /// The default implementation does nothing.
+    fn set_token(&self, token: Option<String>) {
+        let _ = token;
+    }
+
     /// Returns the name of the interface (for the debug trait)
     fn name(&self) -> Box<str> {
         "Janus Interface".to_string().into_boxed_str()
@@ -3,6 +3,9 @@ use serde::Serialize;
 use serde_json::Value;
 use std::collections::HashMap;
 
+#[cfg(feature = "sdp")]
+pub mod sdp;
+
 /// The top-level response
 #[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct JaResponse {
@@ -110,6 +113,9 @@ pub enum GenericEvent {
     },
     #[serde(rename = "trickle")]
     Trickle,
+    /// Not sent by Janus, emitted locally when the keep-alive finds out the session no longer exists.
+    #[serde(rename = "session_expired")]
+    SessionExpired,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
@@ -118,6 +124,10 @@ pub enum JsepType {
     Offer,
     #[serde(rename = "answer")]
     Answer,
+    #[serde(rename = "pranswer")]
+    PrAnswer,
+    #[serde(rename = "rollback")]
+    Rollback,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
@@ -129,6 +139,19 @@ pub struct Jsep {
     pub trickle: Option<bool>,
 }
 
+#[cfg(feature = "sdp")]
+impl Jsep {
+    /// Parse the session description carried by this JSEP
+    pub fn parse_sdp(&self) -> Result<sdp::Sdp, sdp::SdpError> {
+        self.sdp.parse()
+    }
+
+    /// Replace the session description carried by this JSEP
+    pub fn set_sdp(&mut self, sdp: &sdp::Sdp) {
+        self.sdp = sdp.to_string();
+    }
+}
+
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
 pub struct Candidate {
     #[serde(rename = "sdpMid")]
@@ -391,4 +414,19 @@ mod tests {
         };
         assert_eq!(actual_event, expected);
     }
+
+    #[test]
+    fn it_serialize_pranswer_and_rollback_jsep() {
+        let pranswer = Jsep {
+            jsep_type: JsepType::PrAnswer,
+            sdp: "random_sdp".to_string(),
+            trickle: None,
+        };
+        assert_eq!(
+            serde_json::to_value(&pranswer).unwrap(),
+            json!({ "type": "pranswer", "sdp": "random_sdp" })
+        );
+        let rollback = serde_json::from_value::<Jsep>(json!({ "type": "rollback", "sdp": "" }));
+        assert_eq!(rollback.unwrap().jsep_type, JsepType::Rollback);
+    }
 }
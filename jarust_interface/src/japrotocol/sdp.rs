@@ -0,0 +1,1199 @@
+//! Structured view of a session description (RFC 8866)
+//!
+//! [`Sdp`] parses the `sdp` carried by a [`Jsep`](super::Jsep) into session and media sections. Attributes are
+//! kept in order as raw [`Attribute`]s so serializing a parsed description gives back an equivalent one, and the
+//! commonly used ones (mid, rtpmap, fmtp, ssrc, rid, simulcast, ICE, ...) are available through typed accessors.
+//!
+//! Repeat times, time zones and encryption keys (`r=`, `z=` and `k=` lines) are not supported and are dropped.
+//!
+//! ```ignore
+//! let sdp = jsep.parse_sdp()?;
+//! for media in &sdp.media {
+//!     println!("{:?} {:?}", media.mid(), media.rtpmaps());
+//! }
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Sdp {
+    /// `v=` line, always 0
+    pub version: u32,
+    pub origin: Origin,
+    /// `s=` line
+    pub session_name: String,
+    /// `i=` line
+    pub session_information: Option<String>,
+    /// `u=` line
+    pub uri: Option<String>,
+    /// `e=` lines
+    pub emails: Vec<String>,
+    /// `p=` lines
+    pub phones: Vec<String>,
+    pub connection: Option<Connection>,
+    pub bandwidths: Vec<Bandwidth>,
+    pub timings: Vec<Timing>,
+    /// session level `a=` lines
+    pub attributes: Vec<Attribute>,
+    /// media sections, in `m=` line order
+    pub media: Vec<MediaDescription>,
+}
+
+/// Error returned when a session description or one of its attributes can't be parsed
+#[derive(thiserror::Error, Clone, PartialEq, Eq, Debug)]
+#[error("line {line}: {reason}")]
+pub struct SdpError {
+    /// 1-based line number, 0 when not tied to a specific line
+    pub line: usize,
+    pub reason: String,
+}
+
+/// `o=` line
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Origin {
+    pub username: String,
+    pub session_id: u64,
+    pub session_version: u64,
+    pub net_type: String,
+    pub addr_type: String,
+    pub unicast_address: String,
+}
+
+/// `c=` line
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Connection {
+    pub net_type: String,
+    pub addr_type: String,
+    pub address: String,
+}
+
+/// `b=` line
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Bandwidth {
+    pub bwtype: String,
+    pub bandwidth: u64,
+}
+
+/// `t=` line
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Timing {
+    pub start: u64,
+    pub stop: u64,
+}
+
+/// `a=` line, either a property (`a=rtcp-mux`) or a value attribute (`a=mid:0`)
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Attribute {
+    pub name: String,
+    pub value: Option<String>,
+}
+
+/// A media section, starting with an `m=` line
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct MediaDescription {
+    /// media type, e.g. `audio`, `video` or `application`
+    pub media: String,
+    pub port: u16,
+    pub num_ports: Option<u16>,
+    /// transport protocol, e.g. `UDP/TLS/RTP/SAVPF`
+    pub protocol: String,
+    /// payload types for RTP media, or the format for data channels
+    pub formats: Vec<String>,
+    /// `i=` line
+    pub title: Option<String>,
+    pub connection: Option<Connection>,
+    pub bandwidths: Vec<Bandwidth>,
+    pub attributes: Vec<Attribute>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum Direction {
+    SendRecv,
+    SendOnly,
+    RecvOnly,
+    Inactive,
+}
+
+/// `a=rtpmap:<payload type> <encoding name>/<clock rate>[/<encoding parameters>]`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rtpmap {
+    pub payload_type: u8,
+    pub encoding_name: String,
+    pub clock_rate: u32,
+    /// number of channels for audio
+    pub encoding_params: Option<String>,
+}
+
+/// `a=fmtp:<payload type> <format specific parameters>`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Fmtp {
+    pub payload_type: u8,
+    pub parameters: String,
+}
+
+/// `a=ssrc:<ssrc> <attribute>[:<value>]`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Ssrc {
+    pub ssrc: u32,
+    pub attribute: String,
+    pub value: Option<String>,
+}
+
+/// `a=ssrc-group:<semantics> <ssrc>...`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SsrcGroup {
+    pub semantics: String,
+    pub ssrcs: Vec<u32>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum RidDirection {
+    Send,
+    Recv,
+}
+
+/// `a=rid:<id> <direction> [<restrictions>]`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Rid {
+    pub id: String,
+    pub direction: RidDirection,
+    pub restrictions: Option<String>,
+}
+
+/// A single rid in a simulcast stream list, `~` prefixed when paused
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct SimulcastRid {
+    pub id: String,
+    pub paused: bool,
+}
+
+/// `a=simulcast:[send <streams>] [recv <streams>]`
+///
+/// Each stream is a list of alternative rids.
+#[derive(Clone, PartialEq, Eq, Hash, Debug, Default)]
+pub struct Simulcast {
+    pub send: Vec<Vec<SimulcastRid>>,
+    pub recv: Vec<Vec<SimulcastRid>>,
+}
+
+/// `a=group:<semantics> <mid>...`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Group {
+    pub semantics: String,
+    pub mids: Vec<String>,
+}
+
+/// `a=fingerprint:<hash function> <fingerprint>`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct Fingerprint {
+    pub hash_function: String,
+    pub fingerprint: String,
+}
+
+/// `a=candidate:<foundation> <component> <transport> <priority> <address> <port> typ <type> [<name> <value>]...`
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct IceCandidate {
+    pub foundation: String,
+    pub component: u32,
+    pub transport: String,
+    pub priority: u64,
+    pub address: String,
+    pub port: u16,
+    /// `host`, `srflx`, `prflx` or `relay`
+    pub typ: String,
+    /// trailing name/value pairs such as `raddr`, `rport` or `generation`
+    pub extensions: Vec<(String, String)>,
+}
+
+impl Sdp {
+    /// Value of the first session level attribute with this name
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        find_attribute(&self.attributes, name)
+    }
+
+    pub fn groups(&self) -> Vec<Group> {
+        parse_attributes(&self.attributes, "group")
+    }
+
+    pub fn ice_ufrag(&self) -> Option<&str> {
+        self.attribute("ice-ufrag")
+    }
+
+    pub fn ice_pwd(&self) -> Option<&str> {
+        self.attribute("ice-pwd")
+    }
+
+    /// Space separated ICE options, e.g. `trickle`
+    pub fn ice_options(&self) -> Vec<&str> {
+        self.attribute("ice-options")
+            .map(|options| options.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    pub fn ice_lite(&self) -> bool {
+        self.attribute_flag("ice-lite")
+    }
+
+    pub fn fingerprint(&self) -> Option<Fingerprint> {
+        parse_attributes(&self.attributes, "fingerprint").pop()
+    }
+
+    /// The media section with this mid
+    pub fn media_by_mid(&self, mid: &str) -> Option<&MediaDescription> {
+        self.media.iter().find(|media| media.mid() == Some(mid))
+    }
+
+    fn attribute_flag(&self, name: &str) -> bool {
+        self.attributes.iter().any(|a| a.name == name)
+    }
+}
+
+impl MediaDescription {
+    /// Value of the first attribute with this name
+    pub fn attribute(&self, name: &str) -> Option<&str> {
+        find_attribute(&self.attributes, name)
+    }
+
+    pub fn mid(&self) -> Option<&str> {
+        self.attribute("mid")
+    }
+
+    /// Media direction, defaults to [`Direction::SendRecv`] when not specified
+    pub fn direction(&self) -> Direction {
+        self.attributes
+            .iter()
+            .find_map(|a| match a.name.as_str() {
+                "sendrecv" => Some(Direction::SendRecv),
+                "sendonly" => Some(Direction::SendOnly),
+                "recvonly" => Some(Direction::RecvOnly),
+                "inactive" => Some(Direction::Inactive),
+                _ => None,
+            })
+            .unwrap_or(Direction::SendRecv)
+    }
+
+    pub fn rtpmaps(&self) -> Vec<Rtpmap> {
+        parse_attributes(&self.attributes, "rtpmap")
+    }
+
+    pub fn rtpmap(&self, payload_type: u8) -> Option<Rtpmap> {
+        self.rtpmaps()
+            .into_iter()
+            .find(|rtpmap| rtpmap.payload_type == payload_type)
+    }
+
+    pub fn fmtps(&self) -> Vec<Fmtp> {
+        parse_attributes(&self.attributes, "fmtp")
+    }
+
+    pub fn fmtp(&self, payload_type: u8) -> Option<Fmtp> {
+        self.fmtps()
+            .into_iter()
+            .find(|fmtp| fmtp.payload_type == payload_type)
+    }
+
+    pub fn ssrcs(&self) -> Vec<Ssrc> {
+        parse_attributes(&self.attributes, "ssrc")
+    }
+
+    pub fn ssrc_groups(&self) -> Vec<SsrcGroup> {
+        parse_attributes(&self.attributes, "ssrc-group")
+    }
+
+    pub fn rids(&self) -> Vec<Rid> {
+        parse_attributes(&self.attributes, "rid")
+    }
+
+    pub fn simulcast(&self) -> Option<Simulcast> {
+        parse_attributes(&self.attributes, "simulcast").pop()
+    }
+
+    pub fn ice_ufrag(&self) -> Option<&str> {
+        self.attribute("ice-ufrag")
+    }
+
+    pub fn ice_pwd(&self) -> Option<&str> {
+        self.attribute("ice-pwd")
+    }
+
+    pub fn candidates(&self) -> Vec<IceCandidate> {
+        parse_attributes(&self.attributes, "candidate")
+    }
+
+    pub fn end_of_candidates(&self) -> bool {
+        self.attributes
+            .iter()
+            .any(|a| a.name == "end-of-candidates")
+    }
+
+    pub fn fingerprint(&self) -> Option<Fingerprint> {
+        parse_attributes(&self.attributes, "fingerprint").pop()
+    }
+
+    pub fn rtcp_mux(&self) -> bool {
+        self.attributes.iter().any(|a| a.name == "rtcp-mux")
+    }
+}
+
+impl Attribute {
+    pub fn property(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            value: None,
+        }
+    }
+
+    pub fn value(name: impl Into<String>, value: impl ToString) -> Self {
+        Self {
+            name: name.into(),
+            value: Some(value.to_string()),
+        }
+    }
+}
+
+fn find_attribute<'a>(attributes: &'a [Attribute], name: &str) -> Option<&'a str> {
+    attributes
+        .iter()
+        .find(|a| a.name == name)
+        .and_then(|a| a.value.as_deref())
+}
+
+/// Parse every attribute with this name, malformed ones are skipped
+fn parse_attributes<T: FromStr>(attributes: &[Attribute], name: &str) -> Vec<T> {
+    attributes
+        .iter()
+        .filter(|a| a.name == name)
+        .filter_map(|a| a.value.as_deref()?.parse().ok())
+        .collect()
+}
+
+fn invalid(line: usize, reason: impl Into<String>) -> SdpError {
+    SdpError {
+        line,
+        reason: reason.into(),
+    }
+}
+
+/// Attribute values don't know on which line they are, they're reported as line 0
+fn invalid_value(reason: impl Into<String>) -> SdpError {
+    invalid(0, reason)
+}
+
+fn parse_number<T: FromStr>(value: Option<&str>, what: &str) -> Result<T, SdpError> {
+    value
+        .and_then(|value| value.parse().ok())
+        .ok_or_else(|| invalid_value(format!("invalid {what}")))
+}
+
+fn parse_word(value: Option<&str>, what: &str) -> Result<String, SdpError> {
+    value
+        .map(ToString::to_string)
+        .ok_or_else(|| invalid_value(format!("missing {what}")))
+}
+
+impl FromStr for Sdp {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut version = None;
+        let mut origin = None;
+        let mut session_name = None;
+        let mut session_information = None;
+        let mut uri = None;
+        let mut emails = vec![];
+        let mut phones = vec![];
+        let mut connection = None;
+        let mut bandwidths = vec![];
+        let mut timings = vec![];
+        let mut attributes = vec![];
+        let mut media: Vec<MediaDescription> = vec![];
+
+        for (index, line) in s.lines().enumerate() {
+            let number = index + 1;
+            let line = line.trim_end_matches('\r');
+            if line.is_empty() {
+                continue;
+            }
+            let (kind, value) = line
+                .split_once('=')
+                .filter(|(kind, _)| kind.len() == 1)
+                .ok_or_else(|| invalid(number, "expected <type>=<value>"))?;
+            let at_line = |error: SdpError| invalid(number, error.reason);
+
+            if kind == "m" {
+                media.push(value.parse().map_err(at_line)?);
+                continue;
+            }
+            if let Some(current) = media.last_mut() {
+                match kind {
+                    "i" => current.title = Some(value.to_string()),
+                    "c" => current.connection = Some(value.parse().map_err(at_line)?),
+                    "b" => current.bandwidths.push(value.parse().map_err(at_line)?),
+                    "a" => current.attributes.push(value.parse().map_err(at_line)?),
+                    _ => {}
+                }
+                continue;
+            }
+            match kind {
+                "v" => version = Some(parse_number(Some(value), "version").map_err(at_line)?),
+                "o" => origin = Some(value.parse().map_err(at_line)?),
+                "s" => session_name = Some(value.to_string()),
+                "i" => session_information = Some(value.to_string()),
+                "u" => uri = Some(value.to_string()),
+                "e" => emails.push(value.to_string()),
+                "p" => phones.push(value.to_string()),
+                "c" => connection = Some(value.parse().map_err(at_line)?),
+                "b" => bandwidths.push(value.parse().map_err(at_line)?),
+                "t" => timings.push(value.parse().map_err(at_line)?),
+                "a" => attributes.push(value.parse().map_err(at_line)?),
+                _ => {}
+            }
+        }
+
+        Ok(Self {
+            version: version.ok_or_else(|| invalid(0, "missing v= line"))?,
+            origin: origin.ok_or_else(|| invalid(0, "missing o= line"))?,
+            session_name: session_name.ok_or_else(|| invalid(0, "missing s= line"))?,
+            session_information,
+            uri,
+            emails,
+            phones,
+            connection,
+            bandwidths,
+            timings,
+            attributes,
+            media,
+        })
+    }
+}
+
+impl fmt::Display for Sdp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v={}\r\n", self.version)?;
+        write!(f, "o={}\r\n", self.origin)?;
+        write!(f, "s={}\r\n", self.session_name)?;
+        if let Some(information) = &self.session_information {
+            write!(f, "i={information}\r\n")?;
+        }
+        if let Some(uri) = &self.uri {
+            write!(f, "u={uri}\r\n")?;
+        }
+        for email in &self.emails {
+            write!(f, "e={email}\r\n")?;
+        }
+        for phone in &self.phones {
+            write!(f, "p={phone}\r\n")?;
+        }
+        if let Some(connection) = &self.connection {
+            write!(f, "c={connection}\r\n")?;
+        }
+        for bandwidth in &self.bandwidths {
+            write!(f, "b={bandwidth}\r\n")?;
+        }
+        for timing in &self.timings {
+            write!(f, "t={timing}\r\n")?;
+        }
+        for attribute in &self.attributes {
+            write!(f, "a={attribute}\r\n")?;
+        }
+        for media in &self.media {
+            write!(f, "{media}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Origin {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        Ok(Self {
+            username: parse_word(parts.next(), "origin username")?,
+            session_id: parse_number(parts.next(), "origin session id")?,
+            session_version: parse_number(parts.next(), "origin session version")?,
+            net_type: parse_word(parts.next(), "origin network type")?,
+            addr_type: parse_word(parts.next(), "origin address type")?,
+            unicast_address: parse_word(parts.next(), "origin address")?,
+        })
+    }
+}
+
+impl fmt::Display for Origin {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {}",
+            self.username,
+            self.session_id,
+            self.session_version,
+            self.net_type,
+            self.addr_type,
+            self.unicast_address
+        )
+    }
+}
+
+impl FromStr for Connection {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        Ok(Self {
+            net_type: parse_word(parts.next(), "connection network type")?,
+            addr_type: parse_word(parts.next(), "connection address type")?,
+            address: parse_word(parts.next(), "connection address")?,
+        })
+    }
+}
+
+impl fmt::Display for Connection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.net_type, self.addr_type, self.address)
+    }
+}
+
+impl FromStr for Bandwidth {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (bwtype, bandwidth) = s
+            .split_once(':')
+            .ok_or_else(|| invalid_value("expected <bwtype>:<bandwidth>"))?;
+        Ok(Self {
+            bwtype: bwtype.to_string(),
+            bandwidth: parse_number(Some(bandwidth), "bandwidth")?,
+        })
+    }
+}
+
+impl fmt::Display for Bandwidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.bwtype, self.bandwidth)
+    }
+}
+
+impl FromStr for Timing {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        Ok(Self {
+            start: parse_number(parts.next(), "start time")?,
+            stop: parse_number(parts.next(), "stop time")?,
+        })
+    }
+}
+
+impl fmt::Display for Timing {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.start, self.stop)
+    }
+}
+
+impl FromStr for Attribute {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(match s.split_once(':') {
+            Some((name, value)) => Self::value(name, value),
+            None => Self::property(s),
+        })
+    }
+}
+
+impl fmt::Display for Attribute {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.value {
+            Some(value) => write!(f, "{}:{}", self.name, value),
+            None => write!(f, "{}", self.name),
+        }
+    }
+}
+
+impl FromStr for MediaDescription {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        let media = parse_word(parts.next(), "media type")?;
+        let port = parse_word(parts.next(), "media port")?;
+        let (port, num_ports) = match port.split_once('/') {
+            Some((port, num_ports)) => (
+                parse_number(Some(port), "media port")?,
+                Some(parse_number(Some(num_ports), "number of ports")?),
+            ),
+            None => (parse_number(Some(&port), "media port")?, None),
+        };
+        let protocol = parse_word(parts.next(), "media protocol")?;
+        Ok(Self {
+            media,
+            port,
+            num_ports,
+            protocol,
+            formats: parts.map(ToString::to_string).collect(),
+            title: None,
+            connection: None,
+            bandwidths: vec![],
+            attributes: vec![],
+        })
+    }
+}
+
+impl fmt::Display for MediaDescription {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "m={} {}", self.media, self.port)?;
+        if let Some(num_ports) = self.num_ports {
+            write!(f, "/{num_ports}")?;
+        }
+        write!(f, " {}", self.protocol)?;
+        for format in &self.formats {
+            write!(f, " {format}")?;
+        }
+        write!(f, "\r\n")?;
+        if let Some(title) = &self.title {
+            write!(f, "i={title}\r\n")?;
+        }
+        if let Some(connection) = &self.connection {
+            write!(f, "c={connection}\r\n")?;
+        }
+        for bandwidth in &self.bandwidths {
+            write!(f, "b={bandwidth}\r\n")?;
+        }
+        for attribute in &self.attributes {
+            write!(f, "a={attribute}\r\n")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Direction::SendRecv => "sendrecv",
+            Direction::SendOnly => "sendonly",
+            Direction::RecvOnly => "recvonly",
+            Direction::Inactive => "inactive",
+        })
+    }
+}
+
+impl FromStr for Rtpmap {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (payload_type, encoding) = s
+            .split_once(' ')
+            .ok_or_else(|| invalid_value("expected <payload type> <encoding>"))?;
+        let mut encoding = encoding.trim().splitn(3, '/');
+        Ok(Self {
+            payload_type: parse_number(Some(payload_type), "payload type")?,
+            encoding_name: parse_word(encoding.next(), "encoding name")?,
+            clock_rate: parse_number(encoding.next(), "clock rate")?,
+            encoding_params: encoding.next().map(ToString::to_string),
+        })
+    }
+}
+
+impl fmt::Display for Rtpmap {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {}/{}",
+            self.payload_type, self.encoding_name, self.clock_rate
+        )?;
+        if let Some(params) = &self.encoding_params {
+            write!(f, "/{params}")?;
+        }
+        Ok(())
+    }
+}
+
+impl Fmtp {
+    /// Value of a `key=value` parameter, e.g. `profile-level-id`
+    pub fn parameter(&self, key: &str) -> Option<&str> {
+        self.parameters.split(';').find_map(|parameter| {
+            let (k, v) = parameter.trim().split_once('=')?;
+            (k == key).then_some(v)
+        })
+    }
+}
+
+impl FromStr for Fmtp {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (payload_type, parameters) = s
+            .split_once(' ')
+            .ok_or_else(|| invalid_value("expected <payload type> <parameters>"))?;
+        Ok(Self {
+            payload_type: parse_number(Some(payload_type), "payload type")?,
+            parameters: parameters.to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Fmtp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.payload_type, self.parameters)
+    }
+}
+
+impl FromStr for Ssrc {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (ssrc, attribute) = s
+            .split_once(' ')
+            .ok_or_else(|| invalid_value("expected <ssrc> <attribute>"))?;
+        let (attribute, value) = match attribute.split_once(':') {
+            Some((attribute, value)) => (attribute, Some(value.to_string())),
+            None => (attribute, None),
+        };
+        Ok(Self {
+            ssrc: parse_number(Some(ssrc), "ssrc")?,
+            attribute: attribute.to_string(),
+            value,
+        })
+    }
+}
+
+impl fmt::Display for Ssrc {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.ssrc, self.attribute)?;
+        if let Some(value) = &self.value {
+            write!(f, ":{value}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for SsrcGroup {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        Ok(Self {
+            semantics: parse_word(parts.next(), "ssrc group semantics")?,
+            ssrcs: parts
+                .map(|ssrc| parse_number(Some(ssrc), "ssrc"))
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl fmt::Display for SsrcGroup {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.semantics)?;
+        for ssrc in &self.ssrcs {
+            write!(f, " {ssrc}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Rid {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.splitn(3, ' ');
+        let id = parse_word(parts.next(), "rid id")?;
+        let direction = match parts.next() {
+            Some("send") => RidDirection::Send,
+            Some("recv") => RidDirection::Recv,
+            _ => return Err(invalid_value("invalid rid direction")),
+        };
+        Ok(Self {
+            id,
+            direction,
+            restrictions: parts.next().map(ToString::to_string),
+        })
+    }
+}
+
+impl fmt::Display for Rid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let direction = match self.direction {
+            RidDirection::Send => "send",
+            RidDirection::Recv => "recv",
+        };
+        write!(f, "{} {}", self.id, direction)?;
+        if let Some(restrictions) = &self.restrictions {
+            write!(f, " {restrictions}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Simulcast {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_streams = |streams: &str| -> Vec<Vec<SimulcastRid>> {
+            streams
+                .split(';')
+                .map(|alternatives| {
+                    alternatives
+                        .split(',')
+                        .map(|rid| match rid.strip_prefix('~') {
+                            Some(id) => SimulcastRid {
+                                id: id.to_string(),
+                                paused: true,
+                            },
+                            None => SimulcastRid {
+                                id: rid.to_string(),
+                                paused: false,
+                            },
+                        })
+                        .collect()
+                })
+                .collect()
+        };
+
+        let mut simulcast = Simulcast::default();
+        let mut parts = s.split_whitespace();
+        while let Some(direction) = parts.next() {
+            let streams = parts
+                .next()
+                .ok_or_else(|| invalid_value("missing simulcast streams"))?;
+            match direction {
+                "send" => simulcast.send = parse_streams(streams),
+                "recv" => simulcast.recv = parse_streams(streams),
+                _ => return Err(invalid_value("invalid simulcast direction")),
+            }
+        }
+        Ok(simulcast)
+    }
+}
+
+impl fmt::Display for Simulcast {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let format_streams = |streams: &Vec<Vec<SimulcastRid>>| {
+            streams
+                .iter()
+                .map(|alternatives| {
+                    alternatives
+                        .iter()
+                        .map(|rid| match rid.paused {
+                            true => format!("~{}", rid.id),
+                            false => rid.id.clone(),
+                        })
+                        .collect::<Vec<_>>()
+                        .join(",")
+                })
+                .collect::<Vec<_>>()
+                .join(";")
+        };
+
+        let mut directions = vec![];
+        if !self.send.is_empty() {
+            directions.push(format!("send {}", format_streams(&self.send)));
+        }
+        if !self.recv.is_empty() {
+            directions.push(format!("recv {}", format_streams(&self.recv)));
+        }
+        write!(f, "{}", directions.join(" "))
+    }
+}
+
+impl FromStr for Group {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split_whitespace();
+        Ok(Self {
+            semantics: parse_word(parts.next(), "group semantics")?,
+            mids: parts.map(ToString::to_string).collect(),
+        })
+    }
+}
+
+impl fmt::Display for Group {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.semantics)?;
+        for mid in &self.mids {
+            write!(f, " {mid}")?;
+        }
+        Ok(())
+    }
+}
+
+impl FromStr for Fingerprint {
+    type Err = SdpError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (hash_function, fingerprint) = s
+            .split_once(' ')
+            .ok_or_else(|| invalid_value("expected <hash function> <fingerprint>"))?;
+        Ok(Self {
+            hash_function: hash_function.to_string(),
+            fingerprint: fingerprint.trim().to_string(),
+        })
+    }
+}
+
+impl fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}", self.hash_function, self.fingerprint)
+    }
+}
+
+impl FromStr for IceCandidate {
+    type Err = SdpError;
+
+    /// Accepts the attribute value, as well as the `candidate:` prefixed string used when trickling
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.strip_prefix("candidate:").unwrap_or(s);
+        let mut parts = s.split_whitespace();
+        let foundation = parse_word(parts.next(), "candidate foundation")?;
+        let component = parse_number(parts.next(), "candidate component")?;
+        let transport = parse_word(parts.next(), "candidate transport")?;
+        let priority = parse_number(parts.next(), "candidate priority")?;
+        let address = parse_word(parts.next(), "candidate address")?;
+        let port = parse_number(parts.next(), "candidate port")?;
+        if parts.next() != Some("typ") {
+            return Err(invalid_value("missing candidate type"));
+        }
+        let typ = parse_word(parts.next(), "candidate type")?;
+        let mut extensions = vec![];
+        while let (Some(name), Some(value)) = (parts.next(), parts.next()) {
+            extensions.push((name.to_string(), value.to_string()));
+        }
+        Ok(Self {
+            foundation,
+            component,
+            transport,
+            priority,
+            address,
+            port,
+            typ,
+            extensions,
+        })
+    }
+}
+
+impl fmt::Display for IceCandidate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} {} {} {} {} {} typ {}",
+            self.foundation,
+            self.component,
+            self.transport,
+            self.priority,
+            self.address,
+            self.port,
+            self.typ
+        )?;
+        for (name, value) in &self.extensions {
+            write!(f, " {name} {value}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OFFER: &str = "v=0\r\n\
+        o=- 4327261771880257373 2 IN IP4 127.0.0.1\r\n\
+        s=-\r\n\
+        t=0 0\r\n\
+        a=group:BUNDLE 0 1\r\n\
+        a=ice-options:trickle\r\n\
+        a=msid-semantic: WMS stream\r\n\
+        m=audio 9 UDP/TLS/RTP/SAVPF 111 0\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        a=rtcp:9 IN IP4 0.0.0.0\r\n\
+        a=ice-ufrag:someufrag\r\n\
+        a=ice-pwd:somepassword\r\n\
+        a=fingerprint:sha-256 D2:FA:0E:C3:22:59:5E:14\r\n\
+        a=setup:actpass\r\n\
+        a=mid:0\r\n\
+        a=sendrecv\r\n\
+        a=rtcp-mux\r\n\
+        a=rtpmap:111 opus/48000/2\r\n\
+        a=fmtp:111 minptime=10;useinbandfec=1\r\n\
+        a=rtpmap:0 PCMU/8000\r\n\
+        a=ssrc:1001 cname:audiocname\r\n\
+        a=candidate:1 1 udp 2113937151 192.168.1.2 51000 typ host generation 0\r\n\
+        a=end-of-candidates\r\n\
+        m=video 9 UDP/TLS/RTP/SAVPF 96 97\r\n\
+        c=IN IP4 0.0.0.0\r\n\
+        b=AS:1024\r\n\
+        a=mid:1\r\n\
+        a=sendonly\r\n\
+        a=rtpmap:96 VP8/90000\r\n\
+        a=rtpmap:97 rtx/90000\r\n\
+        a=fmtp:97 apt=96\r\n\
+        a=ssrc-group:FID 2001 2002\r\n\
+        a=ssrc:2001 cname:videocname\r\n\
+        a=ssrc:2002 cname:videocname\r\n\
+        a=rid:h send\r\n\
+        a=rid:l send max-width=320\r\n\
+        a=simulcast:send h;~l\r\n";
+
+    #[test]
+    fn it_parse_session() {
+        let sdp: Sdp = OFFER.parse().unwrap();
+        assert_eq!(sdp.version, 0);
+        assert_eq!(
+            sdp.origin,
+            Origin {
+                username: "-".to_string(),
+                session_id: 4327261771880257373,
+                session_version: 2,
+                net_type: "IN".to_string(),
+                addr_type: "IP4".to_string(),
+                unicast_address: "127.0.0.1".to_string(),
+            }
+        );
+        assert_eq!(sdp.timings, vec![Timing { start: 0, stop: 0 }]);
+        assert_eq!(
+            sdp.groups(),
+            vec![Group {
+                semantics: "BUNDLE".to_string(),
+                mids: vec!["0".to_string(), "1".to_string()],
+            }]
+        );
+        assert_eq!(sdp.ice_options(), vec!["trickle"]);
+        assert_eq!(sdp.media.len(), 2);
+    }
+
+    #[test]
+    fn it_parse_audio_section() {
+        let sdp: Sdp = OFFER.parse().unwrap();
+        let audio = sdp.media_by_mid("0").unwrap();
+        assert_eq!(audio.media, "audio");
+        assert_eq!(audio.port, 9);
+        assert_eq!(audio.protocol, "UDP/TLS/RTP/SAVPF");
+        assert_eq!(audio.formats, vec!["111", "0"]);
+        assert_eq!(audio.direction(), Direction::SendRecv);
+        assert!(audio.rtcp_mux());
+        assert_eq!(audio.ice_ufrag(), Some("someufrag"));
+        assert_eq!(audio.ice_pwd(), Some("somepassword"));
+        assert_eq!(
+            audio.fingerprint(),
+            Some(Fingerprint {
+                hash_function: "sha-256".to_string(),
+                fingerprint: "D2:FA:0E:C3:22:59:5E:14".to_string(),
+            })
+        );
+        assert_eq!(
+            audio.rtpmap(111),
+            Some(Rtpmap {
+                payload_type: 111,
+                encoding_name: "opus".to_string(),
+                clock_rate: 48000,
+                encoding_params: Some("2".to_string()),
+            })
+        );
+        assert_eq!(
+            audio.fmtp(111).unwrap().parameter("useinbandfec"),
+            Some("1")
+        );
+        assert_eq!(
+            audio.ssrcs(),
+            vec![Ssrc {
+                ssrc: 1001,
+                attribute: "cname".to_string(),
+                value: Some("audiocname".to_string()),
+            }]
+        );
+        assert_eq!(
+            audio.candidates(),
+            vec![IceCandidate {
+                foundation: "1".to_string(),
+                component: 1,
+                transport: "udp".to_string(),
+                priority: 2113937151,
+                address: "192.168.1.2".to_string(),
+                port: 51000,
+                typ: "host".to_string(),
+                extensions: vec![("generation".to_string(), "0".to_string())],
+            }]
+        );
+        assert!(audio.end_of_candidates());
+    }
+
+    #[test]
+    fn it_parse_simulcast_video_section() {
+        let sdp: Sdp = OFFER.parse().unwrap();
+        let video = sdp.media_by_mid("1").unwrap();
+        assert_eq!(video.direction(), Direction::SendOnly);
+        assert_eq!(
+            video.bandwidths,
+            vec![Bandwidth {
+                bwtype: "AS".to_string(),
+                bandwidth: 1024,
+            }]
+        );
+        assert_eq!(video.rtpmaps().len(), 2);
+        assert_eq!(
+            video.ssrc_groups(),
+            vec![SsrcGroup {
+                semantics: "FID".to_string(),
+                ssrcs: vec![2001, 2002],
+            }]
+        );
+        assert_eq!(
+            video.rids(),
+            vec![
+                Rid {
+                    id: "h".to_string(),
+                    direction: RidDirection::Send,
+                    restrictions: None,
+                },
+                Rid {
+                    id: "l".to_string(),
+                    direction: RidDirection::Send,
+                    restrictions: Some("max-width=320".to_string()),
+                }
+            ]
+        );
+        assert_eq!(
+            video.simulcast(),
+            Some(Simulcast {
+                send: vec![
+                    vec![SimulcastRid {
+                        id: "h".to_string(),
+                        paused: false,
+                    }],
+                    vec![SimulcastRid {
+                        id: "l".to_string(),
+                        paused: true,
+                    }]
+                ],
+                recv: vec![],
+            })
+        );
+    }
+
+    #[test]
+    fn it_serialize_back_to_the_same_sdp() {
+        let sdp: Sdp = OFFER.parse().unwrap();
+        assert_eq!(sdp.to_string(), OFFER);
+    }
+
+    #[test]
+    fn it_serialize_typed_attributes() {
+        let simulcast: Simulcast = "send h,m;~l recv r".parse().unwrap();
+        assert_eq!(simulcast.to_string(), "send h,m;~l recv r");
+        let candidate: IceCandidate =
+            "candidate:842163049 1 udp 1677729535 1.2.3.4 46154 typ srflx raddr 0.0.0.0 rport 0"
+                .parse()
+                .unwrap();
+        assert_eq!(
+            Attribute::value("candidate", candidate).to_string(),
+            "candidate:842163049 1 udp 1677729535 1.2.3.4 46154 typ srflx raddr 0.0.0.0 rport 0"
+        );
+    }
+
+    #[test]
+    fn it_reports_the_invalid_line() {
+        let result = "v=0\r\no=- abc 2 IN IP4 127.0.0.1\r\ns=-\r\n".parse::<Sdp>();
+        assert!(matches!(result, Err(SdpError { line: 2, .. })));
+    }
+}
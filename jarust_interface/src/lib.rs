@@ -5,11 +5,14 @@
 //! - Transport abstraction, you can use the built-in WebSocket interface, restful interface, or bring your own.
 //! - Transaction generation abstraction, you can use the built-in transaction generator or bring your own.
 //! - DTOs for the Janus API.
+//! - Admin/Monitor API abstraction with restful and WebSocket transports.
 //! - Errors
 //!
 
+pub mod admin_protocol;
 pub mod error;
 pub mod handle_msg;
+pub mod janus_admin_interface;
 pub mod janus_interface;
 pub mod japrotocol;
 pub mod restful;
@@ -1,3 +1,5 @@
+pub mod restful_admin_interface;
 pub mod restful_interface;
 
+pub use restful_admin_interface::RestfulAdminInterface;
 pub use restful_interface::RestfulInterface;
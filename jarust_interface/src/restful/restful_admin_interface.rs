@@ -0,0 +1,189 @@
+use crate::admin_protocol::AdminMessage;
+use crate::janus_admin_interface::AdminConnectionParams;
+use crate::janus_admin_interface::JanusAdminInterface;
+use crate::restful::restful_interface::make_client;
+use crate::tgenerator::GenerateTransaction;
+use crate::tgenerator::TransactionGenerator;
+use crate::Error;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+
+#[derive(Debug)]
+struct Shared {
+    admin_secret: Option<String>,
+    transaction_generator: TransactionGenerator,
+    client: reqwest::Client,
+    url: String,
+}
+
+#[derive(Debug)]
+struct InnerRestfulAdminInterface {
+    shared: Shared,
+}
+
+#[derive(Debug, Clone)]
+pub struct RestfulAdminInterface {
+    inner: Arc<InnerRestfulAdminInterface>,
+}
+
+impl RestfulAdminInterface {
+    fn decorate_request(&self, mut request: Value) -> (Value, String) {
+        let transaction = self
+            .inner
+            .shared
+            .transaction_generator
+            .generate_transaction();
+        if let Some(admin_secret) = self.inner.shared.admin_secret.clone() {
+            request["admin_secret"] = admin_secret.into();
+        };
+        request["transaction"] = transaction.clone().into();
+        (request, transaction)
+    }
+}
+
+#[async_trait::async_trait]
+impl JanusAdminInterface for RestfulAdminInterface {
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn make_admin_interface(
+        conn_params: AdminConnectionParams,
+        transaction_generator: impl GenerateTransaction,
+    ) -> Result<Self, Error> {
+        tracing::debug!("Creating new Restful Admin Interface");
+        let (client, url) = make_client(
+            &conn_params.url,
+            conn_params.tls.as_ref(),
+            conn_params.transport.as_ref(),
+        )
+        .await?;
+        let transaction_generator = TransactionGenerator::new(transaction_generator);
+        let shared = Shared {
+            admin_secret: conn_params.admin_secret,
+            transaction_generator,
+            client,
+            url: format!("{}/{}", url, conn_params.server_root),
+        };
+        let inner = InnerRestfulAdminInterface { shared };
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn internal_send_admin_request(
+        &self,
+        message: AdminMessage,
+        timeout: Duration,
+    ) -> Result<Value, Error> {
+        let url = &self.inner.shared.url;
+        let url = match (message.session_id, message.handle_id) {
+            (Some(session_id), Some(handle_id)) => format!("{url}/{session_id}/{handle_id}"),
+            (Some(session_id), None) => format!("{url}/{session_id}"),
+            _ => url.to_string(),
+        };
+        let (request, _) = self.decorate_request(message.body);
+        tracing::trace!("Sending {request:#?}");
+
+        let response = self
+            .inner
+            .shared
+            .client
+            .post(url)
+            .json(&request)
+            .timeout(timeout)
+            .send()
+            .await?
+            .json::<Value>()
+            .await?;
+        Ok(response)
+    }
+
+    fn name(&self) -> Box<str> {
+        "Restful Admin Interface".to_string().into_boxed_str()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RestfulAdminInterface;
+    use crate::admin_protocol::AdminMessage;
+    use crate::janus_admin_interface::AdminConnectionParams;
+    use crate::janus_admin_interface::JanusAdminInterface;
+    use crate::janus_interface::TlsParams;
+    use crate::janus_interface::TransportParams;
+    use crate::tgenerator::RandomTransactionGenerator;
+    use crate::Error;
+    use serde_json::json;
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    fn conn_params(url: String) -> AdminConnectionParams {
+        AdminConnectionParams {
+            url,
+            capacity: 10,
+            admin_secret: None,
+            server_root: "admin".to_string(),
+            tls: None,
+            transport: None,
+        }
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_tls_params() {
+        let mut params = conn_params("https://127.0.0.1:7889".to_string());
+        params.tls = Some(TlsParams {
+            root_certificates: vec![
+                b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n".to_vec(),
+            ],
+            ..Default::default()
+        });
+        let result =
+            RestfulAdminInterface::make_admin_interface(params, RandomTransactionGenerator).await;
+        assert!(matches!(result, Err(Error::InvalidTlsConfig { .. })));
+    }
+
+    #[tokio::test]
+    async fn it_sends_the_transport_headers() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let mut params = conn_params(format!("http://{}", listener.local_addr().unwrap()));
+        params.transport = Some(TransportParams {
+            headers: vec![("Authorization".to_string(), "Bearer secret".to_string())],
+            ..Default::default()
+        });
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut head = vec![];
+            while !head.ends_with(b"\r\n\r\n") {
+                head.push(socket.read_u8().await.unwrap());
+            }
+            let body = r#"{"admin":"success"}"#;
+            let response = format!(
+                "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{body}",
+                body.len()
+            );
+            socket.write_all(response.as_bytes()).await.unwrap();
+            String::from_utf8(head).unwrap().to_lowercase()
+        });
+
+        let interface =
+            RestfulAdminInterface::make_admin_interface(params, RandomTransactionGenerator)
+                .await
+                .unwrap();
+        interface
+            .internal_send_admin_request(
+                AdminMessage {
+                    body: json!({ "janus": "get_status" }),
+                    session_id: None,
+                    handle_id: None,
+                },
+                Duration::from_secs(1),
+            )
+            .await
+            .unwrap();
+        let head = server.await.unwrap();
+        assert!(head.starts_with("post /admin http/1.1\r\n"));
+        assert!(head.contains("authorization: bearer secret\r\n"));
+    }
+}
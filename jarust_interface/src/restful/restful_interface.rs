@@ -1,7 +1,16 @@
+use crate::error::JanusErrorCode;
 use crate::handle_msg::HandleMessage;
 use crate::handle_msg::HandleMessageWithJsep;
+use crate::janus_interface::AttachParams;
 use crate::janus_interface::ConnectionParams;
+use crate::janus_interface::ConnectionState;
 use crate::janus_interface::JanusInterface;
+#[cfg(not(target_family = "wasm"))]
+use crate::janus_interface::TlsIdentity;
+use crate::janus_interface::TlsParams;
+use crate::janus_interface::TransportParams;
+use crate::japrotocol::GenericEvent;
+use crate::japrotocol::JaHandleEvent;
 use crate::japrotocol::JaResponse;
 use crate::japrotocol::JaSuccessProtocol;
 use crate::japrotocol::ResponseType;
@@ -10,24 +19,255 @@ use crate::tgenerator::GenerateTransaction;
 use crate::tgenerator::TransactionGenerator;
 use crate::Error;
 use jarust_rt::JaTask;
+#[cfg(not(target_family = "wasm"))]
+use reqwest::header::HeaderMap;
+#[cfg(not(target_family = "wasm"))]
+use reqwest::header::HeaderName;
 use serde_json::json;
 use serde_json::Value;
+use std::collections::HashMap;
+use std::ops::ControlFlow;
 use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::Weak;
 use std::time::Duration;
 use tokio::sync::mpsc;
+use tokio::sync::watch;
 use tokio::sync::Mutex;
 
+/// Delay before retrying the first failed long poll.
+const LONG_POLL_INITIAL_BACKOFF: Duration = Duration::from_millis(500);
+/// Upper bound of the long poll backoff, so polling resumes within 10s once the server is back.
+const LONG_POLL_MAX_BACKOFF: Duration = Duration::from_secs(10);
+
+/// Delay before retrying the long poll after `failures` consecutive failures (starting at 0),
+/// doubling each time up to [`LONG_POLL_MAX_BACKOFF`].
+fn long_poll_backoff(failures: u32) -> Duration {
+    let factor = 2u32.saturating_pow(failures);
+    std::cmp::min(
+        LONG_POLL_INITIAL_BACKOFF.saturating_mul(factor),
+        LONG_POLL_MAX_BACKOFF,
+    )
+}
+
+/// Derives the connection state from the requests outcome as there is no persistent connection.
+///
+/// Reports [`ConnectionState::Connecting`] until the first request succeeds.
+#[derive(Debug)]
+struct ConnectionStateTracker {
+    sender: watch::Sender<ConnectionState>,
+}
+
+impl ConnectionStateTracker {
+    fn new() -> Self {
+        let (sender, _) = watch::channel(ConnectionState::Connecting);
+        Self { sender }
+    }
+
+    fn track<T>(&self, result: Result<T, reqwest::Error>) -> Result<T, reqwest::Error> {
+        match result {
+            Ok(value) => {
+                self.sender.send_if_modified(|state| {
+                    let changed = *state != ConnectionState::Connected;
+                    *state = ConnectionState::Connected;
+                    changed
+                });
+                Ok(value)
+            }
+            Err(what) => {
+                if what.is_connect() {
+                    self.sender.send_if_modified(|state| {
+                        let changed = *state == ConnectionState::Connected;
+                        if changed {
+                            *state = ConnectionState::Disconnected {
+                                reason: what.to_string(),
+                            };
+                        }
+                        changed
+                    });
+                }
+                Err(what)
+            }
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Shared {
     apisecret: Option<String>,
+    /// Shared with the long-poll tasks so a rotated token is used on their next request
+    token: Arc<RwLock<Option<String>>>,
     transaction_generator: TransactionGenerator,
     client: reqwest::Client,
     url: String,
+    state: Arc<ConnectionStateTracker>,
 }
 
 #[derive(Debug)]
 struct Exclusive {
-    tasks: Vec<JaTask>,
+    /// One long-poll task per session
+    pollers: HashMap<u64, JaTask>,
+    /// Handles event channels, keyed by session id then handle id
+    handles: HashMap<u64, HashMap<u64, mpsc::UnboundedSender<JaResponse>>>,
+}
+
+impl Exclusive {
+    /// Routes a long-poll event to the handle that sent it, session wide events go to every handle of the session.
+    ///
+    /// Breaks when the session is over, either because it timed out or because janus no longer knows about it.
+    fn route(&mut self, session_id: u64, event: JaResponse) -> ControlFlow<()> {
+        let Some(handles) = self.handles.get_mut(&session_id) else {
+            return ControlFlow::Break(());
+        };
+        match (&event.janus, event.sender) {
+            (ResponseType::Error { error }, _)
+                if JanusErrorCode::from(error.code) == JanusErrorCode::SessionNotFound =>
+            {
+                let expired = session_expired(session_id);
+                for handle in handles.values() {
+                    let _ = handle.send(expired.clone());
+                }
+                self.handles.remove(&session_id);
+                ControlFlow::Break(())
+            }
+            (ResponseType::Event(JaHandleEvent::GenericEvent(GenericEvent::Timeout)), None) => {
+                for handle in handles.values() {
+                    let _ = handle.send(event.clone());
+                }
+                self.handles.remove(&session_id);
+                ControlFlow::Break(())
+            }
+            (
+                ResponseType::Event(JaHandleEvent::GenericEvent(GenericEvent::Detached)),
+                Some(sender),
+            ) => {
+                if let Some(handle) = handles.remove(&sender) {
+                    let _ = handle.send(event);
+                }
+                ControlFlow::Continue(())
+            }
+            (_, Some(sender)) => {
+                if let Some(handle) = handles.get(&sender) {
+                    if handle.send(event).is_err() {
+                        handles.remove(&sender);
+                    }
+                }
+                ControlFlow::Continue(())
+            }
+            (_, None) => {
+                handles.retain(|_, handle| handle.send(event.clone()).is_ok());
+                ControlFlow::Continue(())
+            }
+        }
+    }
+}
+
+/// Builds the http client honoring the TLS and transport params, returns the url to use with it
+///
+/// When a TLS server name is set, requests target that name (used for SNI and certificate verification)
+/// while still being resolved to the original host addresses.
+#[cfg(not(target_family = "wasm"))]
+pub(crate) async fn make_client(
+    url: &str,
+    tls: Option<&TlsParams>,
+    transport: Option<&TransportParams>,
+) -> Result<(reqwest::Client, String), Error> {
+    let mut builder = reqwest::Client::builder();
+    let mut url = url.to_string();
+
+    #[cfg(feature = "use-rustls")]
+    {
+        // reqwest doesn't pick a crypto provider on its own, use the rustls default one unless the
+        // application already installed its own
+        let _ = rustls::crypto::aws_lc_rs::default_provider().install_default();
+        builder = builder.use_rustls_tls();
+    }
+
+    if let Some(transport) = transport {
+        let mut headers = HeaderMap::new();
+        for (name, value) in &transport.headers {
+            headers.append(HeaderName::try_from(name.as_str())?, value.parse()?);
+        }
+        builder = builder.default_headers(headers);
+        if let Some(proxy) = &transport.proxy {
+            let proxy = reqwest::Proxy::all(proxy).map_err(|what| Error::ProxyError {
+                reason: what.to_string(),
+            })?;
+            builder = builder.proxy(proxy);
+        }
+        if let Some(connect_timeout) = transport.connect_timeout {
+            builder = builder.connect_timeout(connect_timeout);
+        }
+    }
+
+    if let Some(tls) = tls {
+        let invalid = |what: reqwest::Error| Error::InvalidTlsConfig {
+            reason: what.to_string(),
+        };
+        builder = builder
+            .danger_accept_invalid_certs(tls.danger_accept_invalid_certs)
+            .danger_accept_invalid_hostnames(tls.danger_accept_invalid_certs);
+        for certificate in &tls.root_certificates {
+            for certificate in
+                reqwest::Certificate::from_pem_bundle(certificate).map_err(invalid)?
+            {
+                builder = builder.add_root_certificate(certificate);
+            }
+        }
+        if let Some(identity) = &tls.identity {
+            builder = builder.identity(make_identity(identity).map_err(invalid)?);
+        }
+        if let Some(server_name) = &tls.server_name {
+            let mut parsed = reqwest::Url::parse(&url).map_err(|what| Error::InvalidTlsConfig {
+                reason: what.to_string(),
+            })?;
+            if parsed.scheme() == "https" {
+                let host = parsed.host_str().unwrap_or_default().to_string();
+                let port = parsed.port_or_known_default().unwrap_or(443);
+                let addrs = tokio::net::lookup_host((host.as_str(), port))
+                    .await?
+                    .collect::<Vec<_>>();
+                builder = builder.resolve_to_addrs(server_name, &addrs);
+                parsed
+                    .set_host(Some(server_name))
+                    .map_err(|what| Error::InvalidTlsConfig {
+                        reason: what.to_string(),
+                    })?;
+                url = parsed.as_str().trim_end_matches('/').to_string();
+            }
+        }
+    }
+    Ok((builder.build()?, url))
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "use-native-tls"))]
+fn make_identity(identity: &TlsIdentity) -> Result<reqwest::Identity, reqwest::Error> {
+    reqwest::Identity::from_pkcs8_pem(&identity.certificate_chain, &identity.private_key)
+}
+
+#[cfg(all(not(target_family = "wasm"), feature = "use-rustls"))]
+fn make_identity(identity: &TlsIdentity) -> Result<reqwest::Identity, reqwest::Error> {
+    // rustls expects the certificate chain and the private key in the same PEM buffer
+    reqwest::Identity::from_pem(&[&identity.certificate_chain[..], &identity.private_key].concat())
+}
+
+#[cfg(target_family = "wasm")]
+pub(crate) async fn make_client(
+    url: &str,
+    _: Option<&TlsParams>,
+    _: Option<&TransportParams>,
+) -> Result<(reqwest::Client, String), Error> {
+    Ok((reqwest::Client::new(), url.to_string()))
+}
+
+fn session_expired(session_id: u64) -> JaResponse {
+    JaResponse {
+        janus: ResponseType::Event(JaHandleEvent::GenericEvent(GenericEvent::SessionExpired)),
+        transaction: None,
+        session_id: Some(session_id),
+        sender: None,
+        jsep: None,
+    }
 }
 
 #[derive(Debug)]
@@ -51,9 +291,83 @@ impl RestfulInterface {
         if let Some(apisecret) = self.inner.shared.apisecret.clone() {
             request["apisecret"] = apisecret.into();
         };
+        if let Some(token) = self.inner.shared.token.read().unwrap().clone() {
+            request["token"] = token.into();
+        };
         request["transaction"] = transaction.clone().into();
         (request, transaction)
     }
+
+    async fn execute(&self, request: reqwest::RequestBuilder) -> Result<reqwest::Response, Error> {
+        Ok(self.inner.shared.state.track(request.send().await)?)
+    }
+
+    /// Single long-poll loop of a session, demultiplexing the events to the session handles
+    ///
+    /// Only holds a weak reference to the interface so dropping it stops the loop.
+    #[tracing::instrument(level = tracing::Level::TRACE, skip(inner))]
+    async fn long_poll(inner: Weak<InnerResultfulInterface>, session_id: u64) {
+        let Some((client, url, state, apisecret, token)) = inner.upgrade().map(|inner| {
+            (
+                inner.shared.client.clone(),
+                inner.shared.url.clone(),
+                inner.shared.state.clone(),
+                inner.shared.apisecret.clone(),
+                inner.shared.token.clone(),
+            )
+        }) else {
+            return;
+        };
+
+        // Back off on failures so an unreachable server doesn't turn the loop into a busy spin
+        let mut failures = 0;
+        loop {
+            // GET requests carry the credentials in the query string
+            let mut query = vec![("maxev", "5".to_string())];
+            if let Some(apisecret) = &apisecret {
+                query.push(("apisecret", apisecret.clone()));
+            }
+            if let Some(token) = token.read().unwrap().clone() {
+                query.push(("token", token));
+            }
+            let response = state.track(
+                client
+                    .get(format!("{url}/{session_id}"))
+                    .query(&query)
+                    .send()
+                    .await,
+            );
+            let response = match response {
+                Ok(response) => response.json::<Value>().await.ok(),
+                Err(_) => None,
+            };
+            // Janus replies with an array of events, or a single object for keep-alives and errors
+            let events = match response {
+                Some(Value::Array(events)) => events,
+                Some(event) => vec![event],
+                None => {
+                    tokio::time::sleep(long_poll_backoff(failures)).await;
+                    failures = failures.saturating_add(1);
+                    continue;
+                }
+            };
+            failures = 0;
+            let Some(inner) = inner.upgrade() else {
+                return;
+            };
+            let mut exclusive = inner.exclusive.lock().await;
+            for event in events {
+                let Ok(event) = serde_json::from_value::<JaResponse>(event) else {
+                    continue;
+                };
+                if exclusive.route(session_id, event).is_break() {
+                    tracing::debug!("Session is over, stopping long polling");
+                    exclusive.pollers.remove(&session_id);
+                    return;
+                }
+            }
+        }
+    }
 }
 
 #[async_trait::async_trait]
@@ -64,15 +378,26 @@ impl JanusInterface for RestfulInterface {
         transaction_generator: impl GenerateTransaction,
     ) -> Result<Self, Error> {
         tracing::debug!("Creating new Restful Interface");
-        let client = reqwest::Client::new();
+        let (client, url) = make_client(
+            &conn_params.url,
+            conn_params.tls.as_ref(),
+            conn_params.transport.as_ref(),
+        )
+        .await?;
         let transaction_generator = TransactionGenerator::new(transaction_generator);
+        let state = Arc::new(ConnectionStateTracker::new());
         let shared = Shared {
             apisecret: conn_params.apisecret,
+            token: Arc::new(RwLock::new(conn_params.token)),
             transaction_generator,
             client,
-            url: format!("{}/{}", conn_params.url, conn_params.server_root),
+            url: format!("{}/{}", url, conn_params.server_root),
+            state,
+        };
+        let exclusive = Exclusive {
+            pollers: HashMap::new(),
+            handles: HashMap::new(),
         };
-        let exclusive = Exclusive { tasks: Vec::new() };
         let inner = InnerResultfulInterface {
             shared,
             exclusive: Mutex::new(exclusive),
@@ -89,13 +414,14 @@ impl JanusInterface for RestfulInterface {
         let (request, _) = self.decorate_request(request);
 
         let response = self
-            .inner
-            .shared
-            .client
-            .post(url.to_string())
-            .json(&request)
-            .timeout(timeout)
-            .send()
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .post(url.to_string())
+                    .json(&request)
+                    .timeout(timeout),
+            )
             .await?
             .json::<JaResponse>()
             .await?;
@@ -122,12 +448,13 @@ impl JanusInterface for RestfulInterface {
     async fn server_info(&self, timeout: Duration) -> Result<ServerInfoRsp, Error> {
         let url = &self.inner.shared.url;
         let response = self
-            .inner
-            .shared
-            .client
-            .get(format!("{url}/info"))
-            .timeout(timeout)
-            .send()
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .get(format!("{url}/info"))
+                    .timeout(timeout),
+            )
             .await?
             .json::<JaResponse>()
             .await?;
@@ -146,23 +473,24 @@ impl JanusInterface for RestfulInterface {
         &self,
         session_id: u64,
         plugin_id: String,
+        params: AttachParams,
         timeout: Duration,
     ) -> Result<(u64, mpsc::UnboundedReceiver<JaResponse>), Error> {
         let url = &self.inner.shared.url;
-        let request = json!({
-            "janus": "attach",
-            "plugin": plugin_id
-        });
+        let mut request = serde_json::to_value(params)?;
+        request["janus"] = "attach".into();
+        request["plugin"] = plugin_id.into();
         let (request, _) = self.decorate_request(request);
 
         let response = self
-            .inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}"))
-            .json(&request)
-            .timeout(timeout)
-            .send()
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .post(format!("{url}/{session_id}"))
+                    .json(&request)
+                    .timeout(timeout),
+            )
             .await?
             .json::<JaResponse>()
             .await?;
@@ -181,32 +509,8 @@ impl JanusInterface for RestfulInterface {
                 return Err(Error::UnexpectedResponse);
             }
         };
-        let (tx, rx) = mpsc::unbounded_channel();
-
-        let handle = jarust_rt::spawn("Long polling", {
-            let client = self.inner.shared.client.clone();
-            let url = url.clone();
-
-            async move {
-                loop {
-                    if let Ok(response) = client
-                        .get(format!("{url}/{session_id}?maxev=5"))
-                        .send()
-                        .await
-                    {
-                        if let Ok(res) = response.json::<Vec<JaResponse>>().await {
-                            for r in res {
-                                let _ = tx.send(r);
-                            }
-                        }
-                    };
-                }
-            }
-        });
-
-        self.inner.exclusive.lock().await.tasks.push(handle);
-
-        Ok((handle_id, rx))
+        let receiver = self.register_handle(session_id, handle_id).await;
+        Ok((handle_id, receiver))
     }
 
     fn has_keep_alive(&self) -> bool {
@@ -225,17 +529,79 @@ impl JanusInterface for RestfulInterface {
         });
         let (request, _) = self.decorate_request(request);
 
-        self.inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}"))
-            .json(&request)
-            .timeout(timeout)
-            .send()
-            .await?;
+        self.execute(
+            self.inner
+                .shared
+                .client
+                .post(format!("{url}/{session_id}"))
+                .json(&request)
+                .timeout(timeout),
+        )
+        .await?;
+        let mut exclusive = self.inner.exclusive.lock().await;
+        exclusive.handles.remove(&session_id);
+        exclusive.pollers.remove(&session_id);
         Ok(())
     }
 
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn claim(&self, session_id: u64, timeout: Duration) -> Result<(), Error> {
+        let url = &self.inner.shared.url;
+        let request = json!({
+            "janus": "claim"
+        });
+        let (request, _) = self.decorate_request(request);
+
+        let response = self
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .post(format!("{url}/{session_id}"))
+                    .json(&request)
+                    .timeout(timeout),
+            )
+            .await?
+            .json::<JaResponse>()
+            .await?;
+        match response.janus {
+            ResponseType::Success(_) => Ok(()),
+            ResponseType::Error { error } => {
+                let what = Error::JanusError {
+                    code: error.code,
+                    reason: error.reason,
+                };
+                tracing::error!("{what}");
+                Err(what)
+            }
+            _ => {
+                tracing::error!("Unexpected response");
+                Err(Error::UnexpectedResponse)
+            }
+        }
+    }
+
+    async fn register_handle(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+    ) -> mpsc::UnboundedReceiver<JaResponse> {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let mut exclusive = self.inner.exclusive.lock().await;
+        exclusive
+            .handles
+            .entry(session_id)
+            .or_default()
+            .insert(handle_id, tx);
+        exclusive.pollers.entry(session_id).or_insert_with(|| {
+            jarust_rt::spawn(
+                "Long polling",
+                Self::long_poll(Arc::downgrade(&self.inner), session_id),
+            )
+        });
+        rx
+    }
+
     #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
     async fn fire_and_forget_msg(&self, message: HandleMessage) -> Result<String, Error> {
         let url = &self.inner.shared.url;
@@ -247,13 +613,14 @@ impl JanusInterface for RestfulInterface {
             "body": message.body
         });
         let (request, transaction) = self.decorate_request(request);
-        self.inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .send()
-            .await?;
+        self.execute(
+            self.inner
+                .shared
+                .client
+                .post(format!("{url}/{session_id}/{handle_id}"))
+                .json(&request),
+        )
+        .await?;
         Ok(transaction)
     }
 
@@ -272,16 +639,17 @@ impl JanusInterface for RestfulInterface {
             "body": message.body
         });
         let (request, transaction) = self.decorate_request(request);
-        self.inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .timeout(timeout)
-            .send()
-            .await?
-            .json::<JaResponse>()
-            .await?;
+        self.execute(
+            self.inner
+                .shared
+                .client
+                .post(format!("{url}/{session_id}/{handle_id}"))
+                .json(&request)
+                .timeout(timeout),
+        )
+        .await?
+        .json::<JaResponse>()
+        .await?;
         Ok(transaction)
     }
 
@@ -300,13 +668,14 @@ impl JanusInterface for RestfulInterface {
         });
         let (request, _) = self.decorate_request(request);
         let response = self
-            .inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .timeout(timeout)
-            .send()
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .post(format!("{url}/{session_id}/{handle_id}"))
+                    .json(&request)
+                    .timeout(timeout),
+            )
             .await?
             .json::<JaResponse>()
             .await?;
@@ -328,13 +697,14 @@ impl JanusInterface for RestfulInterface {
             "jsep": message.jsep
         });
         let (request, transaction) = self.decorate_request(request);
-        self.inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .send()
-            .await?;
+        self.execute(
+            self.inner
+                .shared
+                .client
+                .post(format!("{url}/{session_id}/{handle_id}"))
+                .json(&request),
+        )
+        .await?;
         Ok(transaction)
     }
 
@@ -354,16 +724,17 @@ impl JanusInterface for RestfulInterface {
             "jsep": message.jsep
         });
         let (request, transaction) = self.decorate_request(request);
-        self.inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .timeout(timeout)
-            .send()
-            .await?
-            .json::<JaResponse>()
-            .await?;
+        self.execute(
+            self.inner
+                .shared
+                .client
+                .post(format!("{url}/{session_id}/{handle_id}"))
+                .json(&request)
+                .timeout(timeout),
+        )
+        .await?
+        .json::<JaResponse>()
+        .await?;
         Ok(transaction)
     }
 
@@ -374,12 +745,13 @@ impl JanusInterface for RestfulInterface {
 
         let (request, _) = self.decorate_request(request.body);
         _ = self
-            .inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .send()
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .post(format!("{url}/{session_id}/{handle_id}"))
+                    .json(&request),
+            )
             .await?
             .json::<JaResponse>()
             .await?;
@@ -397,28 +769,297 @@ impl JanusInterface for RestfulInterface {
 
         let (request, transaction) = self.decorate_request(request.body);
         _ = self
-            .inner
-            .shared
-            .client
-            .post(format!("{url}/{session_id}/{handle_id}"))
-            .json(&request)
-            .timeout(timeout)
-            .send()
+            .execute(
+                self.inner
+                    .shared
+                    .client
+                    .post(format!("{url}/{session_id}/{handle_id}"))
+                    .json(&request)
+                    .timeout(timeout),
+            )
             .await?
             .json::<JaResponse>()
             .await?;
         Ok(transaction)
     }
 
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn notify_session_expired(&self, session_id: u64) {
+        let message = session_expired(session_id);
+        let mut exclusive = self.inner.exclusive.lock().await;
+        exclusive.pollers.remove(&session_id);
+        let senders = exclusive.handles.remove(&session_id).unwrap_or_default();
+        for sender in senders.into_values() {
+            let _ = sender.send(message.clone());
+        }
+    }
+
+    fn subscribe_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.inner.shared.state.sender.subscribe()
+    }
+
+    fn set_token(&self, token: Option<String>) {
+        *self.inner.shared.token.write().unwrap() = token;
+    }
+
     fn name(&self) -> Box<str> {
         "Restful Interface".to_string().into_boxed_str()
     }
 }
 
+impl Drop for InnerResultfulInterface {
+    fn drop(&mut self) {
+        self.shared
+            .state
+            .sender
+            .send_replace(ConnectionState::Closed);
+    }
+}
+
 impl Drop for Exclusive {
     fn drop(&mut self) {
-        for task in self.tasks.drain(..) {
-            task.cancel();
+        for (_, poller) in self.pollers.drain() {
+            poller.cancel();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::long_poll_backoff;
+    use super::make_client;
+    use super::ConnectionStateTracker;
+    use super::Exclusive;
+    use super::RestfulInterface;
+    use crate::janus_interface::ConnectionParams;
+    use crate::janus_interface::ConnectionState;
+    use crate::janus_interface::JanusInterface;
+    use crate::janus_interface::TlsIdentity;
+    use crate::janus_interface::TlsParams;
+    use crate::japrotocol::ErrorResponse;
+    use crate::japrotocol::GenericEvent;
+    use crate::japrotocol::JaHandleEvent;
+    use crate::japrotocol::JaResponse;
+    use crate::japrotocol::ResponseType;
+    use crate::tgenerator::RandomTransactionGenerator;
+    use crate::Error;
+    use serde_json::json;
+    use std::collections::HashMap;
+    use std::ops::ControlFlow;
+    use std::time::Duration;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+    use tokio::net::TcpStream;
+    use tokio::sync::mpsc;
+
+    const CERTIFICATE: &[u8] = include_bytes!("../../testdata/cert.pem");
+    const PRIVATE_KEY: &[u8] = include_bytes!("../../testdata/key.pem");
+    const INVALID_PEM: &[u8] =
+        b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n";
+
+    fn event(janus: ResponseType, sender: Option<u64>) -> JaResponse {
+        JaResponse {
+            janus,
+            transaction: None,
+            session_id: Some(1),
+            sender,
+            jsep: None,
+        }
+    }
+
+    /// Reads the head of the next http request and returns its request line along with the socket
+    async fn read_request(listener: &TcpListener) -> (String, TcpStream) {
+        let (mut socket, _) = listener.accept().await.unwrap();
+        let mut head = vec![];
+        while !head.ends_with(b"\r\n\r\n") {
+            head.push(socket.read_u8().await.unwrap());
         }
+        let head = String::from_utf8(head).unwrap();
+        (head.lines().next().unwrap().to_string(), socket)
+    }
+
+    fn generic(event: GenericEvent) -> ResponseType {
+        ResponseType::Event(JaHandleEvent::GenericEvent(event))
+    }
+
+    #[test]
+    fn it_routes_events_by_sender() {
+        let mut exclusive = Exclusive {
+            pollers: HashMap::new(),
+            handles: HashMap::new(),
+        };
+        let (tx_one, mut rx_one) = mpsc::unbounded_channel();
+        let (tx_two, mut rx_two) = mpsc::unbounded_channel();
+        let handles = exclusive.handles.entry(1).or_default();
+        handles.insert(10, tx_one);
+        handles.insert(20, tx_two);
+
+        let webrtcup = event(generic(GenericEvent::WebrtcUp), Some(20));
+        assert_eq!(
+            exclusive.route(1, webrtcup.clone()),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(rx_two.try_recv().unwrap(), webrtcup);
+        assert!(rx_one.try_recv().is_err());
+
+        let detached = event(generic(GenericEvent::Detached), Some(10));
+        assert_eq!(
+            exclusive.route(1, detached.clone()),
+            ControlFlow::Continue(())
+        );
+        assert_eq!(rx_one.try_recv().unwrap(), detached);
+        assert!(!exclusive.handles[&1].contains_key(&10));
+    }
+
+    #[test]
+    fn it_stops_when_the_session_is_gone() {
+        let mut exclusive = Exclusive {
+            pollers: HashMap::new(),
+            handles: HashMap::new(),
+        };
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        exclusive.handles.entry(1).or_default().insert(10, tx);
+
+        let error = event(
+            ResponseType::Error {
+                error: ErrorResponse {
+                    code: 458,
+                    reason: "No such session 1".to_string(),
+                },
+            },
+            None,
+        );
+        assert_eq!(exclusive.route(1, error), ControlFlow::Break(()));
+        assert_eq!(
+            rx.try_recv().unwrap().janus,
+            generic(GenericEvent::SessionExpired)
+        );
+        assert!(exclusive.handles.is_empty());
+    }
+
+    #[test]
+    fn it_caps_the_long_poll_backoff() {
+        assert_eq!(long_poll_backoff(0), Duration::from_millis(500));
+        assert_eq!(long_poll_backoff(1), Duration::from_secs(1));
+        assert_eq!(long_poll_backoff(4), Duration::from_secs(8));
+        assert_eq!(long_poll_backoff(5), Duration::from_secs(10));
+        assert_eq!(long_poll_backoff(u32::MAX), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn it_tracks_the_connection_state() {
+        let tracker = ConnectionStateTracker::new();
+        let mut state = tracker.sender.subscribe();
+        assert_eq!(*state.borrow_and_update(), ConnectionState::Connecting);
+
+        // Nothing listens on port 1, the request fails to connect
+        let refused = || reqwest::Client::new().get("http://127.0.0.1:1").send();
+        // Still connecting until a request goes through
+        assert!(tracker.track(refused().await).is_err());
+        assert!(!state.has_changed().unwrap());
+
+        assert!(tracker.track(Ok(())).is_ok());
+        assert_eq!(*state.borrow_and_update(), ConnectionState::Connected);
+
+        assert!(tracker.track(refused().await).is_err());
+        assert!(state.has_changed().unwrap());
+        assert!(matches!(
+            *state.borrow_and_update(),
+            ConnectionState::Disconnected { .. }
+        ));
+
+        // A subscriber joining late sees the current state
+        let late = tracker.sender.subscribe();
+        assert!(matches!(
+            *late.borrow(),
+            ConnectionState::Disconnected { .. }
+        ));
+
+        assert!(tracker.track(Ok(())).is_ok());
+        assert_eq!(*state.borrow_and_update(), ConnectionState::Connected);
+
+        // Staying connected doesn't notify
+        assert!(tracker.track(Ok(())).is_ok());
+        assert!(!state.has_changed().unwrap());
+    }
+
+    #[tokio::test]
+    async fn it_makes_a_client_with_tls_params() {
+        let tls = TlsParams {
+            root_certificates: vec![CERTIFICATE.to_vec()],
+            identity: Some(TlsIdentity {
+                certificate_chain: CERTIFICATE.to_vec(),
+                private_key: PRIVATE_KEY.to_vec(),
+            }),
+            server_name: Some("janus.local".to_string()),
+            ..Default::default()
+        };
+        let (_, url) = make_client("https://127.0.0.1:8089/janus", Some(&tls), None)
+            .await
+            .unwrap();
+        assert_eq!(url, "https://janus.local:8089/janus");
+    }
+
+    #[tokio::test]
+    async fn it_rejects_invalid_tls_params() {
+        let invalid_root = TlsParams {
+            root_certificates: vec![INVALID_PEM.to_vec()],
+            ..Default::default()
+        };
+        let result = make_client("https://127.0.0.1:8089/janus", Some(&invalid_root), None).await;
+        assert!(matches!(result, Err(Error::InvalidTlsConfig { .. })));
+
+        let invalid_identity = TlsParams {
+            identity: Some(TlsIdentity {
+                certificate_chain: CERTIFICATE.to_vec(),
+                private_key: INVALID_PEM.to_vec(),
+            }),
+            ..Default::default()
+        };
+        let result = make_client(
+            "https://127.0.0.1:8089/janus",
+            Some(&invalid_identity),
+            None,
+        )
+        .await;
+        assert!(matches!(result, Err(Error::InvalidTlsConfig { .. })));
+    }
+
+    #[tokio::test]
+    async fn it_attaches_the_rotated_token_to_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let params = ConnectionParams {
+            url: format!("http://{}", listener.local_addr().unwrap()),
+            capacity: 10,
+            apisecret: None,
+            server_root: "janus".to_string(),
+            reconnect: None,
+            tls: None,
+            transport: None,
+            token: Some("first".to_string()),
+        };
+        let interface = RestfulInterface::make_interface(params, RandomTransactionGenerator)
+            .await
+            .unwrap();
+        let (request, _) = interface.decorate_request(json!({ "janus": "create" }));
+        assert_eq!(request["token"], "first");
+
+        let _events = interface.register_handle(42, 1).await;
+        let (request_line, mut socket) = read_request(&listener).await;
+        assert!(request_line.starts_with("GET /janus/42?maxev=5&token=first "));
+
+        interface.set_token(Some("second".to_string()));
+        let (request, _) = interface.decorate_request(json!({ "janus": "create" }));
+        assert_eq!(request["token"], "second");
+
+        // Answer the pending long poll so the next one picks up the new token
+        socket
+            .write_all(b"HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: 2\r\nconnection: close\r\n\r\n[]")
+            .await
+            .unwrap();
+        drop(socket);
+        let (request_line, _) = read_request(&listener).await;
+        assert!(request_line.starts_with("GET /janus/42?maxev=5&token=second "));
     }
 }
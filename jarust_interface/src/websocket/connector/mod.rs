@@ -12,4 +12,112 @@ mod adapter;
 #[path = "rustls_adapter.rs"]
 mod adapter;
 
-pub use adapter::connect_async;
+mod proxy;
+
+use crate::janus_interface::TlsParams;
+use crate::janus_interface::TransportParams;
+use crate::Error;
+use tokio::net::TcpStream;
+use tokio_tungstenite::tungstenite::handshake::client::Request;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
+use tokio_tungstenite::MaybeTlsStream;
+use tokio_tungstenite::WebSocketStream;
+
+/// Opens the socket (directly or through the proxy), then performs the TLS and websocket handshakes
+pub async fn connect_async(
+    request: Request,
+    tls: Option<&TlsParams>,
+    transport: Option<&TransportParams>,
+) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, Error> {
+    let transport = transport.cloned().unwrap_or_default();
+    let mut config = WebSocketConfig::default();
+    if let Some(max_message_size) = transport.max_message_size {
+        config = config.max_message_size(Some(max_message_size));
+    }
+    if let Some(max_frame_size) = transport.max_frame_size {
+        config = config.max_frame_size(Some(max_frame_size));
+    }
+
+    let uri = request.uri();
+    let Some(host) = uri.host().map(|host| unbracket(host).to_string()) else {
+        return Err(Error::InvalidJanusRequest {
+            reason: format!("missing host in url {uri}"),
+        });
+    };
+    let port = uri
+        .port_u16()
+        .unwrap_or(if uri.scheme_str() == Some("wss") {
+            443
+        } else {
+            80
+        });
+
+    let connect = async {
+        let socket = match &transport.proxy {
+            Some(proxy) => proxy::tunnel(proxy, &host, port).await?,
+            None => TcpStream::connect((host.as_str(), port)).await?,
+        };
+        adapter::handshake(request, socket, &host, tls, config).await
+    };
+
+    match transport.connect_timeout {
+        Some(timeout) => tokio::time::timeout(timeout, connect)
+            .await
+            .map_err(|_| std::io::Error::from(std::io::ErrorKind::TimedOut))?,
+        None => connect.await,
+    }
+}
+
+/// Strips the brackets around IPv6 literals, like tungstenite's `domain()` does
+pub(crate) fn unbracket(host: &str) -> &str {
+    host.strip_prefix('[')
+        .and_then(|host| host.strip_suffix(']'))
+        .unwrap_or(host)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::connect_async;
+    use crate::janus_interface::TlsParams;
+    use crate::Error;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+    use tokio_tungstenite::tungstenite::handshake::client::Request;
+
+    #[tokio::test]
+    async fn it_fails_with_invalid_tls_config() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let request = format!("wss://{}", listener.local_addr().unwrap())
+            .into_client_request()
+            .unwrap();
+        let tls = TlsParams {
+            root_certificates: vec![
+                b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n".to_vec(),
+            ],
+            ..Default::default()
+        };
+        let result = connect_async(request, Some(&tls), None).await;
+        assert!(matches!(result, Err(Error::InvalidTlsConfig { .. })));
+    }
+
+    #[tokio::test]
+    async fn it_connects_to_an_ipv6_literal() {
+        let listener = TcpListener::bind("[::1]:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let server = tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            tokio_tungstenite::accept_async(socket).await.unwrap()
+        });
+
+        let request = format!("ws://[::1]:{port}").into_client_request().unwrap();
+        assert!(connect_async(request, None, None).await.is_ok());
+        server.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_fails_without_host() {
+        let request = Request::builder().uri("/janus").body(()).unwrap();
+        let result = connect_async(request, None, None).await;
+        assert!(matches!(result, Err(Error::InvalidJanusRequest { .. })));
+    }
+}
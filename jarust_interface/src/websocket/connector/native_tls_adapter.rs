@@ -1,14 +1,105 @@
+use crate::janus_interface::TlsParams;
 use crate::Error;
+use native_tls::Certificate;
+use native_tls::Identity;
+use native_tls::TlsConnector;
 use tokio::net::TcpStream;
-use tokio_tungstenite::connect_async_with_config;
+use tokio_tungstenite::client_async_with_config;
 use tokio_tungstenite::tungstenite::handshake::client::Request;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::WebSocketStream;
 
-pub async fn connect_async(
+fn make_tls_connector(tls: Option<&TlsParams>) -> Result<TlsConnector, native_tls::Error> {
+    let mut builder = TlsConnector::builder();
+    let Some(tls) = tls else {
+        return builder.build();
+    };
+    for certificate in &tls.root_certificates {
+        builder.add_root_certificate(Certificate::from_pem(certificate)?);
+    }
+    if let Some(identity) = &tls.identity {
+        builder.identity(Identity::from_pkcs8(
+            &identity.certificate_chain,
+            &identity.private_key,
+        )?);
+    }
+    builder
+        .danger_accept_invalid_certs(tls.danger_accept_invalid_certs)
+        .danger_accept_invalid_hostnames(tls.danger_accept_invalid_certs);
+    builder.build()
+}
+
+pub async fn handshake(
     request: Request,
+    socket: TcpStream,
+    host: &str,
+    tls: Option<&TlsParams>,
+    config: WebSocketConfig,
 ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, Error> {
     tracing::trace!("Using native-tls");
-    let (stream, ..) = connect_async_with_config(request, None, false).await?;
+    let stream = if request.uri().scheme_str() == Some("wss") {
+        let connector = make_tls_connector(tls).map_err(|what| Error::InvalidTlsConfig {
+            reason: what.to_string(),
+        })?;
+        let server_name = tls
+            .and_then(|tls| tls.server_name.clone())
+            .unwrap_or_else(|| host.to_string());
+        let stream = tokio_native_tls::TlsConnector::from(connector)
+            .connect(&server_name, socket)
+            .await
+            .map_err(|what| tokio_tungstenite::tungstenite::Error::Tls(what.into()))?;
+        MaybeTlsStream::NativeTls(stream)
+    } else {
+        MaybeTlsStream::Plain(socket)
+    };
+    let (stream, ..) = client_async_with_config(request, stream, Some(config)).await?;
     Ok(stream)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::make_tls_connector;
+    use crate::janus_interface::TlsIdentity;
+    use crate::janus_interface::TlsParams;
+
+    const CERTIFICATE: &[u8] = include_bytes!("../../../testdata/cert.pem");
+    const PRIVATE_KEY: &[u8] = include_bytes!("../../../testdata/key.pem");
+    const INVALID_PEM: &[u8] =
+        b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n";
+
+    #[test]
+    fn it_builds_a_connector_with_roots_and_identity() {
+        let tls = TlsParams {
+            root_certificates: vec![CERTIFICATE.to_vec()],
+            identity: Some(TlsIdentity {
+                certificate_chain: CERTIFICATE.to_vec(),
+                private_key: PRIVATE_KEY.to_vec(),
+            }),
+            ..Default::default()
+        };
+        assert!(make_tls_connector(Some(&tls)).is_ok());
+        assert!(make_tls_connector(None).is_ok());
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_root_certificate() {
+        let tls = TlsParams {
+            root_certificates: vec![INVALID_PEM.to_vec()],
+            ..Default::default()
+        };
+        assert!(make_tls_connector(Some(&tls)).is_err());
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_identity() {
+        let tls = TlsParams {
+            identity: Some(TlsIdentity {
+                certificate_chain: CERTIFICATE.to_vec(),
+                private_key: INVALID_PEM.to_vec(),
+            }),
+            ..Default::default()
+        };
+        assert!(make_tls_connector(Some(&tls)).is_err());
+    }
+}
@@ -0,0 +1,285 @@
+//! Minimal proxy clients used to tunnel the websocket connection
+//!
+//! Supports HTTP proxies through `CONNECT` and SOCKS5 proxies, both with optional username/password
+//! authentication. The target host name is always resolved by the proxy.
+
+use super::unbracket;
+use crate::Error;
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use reqwest::Url;
+use std::net::IpAddr;
+use tokio::io::AsyncReadExt;
+use tokio::io::AsyncWriteExt;
+use tokio::net::TcpStream;
+
+/// Upper bound for the proxy response to a `CONNECT` request
+const MAX_CONNECT_RESPONSE_SIZE: usize = 8 * 1024;
+
+fn proxy_error(reason: impl Into<String>) -> Error {
+    Error::ProxyError {
+        reason: reason.into(),
+    }
+}
+
+/// Opens a connection to `host:port` tunneled through the proxy
+pub(crate) async fn tunnel(proxy: &str, host: &str, port: u16) -> Result<TcpStream, Error> {
+    let proxy = Url::parse(proxy).map_err(|what| proxy_error(what.to_string()))?;
+    let proxy_host = proxy
+        .host_str()
+        .map(unbracket)
+        .ok_or_else(|| proxy_error("missing proxy host"))?;
+    let credentials = match proxy.username() {
+        "" => None,
+        username => Some((username, proxy.password().unwrap_or_default())),
+    };
+    tracing::debug!("Connecting through proxy {proxy_host}");
+
+    match proxy.scheme() {
+        "http" => {
+            let proxy_port = proxy.port().unwrap_or(8080);
+            let socket = TcpStream::connect((proxy_host, proxy_port)).await?;
+            http_connect(socket, host, port, credentials).await
+        }
+        "socks5" | "socks5h" => {
+            let proxy_port = proxy.port().unwrap_or(1080);
+            let socket = TcpStream::connect((proxy_host, proxy_port)).await?;
+            socks5_connect(socket, host, port, credentials).await
+        }
+        scheme => Err(proxy_error(format!("unsupported proxy scheme {scheme}"))),
+    }
+}
+
+async fn http_connect(
+    mut socket: TcpStream,
+    host: &str,
+    port: u16,
+    credentials: Option<(&str, &str)>,
+) -> Result<TcpStream, Error> {
+    let authority = match host.parse::<IpAddr>() {
+        Ok(IpAddr::V6(address)) => format!("[{address}]:{port}"),
+        _ => format!("{host}:{port}"),
+    };
+    let mut request = format!("CONNECT {authority} HTTP/1.1\r\nHost: {authority}\r\n");
+    if let Some((username, password)) = credentials {
+        let token = STANDARD.encode(format!("{username}:{password}"));
+        request.push_str(&format!("Proxy-Authorization: Basic {token}\r\n"));
+    }
+    request.push_str("\r\n");
+    socket.write_all(request.as_bytes()).await?;
+
+    // Read byte by byte so nothing past the proxy response is consumed
+    let mut response = Vec::new();
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_CONNECT_RESPONSE_SIZE {
+            return Err(proxy_error("proxy response too large"));
+        }
+        response.push(socket.read_u8().await?);
+    }
+    let response = String::from_utf8_lossy(&response);
+    let status = response.lines().next().unwrap_or_default();
+    match status.split_whitespace().nth(1) {
+        Some("200") => Ok(socket),
+        _ => Err(proxy_error(format!("CONNECT refused: {status}"))),
+    }
+}
+
+async fn socks5_connect(
+    mut socket: TcpStream,
+    host: &str,
+    port: u16,
+    credentials: Option<(&str, &str)>,
+) -> Result<TcpStream, Error> {
+    const VERSION: u8 = 0x05;
+    const NO_AUTHENTICATION: u8 = 0x00;
+    const USERNAME_PASSWORD: u8 = 0x02;
+
+    let method = match credentials {
+        Some(_) => USERNAME_PASSWORD,
+        None => NO_AUTHENTICATION,
+    };
+    socket.write_all(&[VERSION, 1, method]).await?;
+    let mut reply = [0u8; 2];
+    socket.read_exact(&mut reply).await?;
+    if reply != [VERSION, method] {
+        return Err(proxy_error("no acceptable SOCKS5 authentication method"));
+    }
+
+    if let Some((username, password)) = credentials {
+        let (username, password) = (username.as_bytes(), password.as_bytes());
+        let (Ok(username_len), Ok(password_len)) =
+            (u8::try_from(username.len()), u8::try_from(password.len()))
+        else {
+            return Err(proxy_error("SOCKS5 credentials too long"));
+        };
+        let mut auth = vec![0x01, username_len];
+        auth.extend_from_slice(username);
+        auth.push(password_len);
+        auth.extend_from_slice(password);
+        socket.write_all(&auth).await?;
+        socket.read_exact(&mut reply).await?;
+        if reply[1] != 0x00 {
+            return Err(proxy_error("SOCKS5 authentication failed"));
+        }
+    }
+
+    let mut connect = vec![VERSION, 0x01, 0x00];
+    match host.parse::<IpAddr>() {
+        Ok(IpAddr::V4(address)) => {
+            connect.push(0x01);
+            connect.extend_from_slice(&address.octets());
+        }
+        Ok(IpAddr::V6(address)) => {
+            connect.push(0x04);
+            connect.extend_from_slice(&address.octets());
+        }
+        // Domain name, resolved by the proxy
+        Err(_) => {
+            let Ok(host_len) = u8::try_from(host.len()) else {
+                return Err(proxy_error("host name too long"));
+            };
+            connect.push(0x03);
+            connect.push(host_len);
+            connect.extend_from_slice(host.as_bytes());
+        }
+    }
+    connect.extend_from_slice(&port.to_be_bytes());
+    socket.write_all(&connect).await?;
+
+    let mut header = [0u8; 4];
+    socket.read_exact(&mut header).await?;
+    if header[1] != 0x00 {
+        return Err(proxy_error(format!(
+            "SOCKS5 connect failed with code {}",
+            header[1]
+        )));
+    }
+    // Skip the bound address and port
+    let address_len = match header[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => socket.read_u8().await? as usize,
+        _ => return Err(proxy_error("invalid SOCKS5 address type")),
+    };
+    let mut bound = vec![0u8; address_len + 2];
+    socket.read_exact(&mut bound).await?;
+    Ok(socket)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tunnel;
+    use tokio::io::AsyncReadExt;
+    use tokio::io::AsyncWriteExt;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn it_tunnels_through_http_connect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("http://user:pass@{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 1024];
+            let read = socket.read(&mut request).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\nhello")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&request[..read]).to_string()
+        });
+
+        let mut socket = tunnel(&proxy, "janus.example.com", 8188).await.unwrap();
+        let mut payload = [0u8; 5];
+        socket.read_exact(&mut payload).await.unwrap();
+        assert_eq!(&payload, b"hello");
+
+        let request = server.await.unwrap();
+        assert!(request.starts_with("CONNECT janus.example.com:8188 HTTP/1.1\r\n"));
+        assert!(request.contains("Proxy-Authorization: Basic dXNlcjpwYXNz\r\n"));
+    }
+
+    #[tokio::test]
+    async fn it_tunnels_through_socks5() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("socks5://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut greeting = [0u8; 3];
+            socket.read_exact(&mut greeting).await.unwrap();
+            socket.write_all(&[0x05, 0x00]).await.unwrap();
+            let mut connect = [0u8; 5 + 17 + 2];
+            socket.read_exact(&mut connect).await.unwrap();
+            socket
+                .write_all(&[0x05, 0x00, 0x00, 0x01, 127, 0, 0, 1, 0x1f, 0x90])
+                .await
+                .unwrap();
+            (greeting, connect)
+        });
+
+        tunnel(&proxy, "janus.example.com", 8188).await.unwrap();
+
+        let (greeting, connect) = server.await.unwrap();
+        assert_eq!(greeting, [0x05, 0x01, 0x00]);
+        assert_eq!(&connect[..5], &[0x05, 0x01, 0x00, 0x03, 17]);
+        assert_eq!(&connect[5..22], b"janus.example.com");
+        assert_eq!(&connect[22..], &8188u16.to_be_bytes());
+    }
+
+    #[tokio::test]
+    async fn it_tunnels_ipv6_literals() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 1024];
+            let read = socket.read(&mut request).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .await
+                .unwrap();
+            String::from_utf8_lossy(&request[..read]).to_string()
+        });
+        tunnel(&proxy, "::1", 8188).await.unwrap();
+        let request = server.await.unwrap();
+        assert!(request.starts_with("CONNECT [::1]:8188 HTTP/1.1\r\n"));
+
+        let listener = TcpListener::bind("[::1]:0").await.unwrap();
+        let proxy = format!("socks5://[::1]:{}", listener.local_addr().unwrap().port());
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut greeting = [0u8; 3];
+            socket.read_exact(&mut greeting).await.unwrap();
+            socket.write_all(&[0x05, 0x00]).await.unwrap();
+            let mut connect = [0u8; 4 + 16 + 2];
+            socket.read_exact(&mut connect).await.unwrap();
+            socket
+                .write_all(&[0x05, 0x00, 0x00, 0x01, 127, 0, 0, 1, 0x1f, 0x90])
+                .await
+                .unwrap();
+            connect
+        });
+        tunnel(&proxy, "::1", 8188).await.unwrap();
+        let connect = server.await.unwrap();
+        assert_eq!(&connect[..4], &[0x05, 0x01, 0x00, 0x04]);
+        assert_eq!(&connect[4..20], &std::net::Ipv6Addr::LOCALHOST.octets());
+        assert_eq!(&connect[20..], &8188u16.to_be_bytes());
+    }
+
+    #[tokio::test]
+    async fn it_reports_refused_connect() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let proxy = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut request = vec![0u8; 1024];
+            let _ = socket.read(&mut request).await.unwrap();
+            socket
+                .write_all(b"HTTP/1.1 407 Proxy Authentication Required\r\n\r\n")
+                .await
+                .unwrap();
+        });
+
+        let result = tunnel(&proxy, "janus.example.com", 8188).await;
+        assert!(matches!(result, Err(crate::Error::ProxyError { .. })));
+    }
+}
@@ -1,28 +1,185 @@
+use crate::janus_interface::TlsParams;
 use crate::Error;
+use rustls::client::danger::HandshakeSignatureValid;
+use rustls::client::danger::ServerCertVerified;
+use rustls::client::danger::ServerCertVerifier;
+use rustls::crypto::CryptoProvider;
+use rustls::pki_types::pem::PemObject;
+use rustls::pki_types::CertificateDer;
+use rustls::pki_types::PrivateKeyDer;
+use rustls::pki_types::ServerName;
+use rustls::pki_types::UnixTime;
+use rustls::DigitallySignedStruct;
 use rustls::RootCertStore;
+use rustls::SignatureScheme;
 use std::sync::Arc;
 use tokio::net::TcpStream;
-use tokio_tungstenite::connect_async_tls_with_config;
+use tokio_tungstenite::client_async_with_config;
 use tokio_tungstenite::tungstenite::handshake::client::Request;
-use tokio_tungstenite::Connector;
+use tokio_tungstenite::tungstenite::protocol::WebSocketConfig;
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::WebSocketStream;
 
-fn make_tls_client_config() -> Result<Arc<rustls::ClientConfig>, Error> {
+/// Accepts any server certificate, still checking the handshake signatures
+#[derive(Debug)]
+struct NoCertificateVerification(Arc<CryptoProvider>);
+
+impl ServerCertVerifier for NoCertificateVerification {
+    fn verify_server_cert(
+        &self,
+        _: &CertificateDer<'_>,
+        _: &[CertificateDer<'_>],
+        _: &ServerName<'_>,
+        _: &[u8],
+        _: UnixTime,
+    ) -> Result<ServerCertVerified, rustls::Error> {
+        Ok(ServerCertVerified::assertion())
+    }
+
+    fn verify_tls12_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls12_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn verify_tls13_signature(
+        &self,
+        message: &[u8],
+        cert: &CertificateDer<'_>,
+        dss: &DigitallySignedStruct,
+    ) -> Result<HandshakeSignatureValid, rustls::Error> {
+        rustls::crypto::verify_tls13_signature(
+            message,
+            cert,
+            dss,
+            &self.0.signature_verification_algorithms,
+        )
+    }
+
+    fn supported_verify_schemes(&self) -> Vec<SignatureScheme> {
+        self.0.signature_verification_algorithms.supported_schemes()
+    }
+}
+
+fn make_tls_client_config(
+    tls: Option<&TlsParams>,
+) -> Result<Arc<rustls::ClientConfig>, rustls::Error> {
+    let invalid_pem =
+        |what: rustls::pki_types::pem::Error| rustls::Error::General(what.to_string());
     let mut root_store = RootCertStore::empty();
     let platform_certs = rustls_native_certs::load_native_certs().certs;
     root_store.add_parsable_certificates(platform_certs);
-    let client_config = rustls::ClientConfig::builder()
-        .with_root_certificates(root_store)
-        .with_no_client_auth();
+    let tls = tls.cloned().unwrap_or_default();
+    for pem in &tls.root_certificates {
+        for certificate in CertificateDer::pem_slice_iter(pem) {
+            root_store.add(certificate.map_err(invalid_pem)?)?;
+        }
+    }
+
+    let builder = rustls::ClientConfig::builder().with_root_certificates(root_store);
+    let mut client_config = match &tls.identity {
+        Some(identity) => {
+            let chain = CertificateDer::pem_slice_iter(&identity.certificate_chain)
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(invalid_pem)?;
+            let key = PrivateKeyDer::from_pem_slice(&identity.private_key).map_err(invalid_pem)?;
+            builder.with_client_auth_cert(chain, key)?
+        }
+        None => builder.with_no_client_auth(),
+    };
+    if tls.danger_accept_invalid_certs {
+        let provider = client_config.crypto_provider().clone();
+        client_config
+            .dangerous()
+            .set_certificate_verifier(Arc::new(NoCertificateVerification(provider)));
+    }
     Ok(Arc::new(client_config))
 }
 
-pub async fn connect_async(
+pub async fn handshake(
     request: Request,
+    socket: TcpStream,
+    host: &str,
+    tls: Option<&TlsParams>,
+    config: WebSocketConfig,
 ) -> Result<WebSocketStream<MaybeTlsStream<TcpStream>>, Error> {
     tracing::trace!("Using rustls");
-    let connector = Connector::Rustls(make_tls_client_config()?);
-    let (stream, ..) = connect_async_tls_with_config(request, None, true, Some(connector)).await?;
+    socket.set_nodelay(true)?;
+    let stream = if request.uri().scheme_str() == Some("wss") {
+        let client_config =
+            make_tls_client_config(tls).map_err(|what| Error::InvalidTlsConfig {
+                reason: what.to_string(),
+            })?;
+        let server_name = tls
+            .and_then(|tls| tls.server_name.clone())
+            .unwrap_or_else(|| host.to_string());
+        let server_name =
+            ServerName::try_from(server_name).map_err(|what| Error::InvalidTlsConfig {
+                reason: what.to_string(),
+            })?;
+        let stream = tokio_rustls::TlsConnector::from(client_config)
+            .connect(server_name, socket)
+            .await?;
+        MaybeTlsStream::Rustls(stream)
+    } else {
+        MaybeTlsStream::Plain(socket)
+    };
+    let (stream, ..) = client_async_with_config(request, stream, Some(config)).await?;
     Ok(stream)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::make_tls_client_config;
+    use crate::janus_interface::TlsIdentity;
+    use crate::janus_interface::TlsParams;
+
+    const CERTIFICATE: &[u8] = include_bytes!("../../../testdata/cert.pem");
+    const PRIVATE_KEY: &[u8] = include_bytes!("../../../testdata/key.pem");
+    const INVALID_PEM: &[u8] =
+        b"-----BEGIN CERTIFICATE-----\nnot base64\n-----END CERTIFICATE-----\n";
+
+    #[test]
+    fn it_builds_a_config_with_roots_and_identity() {
+        let tls = TlsParams {
+            root_certificates: vec![CERTIFICATE.to_vec()],
+            identity: Some(TlsIdentity {
+                certificate_chain: CERTIFICATE.to_vec(),
+                private_key: PRIVATE_KEY.to_vec(),
+            }),
+            ..Default::default()
+        };
+        let config = make_tls_client_config(Some(&tls)).unwrap();
+        assert!(config.client_auth_cert_resolver.has_certs());
+        assert!(make_tls_client_config(None).is_ok());
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_root_certificate() {
+        let tls = TlsParams {
+            root_certificates: vec![INVALID_PEM.to_vec()],
+            ..Default::default()
+        };
+        assert!(make_tls_client_config(Some(&tls)).is_err());
+    }
+
+    #[test]
+    fn it_rejects_an_invalid_identity() {
+        let tls = TlsParams {
+            identity: Some(TlsIdentity {
+                certificate_chain: CERTIFICATE.to_vec(),
+                private_key: INVALID_PEM.to_vec(),
+            }),
+            ..Default::default()
+        };
+        assert!(make_tls_client_config(Some(&tls)).is_err());
+    }
+}
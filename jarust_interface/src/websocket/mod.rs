@@ -6,6 +6,8 @@ mod router;
 mod tmanager;
 mod websocket_client;
 
+pub mod websocket_admin_interface;
 pub mod websocket_interface;
 
+pub use websocket_admin_interface::WebSocketAdminInterface;
 pub use websocket_interface::WebSocketInterface;
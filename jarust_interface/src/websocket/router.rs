@@ -90,6 +90,26 @@ impl Router {
     }
 }
 
+impl Router {
+    /// Publishes the message to all the handles routes of a session
+    #[tracing::instrument(level = tracing::Level::TRACE, skip(self, message))]
+    pub(crate) async fn pub_session_handles(&self, session_id: u64, message: JaResponse) {
+        let prefix = format!("{}/{session_id}/", self.inner.shared.root_path);
+        let channels = {
+            let guard = self.inner.exclusive.read().await;
+            guard
+                .routes
+                .iter()
+                .filter(|(path, _)| path.starts_with(&prefix))
+                .map(|(_, channel)| channel.clone())
+                .collect::<Vec<_>>()
+        };
+        for channel in channels {
+            _ = channel.send(message.clone());
+        }
+    }
+}
+
 impl Router {
     pub fn path_from_request(request: &Value) -> Option<String> {
         if let (Some(session_id), Some(handle_id)) = (
@@ -175,4 +195,29 @@ mod tests {
         assert_eq!(size_one, 1);
         assert_eq!(size_two, 2);
     }
+
+    #[tokio::test]
+    async fn it_publishes_to_all_session_handles() {
+        let mut router = Router::new("janus");
+        let mut first_handle = router.add_subroute("1/11").await;
+        let mut second_handle = router.add_subroute("1/12").await;
+        let mut other_session_handle = router.add_subroute("2/21").await;
+
+        router
+            .pub_session_handles(
+                1,
+                JaResponse {
+                    janus: ResponseType::Ack,
+                    transaction: None,
+                    session_id: Some(1),
+                    sender: None,
+                    jsep: None,
+                },
+            )
+            .await;
+
+        assert!(first_handle.try_recv().is_ok());
+        assert!(second_handle.try_recv().is_ok());
+        assert!(other_session_handle.try_recv().is_err());
+    }
 }
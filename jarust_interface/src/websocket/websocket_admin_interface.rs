@@ -0,0 +1,172 @@
+use super::napmap::NapMap;
+use super::websocket_client::WebSocketClient;
+use crate::admin_protocol::AdminMessage;
+use crate::janus_admin_interface::AdminConnectionParams;
+use crate::janus_admin_interface::JanusAdminInterface;
+use crate::tgenerator::GenerateTransaction;
+use crate::tgenerator::TransactionGenerator;
+use crate::Error;
+use jarust_rt::JaTask;
+use serde_json::Value;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+#[derive(Debug)]
+struct Shared {
+    tasks: Vec<JaTask>,
+    server_root: String,
+    admin_secret: Option<String>,
+    transaction_generator: TransactionGenerator,
+    rsp_map: Arc<NapMap<String, Value>>,
+}
+
+#[derive(Debug)]
+struct Exclusive {
+    ws: WebSocketClient,
+}
+
+#[derive(Debug)]
+struct InnerWebSocketAdminInterface {
+    shared: Shared,
+    exclusive: Mutex<Exclusive>,
+}
+
+#[derive(Debug, Clone)]
+pub struct WebSocketAdminInterface {
+    inner: Arc<InnerWebSocketAdminInterface>,
+}
+
+impl WebSocketAdminInterface {
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn send(&self, message: AdminMessage) -> Result<String, Error> {
+        let mut request = message.body;
+        if let Some(session_id) = message.session_id {
+            request["session_id"] = session_id.into();
+        }
+        if let Some(handle_id) = message.handle_id {
+            request["handle_id"] = handle_id.into();
+        }
+        let (request, transaction) = self.decorate_request(request);
+
+        let mut guard = self.inner.exclusive.lock().await;
+        guard
+            .ws
+            .send(
+                request.to_string().as_bytes(),
+                &self.inner.shared.server_root,
+            )
+            .await?;
+        tracing::trace!("Sending {request:#?}");
+        Ok(transaction)
+    }
+
+    #[tracing::instrument(level = tracing::Level::TRACE, skip(self, timeout))]
+    async fn poll_response(&self, transaction: &str, timeout: Duration) -> Result<Value, Error> {
+        tracing::trace!("Polling response");
+        match tokio::time::timeout(
+            timeout,
+            self.inner.shared.rsp_map.get(transaction.to_string()),
+        )
+        .await
+        {
+            Ok(Some(response)) => Ok(response),
+            Ok(None) => {
+                tracing::error!("Incomplete packet");
+                Err(Error::IncompletePacket)
+            }
+            Err(_) => {
+                tracing::error!("Request timeout");
+                Err(Error::RequestTimeout)
+            }
+        }
+    }
+
+    fn decorate_request(&self, mut request: Value) -> (Value, String) {
+        let transaction = self
+            .inner
+            .shared
+            .transaction_generator
+            .generate_transaction();
+        if let Some(admin_secret) = self.inner.shared.admin_secret.clone() {
+            request["admin_secret"] = admin_secret.into();
+        };
+        request["transaction"] = transaction.clone().into();
+        (request, transaction)
+    }
+}
+
+#[async_trait::async_trait]
+impl JanusAdminInterface for WebSocketAdminInterface {
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn make_admin_interface(
+        conn_params: AdminConnectionParams,
+        transaction_generator: impl GenerateTransaction,
+    ) -> Result<Self, Error> {
+        tracing::debug!("Creating WebSocket Admin Interface");
+        let mut websocket = WebSocketClient::new();
+        let mut receiver = websocket
+            .connect(
+                &conn_params.url,
+                "janus-admin-protocol",
+                conn_params.tls.as_ref(),
+                conn_params.transport.as_ref(),
+            )
+            .await?;
+        let transaction_generator = TransactionGenerator::new(transaction_generator);
+        let rsp_map = Arc::new(NapMap::<String, Value>::new(conn_params.capacity));
+
+        let rsp_task = jarust_rt::spawn("Admin responses gathering task", {
+            let rsp_map = rsp_map.clone();
+            async move {
+                while let Some(Ok(bytes)) = receiver.recv().await {
+                    let Ok(rsp) = serde_json::from_slice::<Value>(&bytes) else {
+                        tracing::error!("Failed to parse admin response");
+                        continue;
+                    };
+                    if let Some(transaction) = rsp["transaction"].as_str() {
+                        rsp_map.insert(transaction.to_string(), rsp).await;
+                    }
+                }
+            }
+        });
+
+        let shared = Shared {
+            tasks: vec![rsp_task],
+            server_root: conn_params.server_root,
+            admin_secret: conn_params.admin_secret,
+            transaction_generator,
+            rsp_map,
+        };
+        let exclusive = Exclusive { ws: websocket };
+        let inner = InnerWebSocketAdminInterface {
+            shared,
+            exclusive: Mutex::new(exclusive),
+        };
+        Ok(Self {
+            inner: Arc::new(inner),
+        })
+    }
+
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn internal_send_admin_request(
+        &self,
+        message: AdminMessage,
+        timeout: Duration,
+    ) -> Result<Value, Error> {
+        let transaction = self.send(message).await?;
+        self.poll_response(&transaction, timeout).await
+    }
+
+    fn name(&self) -> Box<str> {
+        "WebSocket Admin Interface".to_string().into_boxed_str()
+    }
+}
+
+impl Drop for InnerWebSocketAdminInterface {
+    fn drop(&mut self) {
+        self.shared.tasks.iter().for_each(|task| {
+            task.cancel();
+        });
+    }
+}
@@ -1,3 +1,5 @@
+use crate::janus_interface::TlsParams;
+use crate::janus_interface::TransportParams;
 use crate::websocket::connector;
 use crate::Error;
 use bytes::Bytes;
@@ -8,6 +10,7 @@ use jarust_rt::JaTask;
 use tokio::net::TcpStream;
 use tokio::sync::mpsc;
 use tokio_tungstenite::tungstenite::client::IntoClientRequest;
+use tokio_tungstenite::tungstenite::http::HeaderName;
 use tokio_tungstenite::tungstenite::Message;
 use tokio_tungstenite::MaybeTlsStream;
 use tokio_tungstenite::WebSocketStream;
@@ -33,26 +36,44 @@ impl WebSocketClient {
     }
 
     #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
-    pub async fn connect(&mut self, url: &str) -> Result<mpsc::UnboundedReceiver<Bytes>, Error> {
+    pub async fn connect(
+        &mut self,
+        url: &str,
+        protocol: &str,
+        tls: Option<&TlsParams>,
+        transport: Option<&TransportParams>,
+    ) -> Result<mpsc::UnboundedReceiver<Result<Bytes, Error>>, Error> {
         tracing::debug!("Connecting to {url}");
         let mut request = url.into_client_request()?;
         let headers = request.headers_mut();
-        headers.insert("Sec-Websocket-Protocol", "janus-protocol".parse()?);
-        let stream = connector::connect_async(request).await?;
+        headers.insert("Sec-Websocket-Protocol", protocol.parse()?);
+        for (name, value) in transport.iter().flat_map(|transport| &transport.headers) {
+            headers.append(HeaderName::try_from(name.as_str())?, value.parse()?);
+        }
+        let stream = connector::connect_async(request, tls, transport).await?;
 
         let (sender, mut receiver) = stream.split();
         let (tx, rx) = mpsc::unbounded_channel();
 
         let task = jarust_rt::spawn("WebSocket incoming messages", async move {
-            while let Some(Ok(message)) = receiver.next().await {
-                if let Message::Text(text) = message {
-                    let _ = tx.send(text.into());
+            while let Some(message) = receiver.next().await {
+                match message {
+                    Ok(Message::Text(text)) => {
+                        let _ = tx.send(Ok(text.into()));
+                    }
+                    Ok(_) => {}
+                    Err(what) => {
+                        let _ = tx.send(Err(what.into()));
+                        break;
+                    }
                 }
             }
         });
 
         self.sender = Some(sender);
-        self.task = Some(task);
+        if let Some(previous_task) = self.task.replace(task) {
+            previous_task.cancel();
+        }
         Ok(rx)
     }
 
@@ -5,8 +5,15 @@ use super::tmanager::TransactionManager;
 use super::websocket_client::WebSocketClient;
 use crate::handle_msg::HandleMessage;
 use crate::handle_msg::HandleMessageWithJsep;
+use crate::janus_interface::AttachParams;
 use crate::janus_interface::ConnectionParams;
+use crate::janus_interface::ConnectionState;
 use crate::janus_interface::JanusInterface;
+use crate::janus_interface::ReconnectParams;
+use crate::janus_interface::TlsParams;
+use crate::janus_interface::TransportParams;
+use crate::japrotocol::GenericEvent;
+use crate::japrotocol::JaHandleEvent;
 use crate::japrotocol::JaResponse;
 use crate::japrotocol::JaSuccessProtocol;
 use crate::japrotocol::ResponseType;
@@ -14,19 +21,33 @@ use crate::japrotocol::ServerInfoRsp;
 use crate::tgenerator::GenerateTransaction;
 use crate::tgenerator::TransactionGenerator;
 use crate::Error;
+use bytes::Bytes;
 use jarust_rt::JaTask;
 use serde_json::json;
 use serde_json::Value;
+use std::collections::HashSet;
 use std::sync::Arc;
+use std::sync::RwLock;
+use std::sync::Weak;
 use std::time::Duration;
+use std::time::Instant;
 use tokio::sync::mpsc;
+use tokio::sync::watch;
 use tokio::sync::Mutex;
 
+const JANUS_PROTOCOL: &str = "janus-protocol";
+
 #[derive(Debug)]
 struct Shared {
     tasks: Vec<JaTask>,
+    url: String,
     server_root: String,
+    reconnect: Option<ReconnectParams>,
+    tls: Option<TlsParams>,
+    transport: Option<TransportParams>,
+    state_sender: watch::Sender<ConnectionState>,
     apisecret: Option<String>,
+    token: RwLock<Option<String>>,
     transaction_generator: TransactionGenerator,
     ack_map: Arc<NapMap<String, JaResponse>>,
     rsp_map: Arc<NapMap<String, JaResponse>>,
@@ -37,6 +58,7 @@ struct Exclusive {
     router: Router,
     ws: WebSocketClient,
     transaction_manager: TransactionManager,
+    sessions: HashSet<u64>,
 }
 
 #[derive(Debug)]
@@ -123,6 +145,162 @@ impl WebSocketInterface {
         }
     }
 
+    /// Forwards the incoming messages to the demuxer and reconnects when the connection drops.
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn supervise(
+        this: Weak<InnerWebSocketInterface>,
+        mut receiver: mpsc::UnboundedReceiver<Result<Bytes, Error>>,
+        inbound_sender: mpsc::UnboundedSender<Bytes>,
+        state_sender: watch::Sender<ConnectionState>,
+    ) {
+        let mut reclaim: Option<(Instant, Duration)> = None;
+        loop {
+            // Claim responses arrive through the new connection, so we have to keep forwarding
+            // while claiming the sessions.
+            let forward = Self::forward(&mut receiver, &inbound_sender);
+            let claim = async {
+                let Some((disconnected_at, reclaim_timeout)) = reclaim.take() else {
+                    return;
+                };
+                if let Some(inner) = this.upgrade() {
+                    Self { inner }
+                        .reclaim_sessions(disconnected_at, reclaim_timeout)
+                        .await;
+                }
+            };
+            let (reason, _) = futures_util::future::join(forward, claim).await;
+            let Some(reason) = reason else {
+                return;
+            };
+            tracing::warn!("WebSocket connection lost: {reason}");
+            state_sender.send_replace(ConnectionState::Disconnected { reason });
+
+            let Some(params) = this.upgrade().and_then(|inner| inner.shared.reconnect) else {
+                tracing::debug!("Reconnection is disabled");
+                state_sender.send_replace(ConnectionState::Closed);
+                return;
+            };
+            let disconnected_at = Instant::now();
+            let Some(new_receiver) = Self::reconnect(&this, params).await else {
+                state_sender.send_replace(ConnectionState::Closed);
+                return;
+            };
+            receiver = new_receiver;
+            reclaim = Some((disconnected_at, params.reclaim_timeout));
+        }
+    }
+
+    /// Forwards messages until the connection drops and returns the reason,
+    /// returns `None` if the demuxer is gone.
+    async fn forward(
+        receiver: &mut mpsc::UnboundedReceiver<Result<Bytes, Error>>,
+        inbound_sender: &mpsc::UnboundedSender<Bytes>,
+    ) -> Option<String> {
+        while let Some(message) = receiver.recv().await {
+            match message {
+                Ok(message) => inbound_sender.send(message).ok()?,
+                Err(what) => return Some(what.to_string()),
+            }
+        }
+        Some("Connection closed".to_string())
+    }
+
+    /// Reconnects with exponential backoff, returns `None` when giving up or when the interface is dropped.
+    ///
+    /// Only holds a weak reference between the attempts so dropping the interface stops reconnecting.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    async fn reconnect(
+        this: &Weak<InnerWebSocketInterface>,
+        params: ReconnectParams,
+    ) -> Option<mpsc::UnboundedReceiver<Result<Bytes, Error>>> {
+        let mut attempt = 0;
+        loop {
+            if params.max_attempts.is_some_and(|max| attempt >= max) {
+                tracing::error!("Giving up reconnecting after {attempt} attempts");
+                return None;
+            }
+            tokio::time::sleep(params.backoff(attempt)).await;
+            attempt += 1;
+
+            let (url, tls, transport) = {
+                let inner = this.upgrade()?;
+                tracing::info!(attempt, "Reconnecting");
+                inner
+                    .shared
+                    .state_sender
+                    .send_replace(ConnectionState::Connecting);
+                (
+                    inner.shared.url.clone(),
+                    inner.shared.tls.clone(),
+                    inner.shared.transport.clone(),
+                )
+            };
+            // Connect outside of the lock so sending isn't blocked by the handshake
+            let mut ws = WebSocketClient::new();
+            match ws
+                .connect(&url, JANUS_PROTOCOL, tls.as_ref(), transport.as_ref())
+                .await
+            {
+                Ok(receiver) => {
+                    let inner = this.upgrade()?;
+                    inner.exclusive.lock().await.ws = ws;
+                    tracing::info!("Reconnected");
+                    inner
+                        .shared
+                        .state_sender
+                        .send_replace(ConnectionState::Connected);
+                    return Some(receiver);
+                }
+                Err(what) => {
+                    tracing::warn!("Failed to reconnect: {what}");
+                    this.upgrade()?
+                        .shared
+                        .state_sender
+                        .send_replace(ConnectionState::Reconnecting);
+                }
+            }
+        }
+    }
+
+    /// Claims the live sessions over the new connection so janus keeps routing their events to us.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    async fn reclaim_sessions(&self, disconnected_at: Instant, reclaim_timeout: Duration) {
+        let sessions = self
+            .inner
+            .exclusive
+            .lock()
+            .await
+            .sessions
+            .iter()
+            .copied()
+            .collect::<Vec<_>>();
+        for session_id in sessions {
+            let elapsed = disconnected_at.elapsed();
+            if elapsed >= reclaim_timeout {
+                tracing::warn!(session_id, "Reclaim timeout elapsed, session is lost");
+                self.inner
+                    .exclusive
+                    .lock()
+                    .await
+                    .sessions
+                    .remove(&session_id);
+                continue;
+            }
+            match self.claim(session_id, reclaim_timeout - elapsed).await {
+                Ok(_) => tracing::info!(session_id, "Session claimed"),
+                Err(what) => {
+                    tracing::error!(session_id, "Failed to claim session: {what}");
+                    self.inner
+                        .exclusive
+                        .lock()
+                        .await
+                        .sessions
+                        .remove(&session_id);
+                }
+            }
+        }
+    }
+
     fn decorate_request(&self, mut request: Value) -> (Value, String) {
         let transaction = self
             .inner
@@ -132,6 +310,9 @@ impl WebSocketInterface {
         if let Some(apisecret) = self.inner.shared.apisecret.clone() {
             request["apisecret"] = apisecret.into();
         };
+        if let Some(token) = self.inner.shared.token.read().unwrap().clone() {
+            request["token"] = token.into();
+        };
         request["transaction"] = transaction.clone().into();
         (request, transaction)
     }
@@ -146,14 +327,24 @@ impl JanusInterface for WebSocketInterface {
     ) -> Result<Self, Error> {
         tracing::debug!("Creating WebSocket Interface");
         let router = Router::new(&conn_params.server_root);
+        let (state_sender, _) = watch::channel(ConnectionState::Connecting);
         let mut websocket = WebSocketClient::new();
-        let receiver = websocket.connect(&conn_params.url).await?;
+        let receiver = websocket
+            .connect(
+                &conn_params.url,
+                JANUS_PROTOCOL,
+                conn_params.tls.as_ref(),
+                conn_params.transport.as_ref(),
+            )
+            .await?;
+        state_sender.send_replace(ConnectionState::Connected);
         let transaction_manager = TransactionManager::new(conn_params.capacity);
         let transaction_generator = TransactionGenerator::new(transaction_generator);
 
         let ack_map = Arc::new(NapMap::<String, JaResponse>::new(conn_params.capacity));
         let rsp_map = Arc::new(NapMap::<String, JaResponse>::new(conn_params.capacity));
 
+        let (inbound_sender, inbound_receiver) = mpsc::unbounded_channel::<Bytes>();
         let (rsp_sender, mut rsp_receiver) = mpsc::unbounded_channel::<JaResponse>();
         let (ack_sender, mut ack_receiver) = mpsc::unbounded_channel::<JaResponse>();
 
@@ -183,7 +374,7 @@ impl JanusInterface for WebSocketInterface {
             let router = router.clone();
             let transaction_manager = transaction_manager.clone();
             let demuxer = Demuxer {
-                inbound_stream: receiver,
+                inbound_stream: inbound_receiver,
                 router,
                 rsp_sender,
                 ack_sender,
@@ -192,27 +383,37 @@ impl JanusInterface for WebSocketInterface {
             async move { demuxer.start().await }
         });
 
-        let shared = Shared {
-            tasks: vec![demux_task, rsp_task, ack_task],
-            server_root: conn_params.server_root,
-            apisecret: conn_params.apisecret,
-            transaction_generator,
-            ack_map,
-            rsp_map,
-        };
-        let exclusive = Exclusive {
-            router,
-            ws: websocket,
-            transaction_manager,
-        };
-        let inner = InnerWebSocketInterface {
-            shared,
-            exclusive: Mutex::new(exclusive),
-        };
-        let this = Self {
-            inner: Arc::new(inner),
-        };
-        Ok(this)
+        let inner = Arc::new_cyclic(|this| {
+            let supervisor_task = jarust_rt::spawn(
+                "Connection supervisor task",
+                Self::supervise(this.clone(), receiver, inbound_sender, state_sender.clone()),
+            );
+            let shared = Shared {
+                tasks: vec![demux_task, rsp_task, ack_task, supervisor_task],
+                url: conn_params.url,
+                server_root: conn_params.server_root,
+                reconnect: conn_params.reconnect,
+                tls: conn_params.tls,
+                transport: conn_params.transport,
+                state_sender,
+                apisecret: conn_params.apisecret,
+                token: RwLock::new(conn_params.token),
+                transaction_generator,
+                ack_map,
+                rsp_map,
+            };
+            let exclusive = Exclusive {
+                router,
+                ws: websocket,
+                transaction_manager,
+                sessions: HashSet::new(),
+            };
+            InnerWebSocketInterface {
+                shared,
+                exclusive: Mutex::new(exclusive),
+            }
+        });
+        Ok(Self { inner })
     }
 
     #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
@@ -238,6 +439,12 @@ impl JanusInterface for WebSocketInterface {
                 return Err(Error::UnexpectedResponse);
             }
         };
+        self.inner
+            .exclusive
+            .lock()
+            .await
+            .sessions
+            .insert(session_id);
         Ok(session_id)
     }
 
@@ -263,13 +470,13 @@ impl JanusInterface for WebSocketInterface {
         &self,
         session_id: u64,
         plugin_id: String,
+        params: AttachParams,
         timeout: Duration,
     ) -> Result<(u64, mpsc::UnboundedReceiver<JaResponse>), Error> {
-        let request = json!({
-            "janus": "attach",
-            "session_id": session_id,
-            "plugin": plugin_id
-        });
+        let mut request = serde_json::to_value(params)?;
+        request["janus"] = "attach".into();
+        request["session_id"] = session_id.into();
+        request["plugin"] = plugin_id.into();
         let transaction = self.send(request).await?;
         let response = self.poll_response(&transaction, timeout).await?;
         let handle_id = match response.janus {
@@ -287,14 +494,7 @@ impl JanusInterface for WebSocketInterface {
                 return Err(Error::UnexpectedResponse);
             }
         };
-        let receiver = self
-            .inner
-            .exclusive
-            .lock()
-            .await
-            .router
-            .add_subroute(&format!("{session_id}/{handle_id}"))
-            .await;
+        let receiver = self.register_handle(session_id, handle_id).await;
         Ok((handle_id, receiver))
     }
 
@@ -321,9 +521,46 @@ impl JanusInterface for WebSocketInterface {
         });
         let transaction = self.send(request).await?;
         self.poll_response(&transaction, timeout).await?;
+        self.inner
+            .exclusive
+            .lock()
+            .await
+            .sessions
+            .remove(&session_id);
         Ok(())
     }
 
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn claim(&self, session_id: u64, timeout: Duration) -> Result<(), Error> {
+        let request = json!({
+            "janus": "claim",
+            "session_id": session_id
+        });
+        let transaction = self.send(request).await?;
+        self.poll_response(&transaction, timeout).await?;
+        self.inner
+            .exclusive
+            .lock()
+            .await
+            .sessions
+            .insert(session_id);
+        Ok(())
+    }
+
+    async fn register_handle(
+        &self,
+        session_id: u64,
+        handle_id: u64,
+    ) -> mpsc::UnboundedReceiver<JaResponse> {
+        self.inner
+            .exclusive
+            .lock()
+            .await
+            .router
+            .add_subroute(&format!("{session_id}/{handle_id}"))
+            .await
+    }
+
     #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
     async fn fire_and_forget_msg(&self, message: HandleMessage) -> Result<String, Error> {
         let request = json!({
@@ -433,6 +670,28 @@ impl JanusInterface for WebSocketInterface {
         Ok(transaction)
     }
 
+    #[tracing::instrument(level = tracing::Level::TRACE, skip_all)]
+    async fn notify_session_expired(&self, session_id: u64) {
+        let message = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::GenericEvent(GenericEvent::SessionExpired)),
+            transaction: None,
+            session_id: Some(session_id),
+            sender: None,
+            jsep: None,
+        };
+        let mut guard = self.inner.exclusive.lock().await;
+        guard.sessions.remove(&session_id);
+        guard.router.pub_session_handles(session_id, message).await;
+    }
+
+    fn subscribe_connection_state(&self) -> watch::Receiver<ConnectionState> {
+        self.inner.shared.state_sender.subscribe()
+    }
+
+    fn set_token(&self, token: Option<String>) {
+        *self.inner.shared.token.write().unwrap() = token;
+    }
+
     fn name(&self) -> Box<str> {
         "WebSocket Interface".to_string().into_boxed_str()
     }
@@ -443,6 +702,9 @@ impl Drop for InnerWebSocketInterface {
         self.shared.tasks.iter().for_each(|task| {
             task.cancel();
         });
+        self.shared
+            .state_sender
+            .send_replace(ConnectionState::Closed);
     }
 }
 
@@ -458,3 +720,299 @@ fn merge_json(a: &mut Value, b: &Value) {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::WebSocketInterface;
+    use crate::janus_interface::ConnectionParams;
+    use crate::janus_interface::ConnectionState;
+    use crate::janus_interface::JanusInterface;
+    use crate::janus_interface::ReconnectParams;
+    use crate::tgenerator::RandomTransactionGenerator;
+    use futures_util::SinkExt;
+    use futures_util::StreamExt;
+    use serde_json::json;
+    use serde_json::Value;
+    use std::time::Duration;
+    use tokio::net::TcpListener;
+    use tokio::net::TcpStream;
+    use tokio::sync::watch;
+    use tokio_tungstenite::tungstenite::handshake::server::Request;
+    use tokio_tungstenite::tungstenite::handshake::server::Response;
+    use tokio_tungstenite::tungstenite::Message;
+    use tokio_tungstenite::WebSocketStream;
+
+    fn conn_params(url: String) -> ConnectionParams {
+        conn_params_with_reconnect(url, None)
+    }
+
+    fn conn_params_with_reconnect(
+        url: String,
+        reconnect: Option<ReconnectParams>,
+    ) -> ConnectionParams {
+        ConnectionParams {
+            url,
+            capacity: 10,
+            apisecret: None,
+            server_root: "janus".to_string(),
+            reconnect,
+            tls: None,
+            transport: None,
+            token: None,
+        }
+    }
+
+    // The handshake callback signature is imposed by tungstenite
+    #[allow(clippy::result_large_err)]
+    async fn accept(listener: &TcpListener) -> WebSocketStream<TcpStream> {
+        let (socket, _) = listener.accept().await.unwrap();
+        tokio_tungstenite::accept_hdr_async(socket, |_: &Request, mut response: Response| {
+            response
+                .headers_mut()
+                .insert("Sec-WebSocket-Protocol", "janus-protocol".parse().unwrap());
+            Ok(response)
+        })
+        .await
+        .unwrap()
+    }
+
+    /// Answers the next request with a success response merged with `body` and returns the request
+    async fn reply_success(ws: &mut WebSocketStream<TcpStream>, body: Value) -> Value {
+        let Some(Ok(Message::Binary(data))) = ws.next().await else {
+            panic!("Expected a request");
+        };
+        let request = serde_json::from_slice::<Value>(&data).unwrap();
+        let mut response = json!({
+            "janus": "success",
+            "transaction": request["transaction"]
+        });
+        super::merge_json(&mut response, &body);
+        ws.send(Message::text(response.to_string())).await.unwrap();
+        request
+    }
+
+    /// Collects the state changes until `last` is reached
+    async fn states_until(
+        state: &mut watch::Receiver<ConnectionState>,
+        last: ConnectionState,
+    ) -> Vec<ConnectionState> {
+        let mut states = vec![];
+        while states.last() != Some(&last) {
+            state.changed().await.unwrap();
+            states.push(state.borrow_and_update().clone());
+        }
+        states
+    }
+
+    #[test]
+    fn it_caps_the_backoff() {
+        let params = ReconnectParams {
+            initial_backoff: Duration::from_millis(100),
+            max_backoff: Duration::from_millis(500),
+            max_attempts: None,
+            reclaim_timeout: Duration::from_secs(1),
+        };
+        assert_eq!(params.backoff(0), Duration::from_millis(100));
+        assert_eq!(params.backoff(1), Duration::from_millis(200));
+        assert_eq!(params.backoff(2), Duration::from_millis(400));
+        assert_eq!(params.backoff(3), Duration::from_millis(500));
+        assert_eq!(params.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn it_reconnects_and_claims_the_sessions() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let reconnect = ReconnectParams {
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(50),
+            max_attempts: Some(3),
+            reclaim_timeout: Duration::from_secs(5),
+        };
+        let server = tokio::spawn(async move {
+            let mut ws = accept(&listener).await;
+            reply_success(&mut ws, json!({ "data": { "id": 42 } })).await;
+            drop(ws);
+            // Delay the handshake so the connecting state can be observed
+            tokio::time::sleep(Duration::from_millis(100)).await;
+            let mut ws = accept(&listener).await;
+            let claim = reply_success(&mut ws, json!({ "session_id": 42 })).await;
+            (claim, ws)
+        });
+
+        let interface = WebSocketInterface::make_interface(
+            conn_params_with_reconnect(url, Some(reconnect)),
+            RandomTransactionGenerator,
+        )
+        .await
+        .unwrap();
+        let mut state = interface.subscribe_connection_state();
+        let session_id = interface.create(Duration::from_secs(1)).await.unwrap();
+        assert_eq!(session_id, 42);
+
+        let states = states_until(&mut state, ConnectionState::Connected).await;
+        assert!(matches!(
+            states.as_slice(),
+            [
+                ConnectionState::Disconnected { .. },
+                ConnectionState::Connecting,
+                ConnectionState::Connected
+            ]
+        ));
+
+        let (claim, _ws) = server.await.unwrap();
+        assert_eq!(claim["janus"], "claim");
+        assert_eq!(claim["session_id"], 42);
+    }
+
+    #[tokio::test]
+    async fn it_drops_the_sessions_past_the_reclaim_timeout() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let reconnect = ReconnectParams {
+            initial_backoff: Duration::from_millis(50),
+            max_backoff: Duration::from_millis(50),
+            max_attempts: Some(3),
+            reclaim_timeout: Duration::from_millis(10),
+        };
+        let server = tokio::spawn(async move {
+            let mut ws = accept(&listener).await;
+            reply_success(&mut ws, json!({ "data": { "id": 42 } })).await;
+            drop(ws);
+            let mut ws = accept(&listener).await;
+            // No claim is sent for the expired session
+            let next = tokio::time::timeout(Duration::from_millis(200), ws.next()).await;
+            assert!(next.is_err());
+        });
+
+        let interface = WebSocketInterface::make_interface(
+            conn_params_with_reconnect(url, Some(reconnect)),
+            RandomTransactionGenerator,
+        )
+        .await
+        .unwrap();
+        interface.create(Duration::from_secs(1)).await.unwrap();
+
+        server.await.unwrap();
+        assert!(interface.inner.exclusive.lock().await.sessions.is_empty());
+    }
+
+    #[tokio::test]
+    async fn it_gives_up_after_max_attempts() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let reconnect = ReconnectParams {
+            initial_backoff: Duration::from_millis(10),
+            max_backoff: Duration::from_millis(10),
+            max_attempts: Some(2),
+            reclaim_timeout: Duration::from_secs(1),
+        };
+        let server = tokio::spawn(async move {
+            // Closing the listener refuses the reconnection attempts
+            accept(&listener).await
+        });
+
+        let interface = WebSocketInterface::make_interface(
+            conn_params_with_reconnect(url, Some(reconnect)),
+            RandomTransactionGenerator,
+        )
+        .await
+        .unwrap();
+        let mut state = interface.subscribe_connection_state();
+        drop(server.await.unwrap());
+
+        tokio::time::timeout(
+            Duration::from_secs(1),
+            state.wait_for(|state| *state == ConnectionState::Closed),
+        )
+        .await
+        .unwrap()
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_stops_reconnecting_when_dropped() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move { accept(&listener).await });
+
+        let interface = WebSocketInterface::make_interface(
+            conn_params_with_reconnect(
+                url,
+                Some(ReconnectParams {
+                    initial_backoff: Duration::from_millis(10),
+                    ..Default::default()
+                }),
+            ),
+            RandomTransactionGenerator,
+        )
+        .await
+        .unwrap();
+        let mut state = interface.subscribe_connection_state();
+        drop(server.await.unwrap());
+        state
+            .wait_for(|state| *state == ConnectionState::Reconnecting)
+            .await
+            .unwrap();
+
+        // Retrying forever must not keep the interface alive, every state sender goes away
+        drop(interface);
+        tokio::time::timeout(Duration::from_secs(1), async {
+            while state.changed().await.is_ok() {}
+        })
+        .await
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_reports_the_connection_state() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move { accept(&listener).await });
+
+        let interface =
+            WebSocketInterface::make_interface(conn_params(url), RandomTransactionGenerator)
+                .await
+                .unwrap();
+        // Subscribing after the connection is made still reports it
+        let mut state = interface.subscribe_connection_state();
+        assert_eq!(*state.borrow_and_update(), ConnectionState::Connected);
+
+        // Reconnection is disabled, losing the connection closes the interface
+        drop(server.await.unwrap());
+        state
+            .wait_for(|state| *state == ConnectionState::Closed)
+            .await
+            .unwrap();
+    }
+
+    #[tokio::test]
+    async fn it_attaches_the_rotated_token_to_requests() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut ws = accept(&listener).await;
+            let mut requests = vec![];
+            for _ in 0..3 {
+                requests.push(reply_success(&mut ws, json!({ "data": { "id": 42 } })).await);
+            }
+            requests
+        });
+
+        let mut params = conn_params(url);
+        params.token = Some("first".to_string());
+        let interface = WebSocketInterface::make_interface(params, RandomTransactionGenerator)
+            .await
+            .unwrap();
+        interface.create(Duration::from_secs(1)).await.unwrap();
+        interface.set_token(Some("second".to_string()));
+        interface.create(Duration::from_secs(1)).await.unwrap();
+        interface.set_token(None);
+        interface.create(Duration::from_secs(1)).await.unwrap();
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests[0]["token"], "first");
+        assert_eq!(requests[1]["token"], "second");
+        assert!(requests[2].get("token").is_none());
+    }
+}
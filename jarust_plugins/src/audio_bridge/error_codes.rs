@@ -0,0 +1,20 @@
+jarust_interface::error_codes! {
+    /// AudioBridge plugin error codes
+    AudioBridgeErrorCode {
+        NoMessage = 480,
+        InvalidJson = 481,
+        InvalidRequest = 482,
+        MissingElement = 483,
+        InvalidElement = 484,
+        NoSuchRoom = 485,
+        RoomExists = 486,
+        Unauthorized = 487,
+        NoSuchUser = 488,
+        LibopusError = 489,
+        NotJoined = 490,
+        IdExists = 491,
+        InvalidSdp = 492,
+        NoSuchGroup = 493,
+        UnknownError = 499,
+    }
+}
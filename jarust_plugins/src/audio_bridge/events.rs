@@ -23,6 +23,10 @@ enum AudioBridgeEventDto {
         room: JanusId,
         participants: Vec<AudioBridgeParticipant>,
     },
+    #[serde(rename = "announcement-started")]
+    AnnouncementStarted { room: JanusId, file_id: String },
+    #[serde(rename = "announcement-stopped")]
+    AnnouncementStopped { room: JanusId, file_id: String },
     #[serde(rename = "event")]
     Event(AudioBridgeEventEventType),
 }
@@ -67,6 +71,14 @@ pub enum AudioBridgeEventEventType {
         room: JanusId,
         kicked_all: JanusId,
     },
+    ParticipantSuspended {
+        room: JanusId,
+        suspended: JanusId,
+    },
+    ParticipantResumed {
+        room: JanusId,
+        resumed: JanusId,
+    },
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -130,6 +142,22 @@ pub enum AudioBridgeEvent {
         room: JanusId,
         kicked_all: JanusId,
     },
+    ParticipantSuspended {
+        room: JanusId,
+        suspended: JanusId,
+    },
+    ParticipantResumed {
+        room: JanusId,
+        resumed: JanusId,
+    },
+    AnnouncementStarted {
+        room: JanusId,
+        file_id: String,
+    },
+    AnnouncementStopped {
+        room: JanusId,
+        file_id: String,
+    },
     Error {
         error_code: u16,
         error: String,
@@ -216,6 +244,21 @@ impl TryFrom<JaResponse> for PluginEvent {
                                 AudioBridgeEventDto::Event(
                                     AudioBridgeEventEventType::KickedAll { room, kicked_all },
                                 ) => AudioBridgeEvent::KickedAll { room, kicked_all },
+                                AudioBridgeEventDto::Event(
+                                    AudioBridgeEventEventType::ParticipantSuspended {
+                                        room,
+                                        suspended,
+                                    },
+                                ) => AudioBridgeEvent::ParticipantSuspended { room, suspended },
+                                AudioBridgeEventDto::Event(
+                                    AudioBridgeEventEventType::ParticipantResumed { room, resumed },
+                                ) => AudioBridgeEvent::ParticipantResumed { room, resumed },
+                                AudioBridgeEventDto::AnnouncementStarted { room, file_id } => {
+                                    AudioBridgeEvent::AnnouncementStarted { room, file_id }
+                                }
+                                AudioBridgeEventDto::AnnouncementStopped { room, file_id } => {
+                                    AudioBridgeEvent::AnnouncementStopped { room, file_id }
+                                }
                             },
                             Err(_) => AudioBridgeEvent::Other(data),
                         }
@@ -636,6 +679,118 @@ mod tests {
         matches!(event, Err(jarust_interface::Error::IncompletePacket));
     }
 
+    #[test]
+    fn it_parse_participant_suspended() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.audiobridge".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "audiobridge": "event",
+                        "room": 684657u64,
+                        "suspended": 751378u64
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::AudioBridgeEvent(AudioBridgeEvent::ParticipantSuspended {
+                room: JanusId::Uint(684657.into()),
+                suspended: JanusId::Uint(751378.into()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_participant_resumed() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.audiobridge".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "audiobridge": "event",
+                        "room": 684657u64,
+                        "resumed": 751378u64
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::AudioBridgeEvent(AudioBridgeEvent::ParticipantResumed {
+                room: JanusId::Uint(684657.into()),
+                resumed: JanusId::Uint(751378.into()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_announcement_started() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.audiobridge".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "audiobridge": "announcement-started",
+                        "room": 684657u64,
+                        "file_id": "welcome"
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::AudioBridgeEvent(AudioBridgeEvent::AnnouncementStarted {
+                room: JanusId::Uint(684657.into()),
+                file_id: "welcome".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_announcement_stopped() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.audiobridge".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "audiobridge": "announcement-stopped",
+                        "room": 684657u64,
+                        "file_id": "welcome"
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::AudioBridgeEvent(AudioBridgeEvent::AnnouncementStopped {
+                room: JanusId::Uint(684657.into()),
+                file_id: "welcome".to_string(),
+            })
+        );
+    }
+
     #[test]
     fn it_parse_unsupported_event_as_other() {
         let rsp = JaResponse {
@@ -205,6 +205,162 @@ impl AudioBridgeHandle {
         message["request"] = "kick_all".into();
         self.handle.fire_and_forget(message).await
     }
+
+    /// Plays an Opus file in a room, as an announcement to all participants
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn play_file(
+        &self,
+        params: AudioBridgePlayFileParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgePlayFileRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending play file");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "play_file".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgePlayFileRsp>(message, timeout)
+            .await
+    }
+
+    /// Allows you to check whether a specific announcement is still playing
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn is_playing(
+        &self,
+        params: AudioBridgeIsPlayingParams,
+        timeout: Duration,
+    ) -> Result<bool, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending is playing");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "is_playing".into();
+        let response = self
+            .handle
+            .send_waiton_rsp::<AudioBridgeIsPlayingRsp>(message, timeout)
+            .await?;
+
+        Ok(response.playing)
+    }
+
+    /// Stops a specific announcement before it's over
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn stop_file(
+        &self,
+        params: AudioBridgeStopFileParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgeStopFileRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending stop file");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "stop_file".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeStopFileRsp>(message, timeout)
+            .await
+    }
+
+    /// Stops all the announcements playing in a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn stop_all_files(
+        &self,
+        params: AudioBridgeStopAllFilesParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgeStopAllFilesRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending stop all files");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "stop_all_files".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeStopAllFilesRsp>(message, timeout)
+            .await
+    }
+
+    /// Lists all the announcements currently playing in a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_announcements(
+        &self,
+        params: AudioBridgeListAnnouncementsParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgeListAnnouncementsRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending list announcements");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "listannouncements".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeListAnnouncementsRsp>(message, timeout)
+            .await
+    }
+
+    /// Adds a new RTP forwarder, sending the room mix (or a group of it) to a remote RTP receiver
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn rtp_forward(
+        &self,
+        params: AudioBridgeRtpForwardParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgeRtpForwardRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending rtp forward");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "rtp_forward".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeRtpForwardRsp>(message, timeout)
+            .await
+    }
+
+    /// Stops a previously created RTP forwarder
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn stop_rtp_forward(
+        &self,
+        params: AudioBridgeStopRtpForwardParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgeStopRtpForwardRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending stop rtp forward");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "stop_rtp_forward".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeStopRtpForwardRsp>(message, timeout)
+            .await
+    }
+
+    /// Lists all the RTP forwarders of a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_forwarders(
+        &self,
+        params: AudioBridgeListForwardersParams,
+        timeout: Duration,
+    ) -> Result<AudioBridgeListForwardersRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending list forwarders");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "listforwarders".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeListForwardersRsp>(message, timeout)
+            .await
+    }
+
+    /// Suspends a participant, meaning their audio will not be mixed nor will they receive the mix,
+    /// without tearing down their PeerConnection
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn suspend(
+        &self,
+        params: AudioBridgeSuspendParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending suspend");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "suspend".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Resumes a previously suspended participant
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn resume(
+        &self,
+        params: AudioBridgeResumeParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "audiobridge", "Sending resume");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "resume".into();
+        self.handle
+            .send_waiton_rsp::<AudioBridgeSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
 }
 
 // async
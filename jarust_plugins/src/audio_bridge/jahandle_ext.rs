@@ -13,9 +13,18 @@ pub trait AudioBridge: Attach {
     async fn attach_audio_bridge(
         &self,
         timeout: Duration,
+    ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
+        self.attach_audio_bridge_with_params(AttachParams::default(), timeout)
+            .await
+    }
+
+    async fn attach_audio_bridge_with_params(
+        &self,
+        params: AttachParams,
+        timeout: Duration,
     ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
         let (handle, mut receiver) = self
-            .attach("janus.plugin.audiobridge".to_string(), timeout)
+            .attach_with_params("janus.plugin.audiobridge".to_string(), params, timeout)
             .await?;
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let task = jarust_rt::spawn("audiobridge listener", async move {
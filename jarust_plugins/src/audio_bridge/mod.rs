@@ -1,4 +1,5 @@
 pub mod common;
+pub mod error_codes;
 pub mod events;
 pub mod handle;
 pub mod jahandle_ext;
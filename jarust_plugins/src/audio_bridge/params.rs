@@ -352,3 +352,159 @@ make_dto!(
         denoise: bool
     }
 );
+
+make_dto!(
+    AudioBridgePlayFileParams,
+    required {
+        room: JanusId,
+        /// Path to the Opus file to play
+        filename: String
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String,
+        /// Group to play in (for forwarding purposes only, mandatory if enabled in the room)
+        group: String,
+        /// Unique string ID of the announcement, random if not provided
+        file_id: String,
+        /// Whether the file should be played in a loop forever, false by default
+        #[serde(rename = "loop")]
+        looping: bool
+    }
+);
+
+make_dto!(
+    AudioBridgeIsPlayingParams,
+    required {
+        room: JanusId,
+        /// Unique string ID of the announcement
+        file_id: String
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    AudioBridgeStopFileParams,
+    required {
+        room: JanusId,
+        /// Unique string ID of the announcement
+        file_id: String
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    AudioBridgeStopAllFilesParams,
+    required { room: JanusId },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    AudioBridgeListAnnouncementsParams,
+    required { room: JanusId },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    AudioBridgeRtpForwardParams,
+    required {
+        room: JanusId,
+        /// Host address to forward the RTP packets to
+        host: String,
+        /// Port to forward the RTP packets to
+        port: u16
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String,
+        /// If `lock_rtp_forward` is set in the plugin settings, the `admin_key` (also configured in plugin settings) has to be supplied with RTP forwarding requests
+        admin_key: String,
+        /// Group to forward, if enabled in the room (forwards full mix if missing)
+        group: String,
+        /// SSRC to use when streaming, `stream_id` used if missing
+        ssrc: u32,
+        /// Codec to use, among opus (default), pcma (A-Law) or pcmu (mu-Law)
+        codec: AudioBridgeCodec,
+        /// Payload type to use when streaming, 100 used if missing
+        ptype: u8,
+        /// ipv4|ipv6, if we need to resolve the host address to an IP; by default, whatever we get
+        host_family: String,
+        /// Length of authentication tag (32 or 80)
+        srtp_suite: u16,
+        /// Key to use as crypto (base64 encoded key as in SDES)
+        srtp_crypto: String,
+        /// Whether silence should be forwarded when the room is empty
+        always_on: bool
+    }
+);
+
+make_dto!(
+    AudioBridgeStopRtpForwardParams,
+    required {
+        room: JanusId,
+        /// Unique numeric ID of the RTP forwarder
+        stream_id: u64
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String,
+        /// If `lock_rtp_forward` is set in the plugin settings, the `admin_key` (also configured in plugin settings) has to be supplied with RTP forwarding requests
+        admin_key: String
+    }
+);
+
+make_dto!(
+    AudioBridgeListForwardersParams,
+    required { room: JanusId },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    AudioBridgeSuspendParams,
+    required {
+        room: JanusId,
+        /// Participant ID
+        id: JanusId
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String,
+        /// Whether room events should be paused for the suspended participant, false by default
+        pause_events: bool,
+        /// Whether the recording of this participant should be stopped too, false by default
+        stop_record: bool
+    }
+);
+
+make_dto!(
+    AudioBridgeResumeParams,
+    required {
+        room: JanusId,
+        /// Participant ID
+        id: JanusId
+    },
+    optional {
+        /// Room secret, mandatory if configured
+        secret: String,
+        /// Whether to record this user's contribution to a .mjr file (mixer not involved)
+        record: bool,
+        /// Basename of the file to record to, -audio.mjr will be added by the plugin; will be relative to mjrs_dir,
+        /// if configured in the room
+        filename: String
+    }
+);
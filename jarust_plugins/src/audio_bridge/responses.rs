@@ -55,3 +55,92 @@ pub struct AudioBridgeListParticipantsRsp {
     pub room: JanusId,
     pub participants: Vec<AudioBridgeParticipant>,
 }
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgePlayFileRsp {
+    pub room: JanusId,
+    pub file_id: String,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeIsPlayingRsp {
+    pub room: JanusId,
+    pub file_id: String,
+    pub playing: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeStopFileRsp {
+    pub room: JanusId,
+    pub file_id: String,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeStopAllFilesRsp {
+    pub room: JanusId,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeListAnnouncementsRsp {
+    pub room: JanusId,
+    pub announcements: Vec<AudioBridgeAnnouncement>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeAnnouncement {
+    pub file_id: String,
+    pub filename: String,
+    pub playing: bool,
+    #[serde(rename = "loop")]
+    pub looping: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeRtpForwardRsp {
+    pub room: JanusId,
+    /// Group being forwarded, if any
+    pub group: Option<String>,
+    /// Unique numeric ID assigned to the new RTP forwarder
+    pub stream_id: u64,
+    /// Host this forwarder is streaming to, same as request if not resolved
+    pub host: String,
+    /// Port this forwarder is streaming to, same as request
+    pub port: u16,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeStopRtpForwardRsp {
+    pub room: JanusId,
+    pub stream_id: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeListForwardersRsp {
+    pub room: JanusId,
+    pub rtp_forwarders: Vec<AudioBridgeRtpForwarder>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeRtpForwarder {
+    /// Unique numeric ID of the forwarder
+    pub stream_id: u64,
+    /// Group being forwarded, if any
+    pub group: Option<String>,
+    /// IP this forwarder is streaming to
+    pub ip: String,
+    /// Port this forwarder is streaming to
+    pub port: u16,
+    pub ssrc: Option<u32>,
+    pub codec: Option<String>,
+    pub ptype: Option<u8>,
+    /// Whether the RTP stream is encrypted
+    pub srtp: Option<bool>,
+    /// Whether silence is forwarded when the room is empty
+    pub always_on: Option<bool>,
+}
+
+/// Plain `{"audiobridge": "success"}` response, returned by the suspend and resume requests
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct AudioBridgeSuccessRsp {
+    pub audiobridge: String,
+}
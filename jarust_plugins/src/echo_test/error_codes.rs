@@ -0,0 +1,10 @@
+jarust_interface::error_codes! {
+    /// EchoTest plugin error codes
+    EchoTestErrorCode {
+        NoMessage = 411,
+        InvalidJson = 412,
+        InvalidElement = 413,
+        InvalidSdp = 414,
+        UnknownError = 499,
+    }
+}
@@ -13,9 +13,18 @@ pub trait EchoTest: Attach {
     async fn attach_echo_test(
         &self,
         timeout: Duration,
+    ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
+        self.attach_echo_test_with_params(AttachParams::default(), timeout)
+            .await
+    }
+
+    async fn attach_echo_test_with_params(
+        &self,
+        params: AttachParams,
+        timeout: Duration,
     ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
         let (handle, mut receiver) = self
-            .attach("janus.plugin.echotest".to_string(), timeout)
+            .attach_with_params("janus.plugin.echotest".to_string(), params, timeout)
             .await?;
         let (tx, rx) = mpsc::unbounded_channel();
         let task = jarust_rt::spawn("echotest listener", async move {
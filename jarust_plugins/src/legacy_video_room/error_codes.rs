@@ -0,0 +1,57 @@
+jarust_interface::error_codes! {
+    /// Legacy VideoRoom plugin error codes
+    LegacyVideoRoomErrorCode {
+        NoMessage = 421,
+        InvalidJson = 422,
+        InvalidRequest = 423,
+        JoinFirst = 424,
+        AlreadyJoined = 425,
+        NoSuchRoom = 426,
+        RoomExists = 427,
+        NoSuchFeed = 428,
+        MissingElement = 429,
+        InvalidElement = 430,
+        InvalidSdpType = 431,
+        PublishersFull = 432,
+        Unauthorized = 433,
+        AlreadyPublished = 434,
+        NotPublished = 435,
+        IdExists = 436,
+        InvalidSdp = 437,
+        UnknownError = 499,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::LegacyVideoRoomErrorCode;
+
+    #[test]
+    fn it_matches_the_janus_error_codes() {
+        // From janus_videoroom.c
+        let codes = [
+            (LegacyVideoRoomErrorCode::NoMessage, 421),
+            (LegacyVideoRoomErrorCode::InvalidJson, 422),
+            (LegacyVideoRoomErrorCode::InvalidRequest, 423),
+            (LegacyVideoRoomErrorCode::JoinFirst, 424),
+            (LegacyVideoRoomErrorCode::AlreadyJoined, 425),
+            (LegacyVideoRoomErrorCode::NoSuchRoom, 426),
+            (LegacyVideoRoomErrorCode::RoomExists, 427),
+            (LegacyVideoRoomErrorCode::NoSuchFeed, 428),
+            (LegacyVideoRoomErrorCode::MissingElement, 429),
+            (LegacyVideoRoomErrorCode::InvalidElement, 430),
+            (LegacyVideoRoomErrorCode::InvalidSdpType, 431),
+            (LegacyVideoRoomErrorCode::PublishersFull, 432),
+            (LegacyVideoRoomErrorCode::Unauthorized, 433),
+            (LegacyVideoRoomErrorCode::AlreadyPublished, 434),
+            (LegacyVideoRoomErrorCode::NotPublished, 435),
+            (LegacyVideoRoomErrorCode::IdExists, 436),
+            (LegacyVideoRoomErrorCode::InvalidSdp, 437),
+            (LegacyVideoRoomErrorCode::UnknownError, 499),
+        ];
+        for (variant, code) in codes {
+            assert_eq!(variant.code(), code);
+            assert_eq!(LegacyVideoRoomErrorCode::from(code), variant);
+        }
+    }
+}
@@ -28,6 +28,8 @@ enum LegacyVideoRoomEventDto {
     },
     #[serde(rename = "slow_link")]
     SlowLink,
+    #[serde(rename = "destroyed")]
+    DestroyRoom { room: JanusId },
     #[serde(rename = "event")]
     Event(InnerLegacyVideoRoomEvent),
 }
@@ -43,6 +45,9 @@ enum InnerLegacyVideoRoomEvent {
         room: JanusId,
         publishers: Vec<LegacyVideoRoomPublisher>,
     },
+    UnpublishedRsp {
+        unpublished: String,
+    },
     Unpublished {
         room: JanusId,
         unpublished: JanusId,
@@ -51,11 +56,27 @@ enum InnerLegacyVideoRoomEvent {
         room: JanusId,
         started: String,
     },
+    PausedRsp {
+        paused: String,
+    },
+    SwitchedRsp {
+        switched: String,
+        room: JanusId,
+        id: JanusId,
+    },
     Leaving {
         room: JanusId,
         leaving: String,
         reason: String,
     },
+    LeavingRsp {
+        leaving: String,
+        room: JanusId,
+    },
+    LeftRsp {
+        left: String,
+        room: JanusId,
+    },
     Kicked {
         kicked: JanusId,
         room: JanusId,
@@ -90,14 +111,32 @@ pub enum LegacyVideoRoomEvent {
         jsep: Jsep,
     },
     SlowLink,
+    /// Sent to all participants in the video room when the room is destroyed
+    RoomDestroyed {
+        room: JanusId,
+    },
     Unpublished {
         room: JanusId,
         unpublished: JanusId,
     },
+    /// Sent back to a publisher after a successful [unpublish](super::handle::LegacyVideoRoomHandle::unpublish) request
+    UnpublishedAsyncRsp,
     SubscriberStarted {
         room: JanusId,
         started: String,
     },
+    /// Sent back to a subscriber after a successful [pause](super::handle::LegacyVideoRoomHandle::pause) request
+    PausedAsyncRsp,
+    /// Sent back to a subscriber after a successful [switch](super::handle::LegacyVideoRoomHandle::switch) request
+    SubscriberSwitched {
+        room: JanusId,
+        /// unique ID of the new publisher
+        id: JanusId,
+    },
+    /// Sent back to a publisher or a subscriber after a successful [leave](super::handle::LegacyVideoRoomHandle::leave) request
+    LeftAsyncRsp {
+        room: JanusId,
+    },
     Leaving {
         room: JanusId,
         reason: String,
@@ -163,6 +202,9 @@ impl TryFrom<JaResponse> for PluginEvent {
                                     }
                                 }
                                 LegacyVideoRoomEventDto::SlowLink => LegacyVideoRoomEvent::SlowLink,
+                                LegacyVideoRoomEventDto::DestroyRoom { room } => {
+                                    LegacyVideoRoomEvent::RoomDestroyed { room }
+                                }
                                 LegacyVideoRoomEventDto::Event(event) => match event {
                                     InnerLegacyVideoRoomEvent::Configured { room, .. } => {
                                         LegacyVideoRoomEvent::Configured {
@@ -178,6 +220,19 @@ impl TryFrom<JaResponse> for PluginEvent {
                                         room,
                                         unpublished,
                                     } => LegacyVideoRoomEvent::Unpublished { room, unpublished },
+                                    InnerLegacyVideoRoomEvent::UnpublishedRsp { .. } => {
+                                        LegacyVideoRoomEvent::UnpublishedAsyncRsp
+                                    }
+                                    InnerLegacyVideoRoomEvent::PausedRsp { .. } => {
+                                        LegacyVideoRoomEvent::PausedAsyncRsp
+                                    }
+                                    InnerLegacyVideoRoomEvent::SwitchedRsp { room, id, .. } => {
+                                        LegacyVideoRoomEvent::SubscriberSwitched { room, id }
+                                    }
+                                    InnerLegacyVideoRoomEvent::LeavingRsp { room, .. }
+                                    | InnerLegacyVideoRoomEvent::LeftRsp { room, .. } => {
+                                        LegacyVideoRoomEvent::LeftAsyncRsp { room }
+                                    }
                                     InnerLegacyVideoRoomEvent::Started { room, started } => {
                                         LegacyVideoRoomEvent::SubscriberStarted { room, started }
                                     }
@@ -510,6 +565,165 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_room_destroyed() {
+        let raw_event = json!({
+            "janus": "event",
+            "session_id": 7323526979899781u64,
+            "sender": 7967725809069290u64,
+            "plugindata": {
+                "plugin": "janus.plugin.videoroom",
+                "data": {
+                    "videoroom": "destroyed",
+                    "room": 8146468u64
+                }
+            }
+        });
+        let event: PluginEvent = serde_json::from_value::<JaResponse>(raw_event)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::LegacyVideoRoomEvent(LegacyVideoRoomEvent::RoomDestroyed {
+                room: JanusId::Uint(8146468.into())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_unpublished_rsp() {
+        let raw_event = json!({
+            "janus": "event",
+            "session_id": 7323526979899781u64,
+            "sender": 7967725809069290u64,
+            "plugindata": {
+                "plugin": "janus.plugin.videoroom",
+                "data": {
+                    "videoroom": "event",
+                    "room": 8146468u64,
+                    "unpublished": "ok"
+                }
+            }
+        });
+        let event: PluginEvent = serde_json::from_value::<JaResponse>(raw_event)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::LegacyVideoRoomEvent(LegacyVideoRoomEvent::UnpublishedAsyncRsp)
+        );
+    }
+
+    #[test]
+    fn parse_paused_rsp() {
+        let raw_event = json!({
+            "janus": "event",
+            "session_id": 7323526979899781u64,
+            "sender": 7967725809069290u64,
+            "plugindata": {
+                "plugin": "janus.plugin.videoroom",
+                "data": {
+                    "videoroom": "event",
+                    "room": 8146468u64,
+                    "paused": "ok"
+                }
+            }
+        });
+        let event: PluginEvent = serde_json::from_value::<JaResponse>(raw_event)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::LegacyVideoRoomEvent(LegacyVideoRoomEvent::PausedAsyncRsp)
+        );
+    }
+
+    #[test]
+    fn parse_switched_rsp() {
+        let raw_event = json!({
+            "janus": "event",
+            "session_id": 7323526979899781u64,
+            "sender": 7967725809069290u64,
+            "plugindata": {
+                "plugin": "janus.plugin.videoroom",
+                "data": {
+                    "videoroom": "event",
+                    "room": 8146468u64,
+                    "switched": "ok",
+                    "id": 1337
+                }
+            }
+        });
+        let event: PluginEvent = serde_json::from_value::<JaResponse>(raw_event)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::LegacyVideoRoomEvent(LegacyVideoRoomEvent::SubscriberSwitched {
+                room: JanusId::Uint(8146468.into()),
+                id: JanusId::Uint(1337.into())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_publisher_left_rsp() {
+        let raw_event = json!({
+            "janus": "event",
+            "session_id": 7323526979899781u64,
+            "sender": 7967725809069290u64,
+            "plugindata": {
+                "plugin": "janus.plugin.videoroom",
+                "data": {
+                    "videoroom": "event",
+                    "room": 8146468u64,
+                    "leaving": "ok"
+                }
+            }
+        });
+        let event: PluginEvent = serde_json::from_value::<JaResponse>(raw_event)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::LegacyVideoRoomEvent(LegacyVideoRoomEvent::LeftAsyncRsp {
+                room: JanusId::Uint(8146468.into())
+            })
+        );
+    }
+
+    #[test]
+    fn parse_subscriber_left_rsp() {
+        let raw_event = json!({
+            "janus": "event",
+            "session_id": 7323526979899781u64,
+            "sender": 7967725809069290u64,
+            "plugindata": {
+                "plugin": "janus.plugin.videoroom",
+                "data": {
+                    "videoroom": "event",
+                    "room": 8146468u64,
+                    "left": "ok"
+                }
+            }
+        });
+        let event: PluginEvent = serde_json::from_value::<JaResponse>(raw_event)
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::LegacyVideoRoomEvent(LegacyVideoRoomEvent::LeftAsyncRsp {
+                room: JanusId::Uint(8146468.into())
+            })
+        );
+    }
+
     #[test]
     fn parse_kicked() {
         let raw_event = json!({
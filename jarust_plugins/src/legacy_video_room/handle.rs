@@ -1,12 +1,32 @@
+use super::params::LegacyVideoRoomAllowedParams;
 use super::params::LegacyVideoRoomCreateParams;
+use super::params::LegacyVideoRoomDestroyParams;
+use super::params::LegacyVideoRoomEditParams;
+use super::params::LegacyVideoRoomEnableRecordingParams;
 use super::params::LegacyVideoRoomExistsParams;
 use super::params::LegacyVideoRoomKickParams;
+use super::params::LegacyVideoRoomListForwardersParams;
+use super::params::LegacyVideoRoomListParticipantsParams;
+use super::params::LegacyVideoRoomPublishParams;
 use super::params::LegacyVideoRoomPublisherConfigureParams;
 use super::params::LegacyVideoRoomPublisherJoinAndConfigureParams;
 use super::params::LegacyVideoRoomPublisherJoinParams;
+use super::params::LegacyVideoRoomRtpForwardParams;
+use super::params::LegacyVideoRoomStopRtpForwardParams;
 use super::params::LegacyVideoRoomSubscriberConfigureParams;
 use super::params::LegacyVideoRoomSubscriberJoinParams;
+use super::params::LegacyVideoRoomSwitchParams;
+use super::responses::LegacyVideoRoomAllowedRsp;
 use super::responses::LegacyVideoRoomCreatedRsp;
+use super::responses::LegacyVideoRoomDestroyedRsp;
+use super::responses::LegacyVideoRoomEditedRsp;
+use super::responses::LegacyVideoRoomListForwardersRsp;
+use super::responses::LegacyVideoRoomListParticipantsRsp;
+use super::responses::LegacyVideoRoomListRoomsRsp;
+use super::responses::LegacyVideoRoomRoom;
+use super::responses::LegacyVideoRoomRtpForwardRsp;
+use super::responses::LegacyVideoRoomStopRtpForwardRsp;
+use super::responses::LegacyVideoRoomSuccessRsp;
 use crate::legacy_video_room::responses::LegacyVideoRoomExistsRsp;
 use jarust_core::prelude::*;
 use jarust_interface::japrotocol::Jsep;
@@ -73,6 +93,149 @@ impl LegacyVideoRoomHandle {
 
         self.handle.send_waiton_rsp::<()>(message, timeout).await
     }
+
+    /// Allows you to dynamically edit some room properties (e.g., the PIN)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn edit_room(
+        &self,
+        params: LegacyVideoRoomEditParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomEditedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending edit");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "edit".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomEditedRsp>(message, timeout)
+            .await
+    }
+
+    /// Destroy an existing video room, whether created dynamically or statically
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn destroy_room(
+        &self,
+        params: LegacyVideoRoomDestroyParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomDestroyedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending destroy");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "destroy".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomDestroyedRsp>(message, timeout)
+            .await
+    }
+
+    /// Get a list of the available rooms
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_rooms(
+        &self,
+        timeout: Duration,
+    ) -> Result<Vec<LegacyVideoRoomRoom>, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending list");
+        let response = self
+            .handle
+            .send_waiton_rsp::<LegacyVideoRoomListRoomsRsp>(json!({"request": "list"}), timeout)
+            .await?;
+        Ok(response.list)
+    }
+
+    /// Allows you to edit who's allowed to join a room via ad-hoc tokens
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn allowed(
+        &self,
+        params: LegacyVideoRoomAllowedParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomAllowedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending allowed");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "allowed".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomAllowedRsp>(message, timeout)
+            .await
+    }
+
+    /// Get a list of the participants in a specific room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_participants(
+        &self,
+        params: LegacyVideoRoomListParticipantsParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomListParticipantsRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending list participants");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "listparticipants".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomListParticipantsRsp>(message, timeout)
+            .await
+    }
+
+    /// Enable or disable recording on all participants while the conference is in progress
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn enable_recording(
+        &self,
+        params: LegacyVideoRoomEnableRecordingParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending enable recording");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "enable_recording".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Forward the media of a publisher to a remote RTP receiver
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn rtp_forward(
+        &self,
+        params: LegacyVideoRoomRtpForwardParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomRtpForwardRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending rtp forward");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "rtp_forward".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomRtpForwardRsp>(message, timeout)
+            .await
+    }
+
+    /// Stop a previously created RTP forwarder
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn stop_rtp_forward(
+        &self,
+        params: LegacyVideoRoomStopRtpForwardParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomStopRtpForwardRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending stop rtp forward");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "stop_rtp_forward".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomStopRtpForwardRsp>(message, timeout)
+            .await
+    }
+
+    /// Get a list of all the RTP forwarders in a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_forwarders(
+        &self,
+        params: LegacyVideoRoomListForwardersParams,
+        timeout: Duration,
+    ) -> Result<LegacyVideoRoomListForwardersRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Sending list forwarders");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "listforwarders".into();
+
+        self.handle
+            .send_waiton_rsp::<LegacyVideoRoomListForwardersRsp>(message, timeout)
+            .await
+    }
 }
 
 // async
@@ -160,6 +323,54 @@ impl LegacyVideoRoomHandle {
             .send_waiton_ack_with_jsep(json!({"request": "start"}), jsep, timeout)
             .await
     }
+
+    /// Start publishing in a room
+    ///
+    /// This request MUST be accompanied by a JSEP SDP offer to negotiate a new PeerConnection.
+    pub async fn publish(
+        &self,
+        params: LegacyVideoRoomPublishParams,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<String, jarust_interface::Error> {
+        let mut message: Value = params.try_into()?;
+        message["request"] = "publish".into();
+        self.handle
+            .send_waiton_ack_with_jsep(message, jsep, timeout)
+            .await
+    }
+
+    /// Stop publishing and tear down the related PeerConnection
+    pub async fn unpublish(&self, timeout: Duration) -> Result<String, jarust_interface::Error> {
+        self.handle
+            .send_waiton_ack(json!({"request": "unpublish"}), timeout)
+            .await
+    }
+
+    /// Leave the room, as either a publisher or a subscriber
+    pub async fn leave(&self, timeout: Duration) -> Result<String, jarust_interface::Error> {
+        self.handle
+            .send_waiton_ack(json!({"request": "leave"}), timeout)
+            .await
+    }
+
+    /// Temporarily pause the delivery of media to a subscriber, resumed with [`LegacyVideoRoomHandle::start`]
+    pub async fn pause(&self, timeout: Duration) -> Result<String, jarust_interface::Error> {
+        self.handle
+            .send_waiton_ack(json!({"request": "pause"}), timeout)
+            .await
+    }
+
+    /// Switch a subscriber to a different publisher, without renegotiating the PeerConnection
+    pub async fn switch(
+        &self,
+        params: LegacyVideoRoomSwitchParams,
+        timeout: Duration,
+    ) -> Result<String, jarust_interface::Error> {
+        let mut message: Value = params.try_into()?;
+        message["request"] = "switch".into();
+        self.handle.send_waiton_ack(message, timeout).await
+    }
 }
 
 impl PluginTask for LegacyVideoRoomHandle {
@@ -13,9 +13,18 @@ pub trait LegacyVideoRoom: Attach {
     async fn attach_legacy_video_room(
         &self,
         timeout: Duration,
+    ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
+        self.attach_legacy_video_room_with_params(AttachParams::default(), timeout)
+            .await
+    }
+
+    async fn attach_legacy_video_room_with_params(
+        &self,
+        params: AttachParams,
+        timeout: Duration,
     ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
         let (handle, mut receiver) = self
-            .attach("janus.plugin.videoroom".to_string(), timeout)
+            .attach_with_params("janus.plugin.videoroom".to_string(), params, timeout)
             .await?;
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let task = jarust_rt::spawn("videoroom listener", async move {
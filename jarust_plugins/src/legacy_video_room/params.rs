@@ -315,3 +315,216 @@ make_dto!(
         max_delay: u64,
     }
 );
+
+make_dto!(
+    LegacyVideoRoomEditParams,
+    required { room: JanusId },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// new pretty name of the room
+        new_description: String,
+        /// whether the room should appear in a list request
+        new_is_private: bool,
+        /// new password required to edit/destroy the room
+        new_secret: String,
+        /// new PIN required to join the room, PIN will be removed if set to an empty string
+        new_pin: String,
+        /// whether the room should require `private_id` from subscribers
+        new_require_pvtid: bool,
+        /// new bitrate cap to force on all publishers (except those with custom overrides)
+        new_bitrate: u64,
+        /// new period for regular PLI keyframe requests to publishers
+        new_fir_freq: u64,
+        /// new cap on the number of concurrent active WebRTC publishers
+        new_publishers: u64,
+        /// whether recording state can only be changed when providing the room secret
+        new_lock_record: bool,
+        /// whether the room should be also removed from the config file, default=false
+        permanent: bool,
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomDestroyParams,
+    required { room: JanusId },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// whether the room should be also removed from the config file, default=false
+        permanent: bool
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomAllowedParams,
+    required {
+        room: JanusId,
+        action: LegacyVideoRoomAllowedAction,
+        /// array of strings (tokens users might pass in "join", only for add|remove)
+        #[serde(skip_serializing_if = "Vec::is_empty")]
+        allowed: Vec<String>
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum LegacyVideoRoomAllowedAction {
+    Enable,
+    Disable,
+    Add,
+    Remove,
+}
+
+make_dto!(
+    LegacyVideoRoomListParticipantsParams,
+    required { room: JanusId }
+);
+
+make_dto!(
+    LegacyVideoRoomEnableRecordingParams,
+    required {
+        room: JanusId,
+        /// whether participants in this room should be automatically recorded or not
+        record: bool
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomPublishParams,
+    optional {
+        /// depending on whether or not audio should be relayed; true by default
+        audio: bool,
+        /// depending on whether or not video should be relayed; true by default
+        video: bool,
+        /// depending on whether or not data should be relayed; true by default
+        data: bool,
+        /// audio codec to prefer among the negotiated ones
+        audiocodec: LegacyVideoRoomAudioCodec,
+        /// video codec to prefer among the negotiated ones
+        videocodec: LegacyVideoRoomVideoCodec,
+        /// bitrate cap to return via REMB;
+        /// overrides the global room value if present (unless `bitrate_cap` is set)
+        bitrate: u64,
+        /// whether this publisher should be recorded or not
+        record: bool,
+        /// if recording, the base path/file to use for the recording files
+        filename: String,
+        /// new display name to use in the room
+        display: String,
+        /// new `audio_active_packets` to overwrite in the room one
+        audio_active_packets: u64,
+        /// new `audio_level_average` to overwrite the room one
+        audio_level_average: u64,
+        /// minimum delay to enforce via the playout-delay RTP extension, in blocks of 10ms
+        min_delay: u64,
+        /// maximum delay to enforce via the playout-delay RTP extension, in blocks of 10ms
+        max_delay: u64
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomSwitchParams,
+    required {
+        /// unique ID of the new publisher to switch to
+        feed: JanusId
+    },
+    optional {
+        /// depending on whether audio should be relayed or not
+        audio: bool,
+        /// depending on whether video should be relayed or not
+        video: bool,
+        /// depending on whether datachannel messages should be relayed or not
+        data: bool
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomRtpForwardParams,
+    required {
+        room: JanusId,
+        /// unique ID of the publisher to relay externally
+        publisher_id: JanusId,
+        /// host address to forward the RTP and data packets to
+        host: String
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// If `lock_rtp_forward` is set in the plugin settings, the `admin_key` (also configured in plugin settings) has to be supplied with RTP forwarding requests
+        admin_key: String,
+        /// ipv4|ipv6, if we need to resolve the host address to an IP; by default, whatever we get
+        host_family: String,
+        /// port to forward the audio RTP packets to
+        audio_port: u16,
+        /// port to contact to receive audio RTCP feedback from the recipient; currently unused for audio
+        audio_rtcp_port: u16,
+        /// audio SSRC to use when forwarding
+        audio_ssrc: u32,
+        /// audio payload type to use when forwarding
+        audio_pt: u8,
+        /// port to forward the video RTP packets to
+        video_port: u16,
+        /// port to contact to receive video RTCP feedback from the recipient
+        video_rtcp_port: u16,
+        /// video SSRC to use when forwarding
+        video_ssrc: u32,
+        /// video payload type to use when forwarding
+        video_pt: u8,
+        /// if simulcasting, port to forward the video RTP packets from the second substream/layer to
+        video_port_2: u16,
+        /// if simulcasting, SSRC to use the second substream/layer
+        video_ssrc_2: u32,
+        /// if simulcasting, payload type to use the second substream/layer
+        video_pt_2: u8,
+        /// if simulcasting, port to forward the video RTP packets from the third substream/layer to
+        video_port_3: u16,
+        /// if simulcasting, SSRC to use the third substream/layer
+        video_ssrc_3: u32,
+        /// if simulcasting, payload type to use the third substream/layer
+        video_pt_3: u8,
+        /// set to true if the source is simulcast and you want the forwarder to act as a regular viewer
+        /// (single stream being forwarded) or false otherwise (substreams forwarded separately), default=false
+        simulcast: bool,
+        /// port to forward the datachannel messages to
+        data_port: u16,
+        /// length of authentication tag (32 or 80)
+        srtp_suite: u16,
+        /// key to use as crypto (base64 encoded key as in SDES)
+        srtp_crypto: String
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomStopRtpForwardParams,
+    required {
+        room: JanusId,
+        /// unique ID of the publisher the forwarder belongs to
+        publisher_id: JanusId,
+        /// unique numeric ID of the RTP forwarder
+        stream_id: u64
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// If `lock_rtp_forward` is set in the plugin settings, the `admin_key` (also configured in plugin settings) has to be supplied with RTP forwarding requests
+        admin_key: String
+    }
+);
+
+make_dto!(
+    LegacyVideoRoomListForwardersParams,
+    required { room: JanusId },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String
+    }
+);
@@ -21,3 +21,170 @@ pub struct LegacyVideoRoomPublisher {
     pub display: Option<String>,
     pub substream: Option<u8>,
 }
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomEditedRsp {
+    pub room: JanusId,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomDestroyedRsp {
+    pub room: JanusId,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomListRoomsRsp {
+    pub list: Vec<LegacyVideoRoomRoom>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomRoom {
+    /// unique numeric ID
+    pub room: JanusId,
+    /// name of the room
+    pub description: String,
+    /// whether a PIN is required to join this room
+    pub pin_required: bool,
+    /// how many publishers can actually publish via WebRTC at the same time
+    pub max_publishers: u64,
+    /// bitrate cap that should be forced (via REMB) on all publishers by default
+    pub bitrate: u64,
+    /// whether the above cap should act as a limit to dynamic bitrate changes by publishers
+    pub bitrate_cap: Option<bool>,
+    /// how often a keyframe request is sent via PLI/FIR to active publishers
+    pub fir_freq: u64,
+    /// whether subscriptions in this room require a private_id
+    pub require_pvtid: Option<bool>,
+    /// whether an event is sent to notify all participants if a new participant joins the room
+    pub notify_joining: Option<bool>,
+    /// comma separated list of allowed audio codecs
+    pub audiocodec: String,
+    /// comma separated list of allowed video codecs
+    pub videocodec: String,
+    /// whether the room is being recorded
+    pub record: bool,
+    /// if recording, the path where the .mjr files are being saved
+    pub record_dir: Option<String>,
+    /// whether the room recording state can only be changed providing the secret
+    pub lock_record: Option<bool>,
+    /// count of the participants (publishers, active or not; not subscribers)
+    pub num_participants: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomAllowedRsp {
+    pub room: JanusId,
+    #[serde(default = "Vec::default")]
+    pub allowed: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomListParticipantsRsp {
+    pub room: JanusId,
+    pub participants: Vec<LegacyVideoRoomParticipant>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomParticipant {
+    /// unique numeric ID of the participant
+    pub id: JanusId,
+    /// display name of the participant, if any
+    pub display: Option<String>,
+    /// whether user is an active publisher in the room
+    pub publisher: bool,
+    /// whether user is talking or not (only if audio levels are used)
+    pub talking: Option<bool>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomRtpForwardRsp {
+    /// unique ID, same as request
+    pub room: JanusId,
+    /// unique ID, same as request
+    pub publisher_id: JanusId,
+    pub rtp_stream: LegacyVideoRoomRtpStream,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomRtpStream {
+    /// host this forwarder is streaming to, same as request if not resolved
+    pub host: String,
+    /// audio RTP port, same as request if configured
+    pub audio: Option<u16>,
+    /// audio RTCP port, same as request if configured
+    pub audio_rtcp: Option<u16>,
+    /// unique numeric ID assigned to the audio RTP forwarder, if any
+    pub audio_stream_id: Option<u64>,
+    /// video RTP port, same as request if configured
+    pub video: Option<u16>,
+    /// video RTCP port, same as request if configured
+    pub video_rtcp: Option<u16>,
+    /// unique numeric ID assigned to the main video RTP forwarder, if any
+    pub video_stream_id: Option<u64>,
+    /// second video port, same as request if configured
+    pub video_2: Option<u16>,
+    /// unique numeric ID assigned to the second video RTP forwarder, if any
+    pub video_stream_id_2: Option<u64>,
+    /// third video port, same as request if configured
+    pub video_3: Option<u16>,
+    /// unique numeric ID assigned to the third video RTP forwarder, if any
+    pub video_stream_id_3: Option<u64>,
+    /// data port, same as request if configured
+    pub data: Option<u16>,
+    /// unique numeric ID assigned to datachannel messages forwarder, if any
+    pub data_stream_id: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomStopRtpForwardRsp {
+    /// unique ID, same as request
+    pub room: JanusId,
+    /// unique ID, same as request
+    pub publisher_id: JanusId,
+    /// unique numeric ID, same as request
+    pub stream_id: u64,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomListForwardersRsp {
+    /// unique ID of the room
+    pub room: JanusId,
+    /// Array of publishers with RTP forwarders
+    pub rtp_forwarders: Vec<LegacyVideoRoomRtpForwarderPublisher>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomRtpForwarderPublisher {
+    pub publisher_id: JanusId,
+    pub rtp_forwarder: Vec<LegacyVideoRoomRtpForwarder>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomRtpForwarder {
+    /// unique numeric ID assigned to this audio RTP forwarder, if any
+    pub audio_stream_id: Option<u64>,
+    /// unique numeric ID assigned to this video RTP forwarder, if any
+    pub video_stream_id: Option<u64>,
+    /// unique numeric ID assigned to this datachannel messages forwarder, if any
+    pub data_stream_id: Option<u64>,
+    /// IP this forwarder is streaming to
+    pub ip: String,
+    /// port this forwarder is streaming to
+    pub port: u16,
+    /// local port this forwarder is using to get RTCP feedback, if any
+    pub rtcp_port: Option<u16>,
+    /// SSRC this forwarder is using, if any
+    pub ssrc: Option<u32>,
+    /// payload type this forwarder is using, if any
+    pub pt: Option<u8>,
+    /// video substream this video forwarder is relaying, if any
+    pub substream: Option<u8>,
+    /// whether the RTP stream is encrypted
+    pub srtp: Option<bool>,
+}
+
+/// Plain `{"videoroom": "success"}` response, returned by the enable_recording request
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct LegacyVideoRoomSuccessRsp {
+    pub videoroom: String,
+}
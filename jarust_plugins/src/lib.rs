@@ -7,6 +7,11 @@
 //! - AudioBridge plugin
 //! - VideoRoom plugin
 //! - Streaming plugin (minimal support)
+//! - TextRoom plugin
+//! - SIP plugin
+//! - VideoCall plugin
+//! - RecordPlay plugin
+//! - NoSIP plugin
 //!
 //! All of the plugins are hidden behind feature flags to allow you to cherry-pick your dependencies. By default, all plugins are enabled.
 //!
@@ -35,5 +40,20 @@ pub mod streaming;
 #[cfg(feature = "legacy-video-room")]
 pub mod legacy_video_room;
 
+#[cfg(feature = "text-room")]
+pub mod text_room;
+
+#[cfg(feature = "sip")]
+pub mod sip;
+
+#[cfg(feature = "video-call")]
+pub mod video_call;
+
+#[cfg(feature = "record-play")]
+pub mod record_play;
+
+#[cfg(feature = "no-sip")]
+pub mod no_sip;
+
 pub mod common;
 pub use common::JanusId;
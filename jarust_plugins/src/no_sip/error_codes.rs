@@ -0,0 +1,17 @@
+jarust_interface::error_codes! {
+    /// NoSIP plugin error codes
+    NoSipErrorCode {
+        NoMessage = 440,
+        InvalidJson = 441,
+        InvalidRequest = 442,
+        MissingElement = 443,
+        InvalidElement = 444,
+        WrongState = 445,
+        MissingSdp = 446,
+        InvalidSdp = 447,
+        IoError = 448,
+        RecordingError = 449,
+        TooStrict = 450,
+        UnknownError = 499,
+    }
+}
@@ -0,0 +1,238 @@
+use super::params::NoSipSdpType;
+use super::params::NoSipSrtp;
+use jarust_interface::japrotocol::GenericEvent;
+use jarust_interface::japrotocol::JaHandleEvent;
+use jarust_interface::japrotocol::JaResponse;
+use jarust_interface::japrotocol::Jsep;
+use jarust_interface::japrotocol::PluginInnerData;
+use jarust_interface::japrotocol::ResponseType;
+use serde::Deserialize;
+use serde_json::from_value;
+use serde_json::Value;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "nosip")]
+enum NoSipEventDto {
+    #[serde(rename = "event")]
+    Event { result: NoSipEventResult },
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum NoSipEventResult {
+    Generated {
+        #[serde(rename = "type")]
+        sdp_type: NoSipSdpType,
+        sdp: String,
+        #[serde(default)]
+        update: bool,
+        info: Option<String>,
+    },
+    Processed {
+        srtp: Option<NoSipSrtp>,
+        info: Option<String>,
+    },
+    HangingUp,
+    RecordingUpdated,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PluginEvent {
+    NoSipEvent(NoSipEvent),
+    GenericEvent(GenericEvent),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum NoSipEvent {
+    /// Sent back after a successful [generate](super::handle::NoSipHandle::generate) request
+    Generated {
+        /// whether the barebone SDP is an offer or an answer
+        sdp_type: NoSipSdpType,
+        /// barebone SDP to send to the SIP peer
+        sdp: String,
+        /// whether this is a renegotiation of an existing session
+        update: bool,
+        info: Option<String>,
+    },
+    /// Sent back after a successful [process](super::handle::NoSipHandle::process) request
+    Processed {
+        srtp: Option<NoSipSrtp>,
+        info: Option<String>,
+    },
+    /// Sent back after a successful [process](super::handle::NoSipHandle::process) request with the WebRTC JSEP
+    ProcessedWithJsep {
+        srtp: Option<NoSipSrtp>,
+        info: Option<String>,
+        jsep: Jsep,
+    },
+    /// Sent back after a successful [hangup](super::handle::NoSipHandle::hangup) request
+    HangingUp,
+    /// Sent back after a successful [recording](super::handle::NoSipHandle::recording) request
+    RecordingUpdated,
+    Error {
+        error_code: u16,
+        error: String,
+    },
+    Other(Value),
+}
+
+impl TryFrom<JaResponse> for PluginEvent {
+    type Error = jarust_interface::Error;
+
+    fn try_from(value: JaResponse) -> Result<Self, Self::Error> {
+        use NoSipEventResult as Event;
+
+        match value.janus {
+            ResponseType::Event(JaHandleEvent::PluginEvent { plugin_data }) => {
+                let nosip_event = match plugin_data.data {
+                    PluginInnerData::Error { error_code, error } => {
+                        NoSipEvent::Error { error_code, error }
+                    }
+                    PluginInnerData::Data(data) => {
+                        match from_value::<NoSipEventDto>(data.clone()) {
+                            Ok(NoSipEventDto::Event { result }) => match result {
+                                Event::Generated {
+                                    sdp_type,
+                                    sdp,
+                                    update,
+                                    info,
+                                } => NoSipEvent::Generated {
+                                    sdp_type,
+                                    sdp,
+                                    update,
+                                    info,
+                                },
+                                Event::Processed { srtp, info } => match value.jsep {
+                                    Some(jsep) => {
+                                        NoSipEvent::ProcessedWithJsep { srtp, info, jsep }
+                                    }
+                                    None => NoSipEvent::Processed { srtp, info },
+                                },
+                                Event::HangingUp => NoSipEvent::HangingUp,
+                                Event::RecordingUpdated => NoSipEvent::RecordingUpdated,
+                            },
+                            Err(_) => NoSipEvent::Other(data),
+                        }
+                    }
+                };
+                Ok(PluginEvent::NoSipEvent(nosip_event))
+            }
+            ResponseType::Event(JaHandleEvent::GenericEvent(event)) => {
+                Ok(PluginEvent::GenericEvent(event))
+            }
+            _ => Err(Self::Error::IncompletePacket),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginEvent;
+    use crate::no_sip::events::NoSipEvent;
+    use crate::no_sip::params::NoSipSdpType;
+    use crate::no_sip::params::NoSipSrtp;
+    use jarust_interface::japrotocol::JaHandleEvent;
+    use jarust_interface::japrotocol::JaResponse;
+    use jarust_interface::japrotocol::Jsep;
+    use jarust_interface::japrotocol::JsepType;
+    use jarust_interface::japrotocol::PluginData;
+    use jarust_interface::japrotocol::PluginInnerData;
+    use jarust_interface::japrotocol::ResponseType;
+    use serde_json::json;
+
+    #[test]
+    fn it_parse_generated() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.nosip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "nosip": "event",
+                        "result": {
+                            "event": "generated",
+                            "type": "offer",
+                            "sdp": "v=0\r\n",
+                            "update": false
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::NoSipEvent(NoSipEvent::Generated {
+                sdp_type: NoSipSdpType::Offer,
+                sdp: "v=0\r\n".to_string(),
+                update: false,
+                info: None,
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_processed_with_jsep() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.nosip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "nosip": "event",
+                        "result": {
+                            "event": "processed",
+                            "srtp": "sdes_optional"
+                        }
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Answer,
+                trickle: None,
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::NoSipEvent(NoSipEvent::ProcessedWithJsep {
+                srtp: Some(NoSipSrtp::SdesOptional),
+                info: None,
+                jsep: Jsep {
+                    jsep_type: JsepType::Answer,
+                    trickle: None,
+                    sdp: "test_sdp".to_string(),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_hangingup() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.nosip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "nosip": "event",
+                        "result": {
+                            "event": "hangingup"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(event, PluginEvent::NoSipEvent(NoSipEvent::HangingUp));
+    }
+}
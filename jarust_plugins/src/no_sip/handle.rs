@@ -0,0 +1,121 @@
+use super::params::*;
+use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
+use jarust_rt::JaTask;
+use serde_json::json;
+use serde_json::Value;
+use std::ops::Deref;
+use std::time::Duration;
+
+pub struct NoSipHandle {
+    handle: JaHandle,
+    task: Option<JaTask>,
+}
+
+// async, the outcome of every request is notified with an event
+impl NoSipHandle {
+    /// Convert a WebRTC JSEP (offer or answer) into a barebone SDP for the SIP peer
+    ///
+    /// The result is notified with a [`NoSipEvent::Generated`](super::events::NoSipEvent::Generated) event.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn generate(
+        &self,
+        params: NoSipGenerateParams,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "nosip", "Sending generate");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "generate".into();
+        self.handle
+            .send_waiton_ack_with_jsep(message, jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Convert a barebone SDP (offer or answer) received from the SIP peer into a WebRTC JSEP
+    ///
+    /// The result is notified with a [`NoSipEvent::ProcessedWithJsep`](super::events::NoSipEvent::ProcessedWithJsep) event.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn process(
+        &self,
+        params: NoSipProcessParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "nosip", "Sending process");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "process".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Tear down the media session
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn hangup(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "nosip", "Sending hangup");
+        self.handle
+            .send_waiton_ack(json!({"request": "hangup"}), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Start or stop recording the media session
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn recording(
+        &self,
+        params: NoSipRecordingParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "nosip", "Sending recording");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "recording".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Request a keyframe to the WebRTC user, the SIP peer or both
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn keyframe(
+        &self,
+        params: NoSipKeyframeParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "nosip", "Sending keyframe");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "keyframe".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+}
+
+impl PluginTask for NoSipHandle {
+    fn assign_task(&mut self, task: JaTask) {
+        self.task = Some(task);
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        };
+    }
+}
+
+impl From<JaHandle> for NoSipHandle {
+    fn from(handle: JaHandle) -> Self {
+        Self { handle, task: None }
+    }
+}
+
+impl Deref for NoSipHandle {
+    type Target = JaHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Drop for NoSipHandle {
+    fn drop(&mut self) {
+        self.cancel_task();
+    }
+}
@@ -0,0 +1,46 @@
+use super::events::PluginEvent;
+use super::handle::NoSipHandle;
+use jarust_core::prelude::*;
+use std::ops::Deref;
+use std::time::Duration;
+use tokio::sync::mpsc;
+
+#[async_trait::async_trait]
+pub trait NoSip: Attach {
+    type Event: TryFrom<JaResponse, Error = jarust_interface::Error> + Send + Sync + 'static;
+    type Handle: From<JaHandle> + Deref<Target = JaHandle> + PluginTask;
+
+    async fn attach_no_sip(
+        &self,
+        timeout: Duration,
+    ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
+        self.attach_no_sip_with_params(AttachParams::default(), timeout)
+            .await
+    }
+
+    async fn attach_no_sip_with_params(
+        &self,
+        params: AttachParams,
+        timeout: Duration,
+    ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
+        let (handle, mut receiver) = self
+            .attach_with_params("janus.plugin.nosip".to_string(), params, timeout)
+            .await?;
+        let (tx, rx) = mpsc::unbounded_channel();
+        let task = jarust_rt::spawn("nosip listener", async move {
+            while let Some(rsp) = receiver.recv().await {
+                if let Ok(event) = rsp.try_into() {
+                    let _ = tx.send(event);
+                };
+            }
+        });
+        let mut handle: Self::Handle = handle.into();
+        handle.assign_task(task);
+        Ok((handle, rx))
+    }
+}
+
+impl NoSip for JaSession {
+    type Event = PluginEvent;
+    type Handle = NoSipHandle;
+}
@@ -0,0 +1,5 @@
+pub mod error_codes;
+pub mod events;
+pub mod handle;
+pub mod jahandle_ext;
+pub mod params;
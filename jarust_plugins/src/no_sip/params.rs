@@ -0,0 +1,86 @@
+use serde::Deserialize;
+use serde::Serialize;
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_nosip.c#L60-L230
+make_dto!(
+    NoSipGenerateParams,
+    optional {
+        /// opaque string that the user can provide for context, returned in the event
+        info: String,
+        /// whether to offer (sdes_optional) or mandate (sdes_mandatory) SRTP
+        srtp: NoSipSrtp,
+        /// SRTP profile to negotiate, in case SRTP is offered
+        srtp_profile: String,
+        /// whether this is a renegotiation of an existing session, default=false
+        update: bool
+    }
+);
+
+make_dto!(
+    NoSipProcessParams,
+    required {
+        /// whether the barebone SDP is an offer or an answer
+        #[serde(rename = "type")]
+        sdp_type: NoSipSdpType,
+        /// barebone SDP to convert to WebRTC
+        sdp: String
+    },
+    optional {
+        /// opaque string that the user can provide for context, returned in the event
+        info: String,
+        /// whether the peer offers (sdes_optional) or mandates (sdes_mandatory) SRTP
+        srtp: NoSipSrtp,
+        /// whether this is a renegotiation of an existing session, default=false
+        update: bool
+    }
+);
+
+make_dto!(
+    NoSipRecordingParams,
+    required {
+        action: NoSipRecordingAction
+    },
+    optional {
+        /// whether or not our audio should be recorded
+        audio: bool,
+        /// whether or not our video should be recorded
+        video: bool,
+        /// whether or not our peer's audio should be recorded
+        peer_audio: bool,
+        /// whether or not our peer's video should be recorded
+        peer_video: bool,
+        /// base name to use for the recordings
+        filename: String
+    }
+);
+
+make_dto!(
+    NoSipKeyframeParams,
+    optional {
+        /// whether we should send a PLI to the WebRTC user
+        user: bool,
+        /// whether we should send a PLI to the SIP peer
+        peer: bool
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoSipSdpType {
+    Offer,
+    Answer,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NoSipSrtp {
+    SdesOptional,
+    SdesMandatory,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoSipRecordingAction {
+    Start,
+    Stop,
+}
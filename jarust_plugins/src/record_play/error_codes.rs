@@ -0,0 +1,16 @@
+jarust_interface::error_codes! {
+    /// RecordPlay plugin error codes
+    RecordPlayErrorCode {
+        NoMessage = 411,
+        InvalidJson = 412,
+        InvalidRequest = 413,
+        InvalidElement = 414,
+        MissingElement = 415,
+        NotFound = 416,
+        InvalidRecording = 417,
+        InvalidState = 418,
+        InvalidSdp = 419,
+        RecordingExists = 420,
+        UnknownError = 499,
+    }
+}
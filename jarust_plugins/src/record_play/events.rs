@@ -0,0 +1,210 @@
+use jarust_interface::japrotocol::GenericEvent;
+use jarust_interface::japrotocol::JaHandleEvent;
+use jarust_interface::japrotocol::JaResponse;
+use jarust_interface::japrotocol::Jsep;
+use jarust_interface::japrotocol::PluginInnerData;
+use jarust_interface::japrotocol::ResponseType;
+use serde::Deserialize;
+use serde_json::from_value;
+use serde_json::Value;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "recordplay")]
+enum RecordPlayEventDto {
+    #[serde(rename = "event")]
+    Event { result: RecordPlayEventResult },
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "status", rename_all = "lowercase")]
+enum RecordPlayEventResult {
+    Recording { id: u64 },
+    Preparing { id: u64 },
+    Playing,
+    Stopped { id: Option<u64> },
+    Done,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PluginEvent {
+    RecordPlayEvent(RecordPlayEvent),
+    GenericEvent(GenericEvent),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum RecordPlayEvent {
+    /// Sent back after a successful [record](super::handle::RecordPlayHandle::record) request
+    Recording {
+        id: u64,
+    },
+    /// Sent back after a successful [record](super::handle::RecordPlayHandle::record) request with the JSEP SDP answer
+    RecordingWithJsep {
+        id: u64,
+        jsep: Jsep,
+    },
+    /// Sent back after a successful [play](super::handle::RecordPlayHandle::play) request
+    Preparing {
+        id: u64,
+    },
+    /// Sent back after a successful [play](super::handle::RecordPlayHandle::play) request with the JSEP SDP offer to answer
+    PreparingWithJsep {
+        id: u64,
+        jsep: Jsep,
+    },
+    /// Sent back after a successful [start](super::handle::RecordPlayHandle::start) request
+    Playing,
+    /// Sent back after a successful [stop](super::handle::RecordPlayHandle::stop) request
+    Stopped {
+        id: Option<u64>,
+    },
+    /// The playout reached the end of the recording
+    Done,
+    Error {
+        error_code: u16,
+        error: String,
+    },
+    Other(Value),
+}
+
+impl TryFrom<JaResponse> for PluginEvent {
+    type Error = jarust_interface::Error;
+
+    fn try_from(value: JaResponse) -> Result<Self, Self::Error> {
+        use RecordPlayEventResult as Event;
+
+        match value.janus {
+            ResponseType::Event(JaHandleEvent::PluginEvent { plugin_data }) => {
+                let recordplay_event = match plugin_data.data {
+                    PluginInnerData::Error { error_code, error } => {
+                        RecordPlayEvent::Error { error_code, error }
+                    }
+                    PluginInnerData::Data(data) => {
+                        match from_value::<RecordPlayEventDto>(data.clone()) {
+                            Ok(RecordPlayEventDto::Event { result }) => match result {
+                                Event::Recording { id } => match value.jsep {
+                                    Some(jsep) => RecordPlayEvent::RecordingWithJsep { id, jsep },
+                                    None => RecordPlayEvent::Recording { id },
+                                },
+                                Event::Preparing { id } => match value.jsep {
+                                    Some(jsep) => RecordPlayEvent::PreparingWithJsep { id, jsep },
+                                    None => RecordPlayEvent::Preparing { id },
+                                },
+                                Event::Playing => RecordPlayEvent::Playing,
+                                Event::Stopped { id } => RecordPlayEvent::Stopped { id },
+                                Event::Done => RecordPlayEvent::Done,
+                            },
+                            Err(_) => RecordPlayEvent::Other(data),
+                        }
+                    }
+                };
+                Ok(PluginEvent::RecordPlayEvent(recordplay_event))
+            }
+            ResponseType::Event(JaHandleEvent::GenericEvent(event)) => {
+                Ok(PluginEvent::GenericEvent(event))
+            }
+            _ => Err(Self::Error::IncompletePacket),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginEvent;
+    use crate::record_play::events::RecordPlayEvent;
+    use jarust_interface::japrotocol::JaHandleEvent;
+    use jarust_interface::japrotocol::JaResponse;
+    use jarust_interface::japrotocol::Jsep;
+    use jarust_interface::japrotocol::JsepType;
+    use jarust_interface::japrotocol::PluginData;
+    use jarust_interface::japrotocol::PluginInnerData;
+    use jarust_interface::japrotocol::ResponseType;
+    use serde_json::json;
+
+    #[test]
+    fn it_parse_recording_with_jsep() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.recordplay".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "recordplay": "event",
+                        "result": {
+                            "status": "recording",
+                            "id": 1234
+                        }
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Answer,
+                trickle: None,
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::RecordPlayEvent(RecordPlayEvent::RecordingWithJsep {
+                id: 1234,
+                jsep: Jsep {
+                    jsep_type: JsepType::Answer,
+                    trickle: None,
+                    sdp: "test_sdp".to_string(),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_stopped() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.recordplay".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "recordplay": "event",
+                        "result": {
+                            "status": "stopped",
+                            "id": 1234
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::RecordPlayEvent(RecordPlayEvent::Stopped { id: Some(1234) })
+        );
+    }
+
+    #[test]
+    fn it_parse_done() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.recordplay".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "recordplay": "event",
+                        "result": {
+                            "status": "done"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(event, PluginEvent::RecordPlayEvent(RecordPlayEvent::Done));
+    }
+}
@@ -0,0 +1,131 @@
+use super::params::*;
+use super::responses::*;
+use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
+use jarust_rt::JaTask;
+use serde_json::json;
+use serde_json::Value;
+use std::ops::Deref;
+use std::time::Duration;
+
+pub struct RecordPlayHandle {
+    handle: JaHandle,
+    task: Option<JaTask>,
+}
+
+// sync
+impl RecordPlayHandle {
+    /// Lists all the available recordings
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list(&self, timeout: Duration) -> Result<Vec<Recording>, jarust_interface::Error> {
+        tracing::info!(plugin = "recordplay", "Sending list");
+        let response = self
+            .handle
+            .send_waiton_rsp::<RecordPlayListRsp>(json!({"request": "list"}), timeout)
+            .await?;
+        Ok(response.list)
+    }
+
+    /// Force the plugin to rescan the recordings folder, e.g: after recordings were added externally
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn update(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "recordplay", "Sending update");
+        self.handle
+            .send_waiton_rsp::<RecordPlayOkRsp>(json!({"request": "update"}), timeout)
+            .await?;
+        Ok(())
+    }
+}
+
+// async
+impl RecordPlayHandle {
+    /// Start a new recording, this request MUST be accompanied by a JSEP SDP offer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn record(
+        &self,
+        params: RecordPlayRecordParams,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "recordplay", "Sending record");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "record".into();
+        self.handle
+            .send_waiton_ack_with_jsep(message, jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Prepare the playout of a recording
+    ///
+    /// Janus will answer with a [`RecordPlayEvent::PreparingWithJsep`](super::events::RecordPlayEvent::PreparingWithJsep)
+    /// event carrying the JSEP SDP offer to be answered with [`RecordPlayHandle::start`].
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn play(
+        &self,
+        params: RecordPlayPlayParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "recordplay", "Sending play");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "play".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Start the playout, this request MUST be accompanied by the JSEP SDP answer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn start(
+        &self,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "recordplay", "Sending start");
+        self.handle
+            .send_waiton_ack_with_jsep(json!({"request": "start"}), jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Stop the current recording or playout
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn stop(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "recordplay", "Sending stop");
+        self.handle
+            .send_waiton_ack(json!({"request": "stop"}), timeout)
+            .await?;
+        Ok(())
+    }
+}
+
+impl PluginTask for RecordPlayHandle {
+    fn assign_task(&mut self, task: JaTask) {
+        self.task = Some(task);
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        };
+    }
+}
+
+impl From<JaHandle> for RecordPlayHandle {
+    fn from(handle: JaHandle) -> Self {
+        Self { handle, task: None }
+    }
+}
+
+impl Deref for RecordPlayHandle {
+    type Target = JaHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Drop for RecordPlayHandle {
+    fn drop(&mut self) {
+        self.cancel_task();
+    }
+}
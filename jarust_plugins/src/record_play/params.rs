@@ -0,0 +1,36 @@
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_recordplay.c#L35-L260
+make_dto!(
+    RecordPlayRecordParams,
+    required {
+        /// pretty name for the recording
+        name: String
+    },
+    optional {
+        /// unique numeric ID for the recording, chosen by the plugin if missing
+        id: u64,
+        /// base path/name for the file (media type and extension added by the plugin), if missing a default is used
+        filename: String,
+        /// codec to force for the audio, if supported by the browser
+        audiocodec: String,
+        /// codec to force for the video, if supported by the browser
+        videocodec: String,
+        /// profile to force for the video (H.264 and VP9 only)
+        videoprofile: String,
+        /// whether RED should be negotiated for audio, default=false
+        opusred: bool,
+        /// whether data channel messages are text, default=true
+        textdata: bool
+    }
+);
+
+make_dto!(
+    RecordPlayPlayParams,
+    required {
+        /// unique numeric ID of the recording to replay
+        id: u64
+    },
+    optional {
+        /// whether to restart the playback of the recording (e.g: after a renegotiation), default=false
+        restart: bool
+    }
+);
@@ -0,0 +1,28 @@
+use serde::Deserialize;
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_recordplay.c#L83-L110
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct RecordPlayListRsp {
+    pub list: Vec<Recording>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct Recording {
+    pub id: u64,
+    pub name: String,
+    pub date: String,
+    /// audio recording file, if any
+    pub audio: Option<String>,
+    /// video recording file, if any
+    pub video: Option<String>,
+    /// data recording file, if any
+    pub data: Option<String>,
+    pub audio_codec: Option<String>,
+    pub video_codec: Option<String>,
+}
+
+/// Plain `{"recordplay": "ok"}` response
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct RecordPlayOkRsp {
+    pub recordplay: String,
+}
@@ -0,0 +1,22 @@
+jarust_interface::error_codes! {
+    /// SIP plugin error codes
+    SipErrorCode {
+        NoMessage = 440,
+        InvalidJson = 441,
+        InvalidRequest = 442,
+        MissingElement = 443,
+        InvalidElement = 444,
+        AlreadyRegistered = 445,
+        InvalidAddress = 446,
+        WrongState = 447,
+        MissingSdp = 448,
+        LibsofiaError = 449,
+        IoError = 450,
+        RecordingError = 451,
+        TooStrict = 452,
+        HelperError = 453,
+        NoSuchCallId = 454,
+        ReferError = 455,
+        UnknownError = 499,
+    }
+}
@@ -0,0 +1,581 @@
+use jarust_interface::japrotocol::GenericEvent;
+use jarust_interface::japrotocol::JaHandleEvent;
+use jarust_interface::japrotocol::JaResponse;
+use jarust_interface::japrotocol::Jsep;
+use jarust_interface::japrotocol::PluginInnerData;
+use jarust_interface::japrotocol::ResponseType;
+use serde::Deserialize;
+use serde_json::from_value;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "sip")]
+enum SipEventDto {
+    #[serde(rename = "event")]
+    Event {
+        call_id: Option<String>,
+        result: SipEventResult,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum SipEventResult {
+    Registered {
+        username: String,
+        #[serde(default)]
+        register_sent: bool,
+        master_id: Option<u64>,
+    },
+    #[serde(rename = "registration_failed")]
+    RegistrationFailed {
+        code: u16,
+        reason: String,
+    },
+    Unregistered {
+        username: String,
+        #[serde(default)]
+        register_sent: bool,
+    },
+    Calling,
+    Ringing,
+    Proceeding {
+        code: u16,
+    },
+    Progress {
+        username: String,
+    },
+    IncomingCall {
+        username: String,
+        displayname: Option<String>,
+        callee: Option<String>,
+        referred_by: Option<String>,
+        replaces: Option<String>,
+        srtp: Option<String>,
+        headers: Option<BTreeMap<String, String>>,
+    },
+    Accepted {
+        username: Option<String>,
+    },
+    Hangup {
+        code: u16,
+        reason: String,
+    },
+    Transfer {
+        refer_id: u64,
+        refer_to: String,
+        referred_by: Option<String>,
+        replaces: Option<String>,
+    },
+    MessageDelivery {
+        code: u16,
+        reason: String,
+    },
+    Message {
+        sender: String,
+        displayname: Option<String>,
+        content_type: Option<String>,
+        content: String,
+    },
+    Info {
+        sender: String,
+        displayname: Option<String>,
+        #[serde(rename = "type")]
+        info_type: String,
+        content: String,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PluginEvent {
+    SipEvent(SipEvent),
+    GenericEvent(GenericEvent),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum SipEvent {
+    /// Sent back after a successful [register](super::handle::SipHandle::register) request
+    Registered {
+        username: String,
+        /// whether a REGISTER was actually sent (false for guests)
+        register_sent: bool,
+        /// handle id of the master registration, to be used when registering helper lines
+        master_id: Option<u64>,
+    },
+    RegistrationFailed {
+        code: u16,
+        reason: String,
+    },
+    Unregistered {
+        username: String,
+        register_sent: bool,
+    },
+    /// Sent back after a successful [call](super::handle::SipHandle::call) request
+    Calling {
+        call_id: Option<String>,
+    },
+    Ringing {
+        call_id: Option<String>,
+    },
+    Proceeding {
+        call_id: Option<String>,
+        code: u16,
+    },
+    /// Early media
+    Progress {
+        call_id: Option<String>,
+        username: String,
+    },
+    /// Early media with the JSEP SDP answer
+    ProgressWithJsep {
+        call_id: Option<String>,
+        username: String,
+        jsep: Jsep,
+    },
+    /// Incoming call without SDP, the [accept](super::handle::SipHandle::accept) request must carry an offer
+    IncomingCall {
+        call_id: Option<String>,
+        /// SIP URI of the caller
+        username: String,
+        display_name: Option<String>,
+        /// SIP URI that was called
+        callee: Option<String>,
+        referred_by: Option<String>,
+        replaces: Option<String>,
+        /// <sdes_optional|sdes_mandatory>
+        srtp: Option<String>,
+        headers: Option<BTreeMap<String, String>>,
+    },
+    /// Incoming call with the JSEP SDP offer to answer
+    IncomingCallWithJsep {
+        call_id: Option<String>,
+        /// SIP URI of the caller
+        username: String,
+        display_name: Option<String>,
+        /// SIP URI that was called
+        callee: Option<String>,
+        referred_by: Option<String>,
+        replaces: Option<String>,
+        /// <sdes_optional|sdes_mandatory>
+        srtp: Option<String>,
+        headers: Option<BTreeMap<String, String>>,
+        jsep: Jsep,
+    },
+    Accepted {
+        call_id: Option<String>,
+        username: Option<String>,
+    },
+    /// Sent when an outgoing call is accepted, with the JSEP SDP answer
+    AcceptedWithJsep {
+        call_id: Option<String>,
+        username: Option<String>,
+        jsep: Jsep,
+    },
+    Hangup {
+        call_id: Option<String>,
+        code: u16,
+        reason: String,
+    },
+    /// The peer asked us to transfer the call, use the `refer_id` when placing the new call
+    Transfer {
+        call_id: Option<String>,
+        refer_id: u64,
+        refer_to: String,
+        referred_by: Option<String>,
+        replaces: Option<String>,
+    },
+    /// Delivery outcome of a [message](super::handle::SipHandle::message) request
+    MessageDelivery {
+        call_id: Option<String>,
+        code: u16,
+        reason: String,
+    },
+    /// Incoming SIP MESSAGE
+    Message {
+        call_id: Option<String>,
+        sender: String,
+        display_name: Option<String>,
+        content_type: Option<String>,
+        content: String,
+    },
+    /// Incoming SIP INFO
+    Info {
+        call_id: Option<String>,
+        sender: String,
+        display_name: Option<String>,
+        info_type: String,
+        content: String,
+    },
+    Error {
+        error_code: u16,
+        error: String,
+    },
+    Other(Value),
+}
+
+impl TryFrom<JaResponse> for PluginEvent {
+    type Error = jarust_interface::Error;
+
+    fn try_from(value: JaResponse) -> Result<Self, Self::Error> {
+        use SipEventResult as Event;
+
+        match value.janus {
+            ResponseType::Event(JaHandleEvent::PluginEvent { plugin_data }) => {
+                let sip_event = match plugin_data.data {
+                    PluginInnerData::Error { error_code, error } => {
+                        SipEvent::Error { error_code, error }
+                    }
+                    PluginInnerData::Data(data) => match from_value::<SipEventDto>(data.clone()) {
+                        Ok(SipEventDto::Event { call_id, result }) => match result {
+                            Event::Registered {
+                                username,
+                                register_sent,
+                                master_id,
+                            } => SipEvent::Registered {
+                                username,
+                                register_sent,
+                                master_id,
+                            },
+                            Event::RegistrationFailed { code, reason } => {
+                                SipEvent::RegistrationFailed { code, reason }
+                            }
+                            Event::Unregistered {
+                                username,
+                                register_sent,
+                            } => SipEvent::Unregistered {
+                                username,
+                                register_sent,
+                            },
+                            Event::Calling => SipEvent::Calling { call_id },
+                            Event::Ringing => SipEvent::Ringing { call_id },
+                            Event::Proceeding { code } => SipEvent::Proceeding { call_id, code },
+                            Event::Progress { username } => match value.jsep {
+                                Some(jsep) => SipEvent::ProgressWithJsep {
+                                    call_id,
+                                    username,
+                                    jsep,
+                                },
+                                None => SipEvent::Progress { call_id, username },
+                            },
+                            Event::IncomingCall {
+                                username,
+                                displayname,
+                                callee,
+                                referred_by,
+                                replaces,
+                                srtp,
+                                headers,
+                            } => match value.jsep {
+                                Some(jsep) => SipEvent::IncomingCallWithJsep {
+                                    call_id,
+                                    username,
+                                    display_name: displayname,
+                                    callee,
+                                    referred_by,
+                                    replaces,
+                                    srtp,
+                                    headers,
+                                    jsep,
+                                },
+                                None => SipEvent::IncomingCall {
+                                    call_id,
+                                    username,
+                                    display_name: displayname,
+                                    callee,
+                                    referred_by,
+                                    replaces,
+                                    srtp,
+                                    headers,
+                                },
+                            },
+                            Event::Accepted { username } => match value.jsep {
+                                Some(jsep) => SipEvent::AcceptedWithJsep {
+                                    call_id,
+                                    username,
+                                    jsep,
+                                },
+                                None => SipEvent::Accepted { call_id, username },
+                            },
+                            Event::Hangup { code, reason } => SipEvent::Hangup {
+                                call_id,
+                                code,
+                                reason,
+                            },
+                            Event::Transfer {
+                                refer_id,
+                                refer_to,
+                                referred_by,
+                                replaces,
+                            } => SipEvent::Transfer {
+                                call_id,
+                                refer_id,
+                                refer_to,
+                                referred_by,
+                                replaces,
+                            },
+                            Event::MessageDelivery { code, reason } => SipEvent::MessageDelivery {
+                                call_id,
+                                code,
+                                reason,
+                            },
+                            Event::Message {
+                                sender,
+                                displayname,
+                                content_type,
+                                content,
+                            } => SipEvent::Message {
+                                call_id,
+                                sender,
+                                display_name: displayname,
+                                content_type,
+                                content,
+                            },
+                            Event::Info {
+                                sender,
+                                displayname,
+                                info_type,
+                                content,
+                            } => SipEvent::Info {
+                                call_id,
+                                sender,
+                                display_name: displayname,
+                                info_type,
+                                content,
+                            },
+                        },
+                        Err(_) => SipEvent::Other(data),
+                    },
+                };
+                Ok(PluginEvent::SipEvent(sip_event))
+            }
+            ResponseType::Event(JaHandleEvent::GenericEvent(event)) => {
+                Ok(PluginEvent::GenericEvent(event))
+            }
+            _ => Err(Self::Error::IncompletePacket),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginEvent;
+    use crate::sip::events::SipEvent;
+    use jarust_interface::japrotocol::JaHandleEvent;
+    use jarust_interface::japrotocol::JaResponse;
+    use jarust_interface::japrotocol::Jsep;
+    use jarust_interface::japrotocol::JsepType;
+    use jarust_interface::japrotocol::PluginData;
+    use jarust_interface::japrotocol::PluginInnerData;
+    use jarust_interface::japrotocol::ResponseType;
+    use serde_json::json;
+
+    #[test]
+    fn it_parse_registered() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.sip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "sip": "event",
+                        "result": {
+                            "event": "registered",
+                            "username": "sip:alice@example.com",
+                            "register_sent": true,
+                            "master_id": 1234
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::SipEvent(SipEvent::Registered {
+                username: "sip:alice@example.com".to_string(),
+                register_sent: true,
+                master_id: Some(1234),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_registration_failed() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.sip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "sip": "event",
+                        "result": {
+                            "event": "registration_failed",
+                            "code": 401,
+                            "reason": "Unauthorized"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::SipEvent(SipEvent::RegistrationFailed {
+                code: 401,
+                reason: "Unauthorized".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_incoming_call_with_jsep() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.sip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "sip": "event",
+                        "call_id": "abc123",
+                        "result": {
+                            "event": "incomingcall",
+                            "username": "sip:bob@example.com",
+                            "displayname": "Bob",
+                            "callee": "sip:alice@example.com"
+                        }
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Offer,
+                trickle: None,
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::SipEvent(SipEvent::IncomingCallWithJsep {
+                call_id: Some("abc123".to_string()),
+                username: "sip:bob@example.com".to_string(),
+                display_name: Some("Bob".to_string()),
+                callee: Some("sip:alice@example.com".to_string()),
+                referred_by: None,
+                replaces: None,
+                srtp: None,
+                headers: None,
+                jsep: Jsep {
+                    jsep_type: JsepType::Offer,
+                    trickle: None,
+                    sdp: "test_sdp".to_string(),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_ringing() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.sip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "sip": "event",
+                        "call_id": "abc123",
+                        "result": {
+                            "event": "ringing"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::SipEvent(SipEvent::Ringing {
+                call_id: Some("abc123".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_hangup() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.sip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "sip": "event",
+                        "call_id": "abc123",
+                        "result": {
+                            "event": "hangup",
+                            "code": 200,
+                            "reason": "Session Terminated"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::SipEvent(SipEvent::Hangup {
+                call_id: Some("abc123".to_string()),
+                code: 200,
+                reason: "Session Terminated".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_transfer() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.sip".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "sip": "event",
+                        "call_id": "abc123",
+                        "result": {
+                            "event": "transfer",
+                            "refer_id": 987,
+                            "refer_to": "sip:carol@example.com"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::SipEvent(SipEvent::Transfer {
+                call_id: Some("abc123".to_string()),
+                refer_id: 987,
+                refer_to: "sip:carol@example.com".to_string(),
+                referred_by: None,
+                replaces: None,
+            })
+        );
+    }
+}
@@ -0,0 +1,242 @@
+use super::params::*;
+use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
+use jarust_rt::JaTask;
+use serde_json::json;
+use serde_json::Value;
+use std::ops::Deref;
+use std::time::Duration;
+
+pub struct SipHandle {
+    handle: JaHandle,
+    task: Option<JaTask>,
+}
+
+// async, the outcome of every request is notified with an event
+impl SipHandle {
+    /// Register to a SIP server, or set up a guest or helper line
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn register(
+        &self,
+        params: SipRegisterParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending register");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "register".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Unregister from the SIP server
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn unregister(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending unregister");
+        self.handle
+            .send_waiton_ack(json!({"request": "unregister"}), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Place a call, this request MUST be accompanied by a JSEP SDP offer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn call(
+        &self,
+        params: SipCallParams,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending call");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "call".into();
+        self.handle
+            .send_waiton_ack_with_jsep(message, jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Accept an incoming call with the JSEP SDP answer (or offer, if the incoming call had no SDP)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn accept(
+        &self,
+        params: SipAcceptParams,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending accept");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "accept".into();
+        self.handle
+            .send_waiton_ack_with_jsep(message, jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Decline an incoming call
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn decline(
+        &self,
+        params: SipDeclineParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending decline");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "decline".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Hang up the current call
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn hangup(
+        &self,
+        params: SipHangupParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending hangup");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "hangup".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Put the current call on hold
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn hold(
+        &self,
+        params: SipHoldParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending hold");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "hold".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Resume a call previously put on hold
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn unhold(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending unhold");
+        self.handle
+            .send_waiton_ack(json!({"request": "unhold"}), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Renegotiate the current call (e.g: ICE restart), this request MUST be accompanied by a JSEP SDP offer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn update(
+        &self,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending update");
+        self.handle
+            .send_waiton_ack_with_jsep(json!({"request": "update"}), jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Transfer the current call, blind or attended, to another SIP URI
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn transfer(
+        &self,
+        params: SipTransferParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending transfer");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "transfer".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Send a DTMF digit via SIP INFO
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn dtmf_info(
+        &self,
+        params: SipDtmfInfoParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending dtmf_info");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "dtmf_info".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Send a SIP MESSAGE, within a call or to a SIP URI
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn message(
+        &self,
+        params: SipMessageParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending message");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "message".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Send a SIP INFO within the current call
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn info(
+        &self,
+        params: SipInfoParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending info");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "info".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Start or stop recording the current call
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn recording(
+        &self,
+        params: SipRecordingParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "sip", "Sending recording");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "recording".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+}
+
+impl PluginTask for SipHandle {
+    fn assign_task(&mut self, task: JaTask) {
+        self.task = Some(task);
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        };
+    }
+}
+
+impl From<JaHandle> for SipHandle {
+    fn from(handle: JaHandle) -> Self {
+        Self { handle, task: None }
+    }
+}
+
+impl Deref for SipHandle {
+    type Target = JaHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Drop for SipHandle {
+    fn drop(&mut self) {
+        self.cancel_task();
+    }
+}
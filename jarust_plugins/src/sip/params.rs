@@ -0,0 +1,229 @@
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_sip.c#L69-L640
+make_dto!(
+    SipRegisterParams,
+    required {
+        /// SIP URI to register, e.g: `sip:alice@example.com`
+        username: String
+    },
+    optional {
+        /// <guest|helper>, leave empty for a regular registration
+        #[serde(rename = "type")]
+        register_type: SipRegisterType,
+        /// whether the REGISTER should actually be sent, default=true
+        send_register: bool,
+        /// whether UDP should be forced for the SIP messaging, default=false
+        force_udp: bool,
+        /// whether TCP should be forced for the SIP messaging, default=false
+        force_tcp: bool,
+        /// whether SIPS URIs should be used, default=false
+        sips: bool,
+        /// whether a CANCEL should be sent as per RFC 2543 (only for INVITEs not answered yet), default=false
+        rfc2543_cancel: bool,
+        /// password to use to register
+        secret: String,
+        /// prehashed password to use to register
+        ha1_secret: String,
+        /// username to use to authenticate, if different from the one in the SIP URI
+        authuser: String,
+        /// display name to use when sending SIP requests
+        display_name: String,
+        /// user agent to use when sending SIP requests
+        user_agent: String,
+        /// server to register at, e.g: `sip:10.0.0.1:5060`, not needed for guests
+        proxy: String,
+        /// outbound proxy to use, if any
+        outbound_proxy: String,
+        /// custom headers to add to the SIP REGISTER
+        headers: BTreeMap<String, String>,
+        /// prefixes of the custom headers to extract from incoming SIP requests
+        incoming_header_prefixes: Vec<String>,
+        /// whether this is just an update of an existing registration, default=false
+        refresh: bool,
+        /// handle id of the master registration, when registering a helper line
+        master_id: u64,
+        /// number of seconds after which the registration should expire
+        register_ttl: u64
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SipRegisterType {
+    /// No REGISTER is sent, the line can only be used to place calls
+    Guest,
+    /// Additional line of a master registration, used to handle more than one call at a time
+    Helper,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SipSrtp {
+    SdesOptional,
+    SdesMandatory,
+}
+
+make_dto!(
+    SipCallParams,
+    required {
+        /// SIP URI to call
+        uri: String
+    },
+    optional {
+        /// user-defined value of Call-ID SIP header used in all SIP requests throughout the call
+        call_id: String,
+        /// REFER id, in case this call was triggered by a transfer
+        refer_id: u64,
+        /// custom headers to add to the SIP INVITE
+        headers: BTreeMap<String, String>,
+        /// whether to offer (sdes_optional) or mandate (sdes_mandatory) SRTP
+        srtp: SipSrtp,
+        /// SRTP profile to negotiate, in case SRTP is offered
+        srtp_profile: String,
+        /// whether re-INVITEs should be automatically accepted or notified, default=true
+        autoaccept_reinvites: bool,
+        /// password to use for the call, if the user is a guest or if a different one is needed
+        secret: String,
+        /// prehashed password to use for the call
+        ha1_secret: String,
+        /// username to use to authenticate the call
+        authuser: String
+    }
+);
+
+make_dto!(
+    SipAcceptParams,
+    optional {
+        /// whether to mandate (sdes_mandatory) or just accept (sdes_optional) SRTP
+        srtp: SipSrtp,
+        /// custom headers to add to the SIP 200 OK
+        headers: BTreeMap<String, String>,
+        /// whether re-INVITEs should be automatically accepted or notified, default=true
+        autoaccept_reinvites: bool
+    }
+);
+
+make_dto!(
+    SipDeclineParams,
+    optional {
+        /// SIP response code to send, default=486 (Busy Here)
+        code: u16,
+        /// custom headers to add to the SIP response
+        headers: BTreeMap<String, String>,
+        /// REFER id, in case we're declining a transfer
+        refer_id: u64
+    }
+);
+
+make_dto!(
+    SipHangupParams,
+    optional {
+        /// custom headers to add to the SIP BYE
+        headers: BTreeMap<String, String>
+    }
+);
+
+make_dto!(
+    SipHoldParams,
+    optional {
+        /// direction to put the call on hold with, default=sendonly
+        direction: SipHoldDirection
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SipHoldDirection {
+    Sendonly,
+    Recvonly,
+    Inactive,
+}
+
+make_dto!(
+    SipTransferParams,
+    required {
+        /// SIP URI to send the transferee to
+        uri: String
+    },
+    optional {
+        /// call-ID of the call this attended transfer is supposed to replace
+        replace: String
+    }
+);
+
+make_dto!(
+    SipDtmfInfoParams,
+    required {
+        /// DTMF digit to send, e.g: `"1"`, `"#"`
+        digit: String
+    },
+    optional {
+        /// duration of the digit in ms, default=100
+        duration: u64
+    }
+);
+
+make_dto!(
+    SipMessageParams,
+    required {
+        /// text to send
+        content: String
+    },
+    optional {
+        /// content type of the message, default=text/plain
+        content_type: String,
+        /// SIP URI of the peer, mandatory outside of a call
+        uri: String,
+        /// user-defined value of Call-ID SIP header used to send the message
+        call_id: String,
+        /// custom headers to add to the SIP MESSAGE
+        headers: BTreeMap<String, String>
+    }
+);
+
+make_dto!(
+    SipInfoParams,
+    required {
+        /// content type of the INFO
+        #[serde(rename = "type")]
+        info_type: String,
+        /// body of the INFO
+        content: String
+    },
+    optional {
+        /// custom headers to add to the SIP INFO
+        headers: BTreeMap<String, String>
+    }
+);
+
+make_dto!(
+    SipRecordingParams,
+    required {
+        action: SipRecordingAction
+    },
+    optional {
+        /// whether or not our audio should be recorded
+        audio: bool,
+        /// whether or not our video should be recorded
+        video: bool,
+        /// whether or not our peer's audio should be recorded
+        peer_audio: bool,
+        /// whether or not our peer's video should be recorded
+        peer_video: bool,
+        /// whether or not to send a PLI to request a keyframe from the peer, default=true
+        send_peer_pli: bool,
+        /// whether or not to send a PLI to request a keyframe from the user, default=true
+        send_pli: bool,
+        /// base name to use for the recordings
+        filename: String
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum SipRecordingAction {
+    Start,
+    Stop,
+}
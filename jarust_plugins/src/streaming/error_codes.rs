@@ -0,0 +1,17 @@
+jarust_interface::error_codes! {
+    /// Streaming plugin error codes
+    StreamingErrorCode {
+        NoMessage = 450,
+        InvalidJson = 451,
+        InvalidRequest = 452,
+        MissingElement = 453,
+        InvalidElement = 454,
+        NoSuchMountpoint = 455,
+        CantCreate = 456,
+        Unauthorized = 457,
+        CantSwitch = 458,
+        CantRecord = 459,
+        InvalidState = 460,
+        UnknownError = 470,
+    }
+}
@@ -2,6 +2,7 @@ use crate::JanusId;
 use jarust_core::prelude::JaResponse;
 use jarust_interface::japrotocol::GenericEvent;
 use jarust_interface::japrotocol::JaHandleEvent;
+use jarust_interface::japrotocol::Jsep;
 use jarust_interface::japrotocol::PluginInnerData;
 use jarust_interface::japrotocol::ResponseType;
 use serde::Deserialize;
@@ -21,6 +22,29 @@ enum StreamingEventDto {
         #[serde(rename = "type")]
         mountpoint_type: String,
     },
+
+    #[serde(rename = "event")]
+    Event { result: StreamingEventResult },
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(untagged)]
+enum StreamingEventResult {
+    Status { status: StreamingStatus },
+    Switched { switched: String, id: JanusId },
+    Configured { event: String },
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum StreamingStatus {
+    Preparing,
+    Starting,
+    Started,
+    Pausing,
+    Updating,
+    Stopping,
+    Stopped,
 }
 
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
@@ -38,6 +62,35 @@ pub enum StreamingEvent {
         id: JanusId,
         mountpoint_type: String,
     },
+    /// Sent back after a successful [watch](super::handle::StreamingHandle::watch) request
+    Preparing,
+    /// Sent back after a successful [watch](super::handle::StreamingHandle::watch) request with the JSEP SDP offer to answer
+    PreparingWithJsep {
+        jsep: Jsep,
+    },
+    /// Sent back after a successful [start](super::handle::StreamingHandle::start) request
+    Starting,
+    /// Sent when the media starts flowing to the subscriber
+    Started,
+    /// Sent back after a successful [pause](super::handle::StreamingHandle::pause) request
+    Paused,
+    /// Sent back after a successful [switch](super::handle::StreamingHandle::switch) request
+    Switched {
+        /// unique ID of the new mountpoint
+        id: JanusId,
+    },
+    /// Sent back after a successful [configure](super::handle::StreamingHandle::configure) request
+    Configured,
+    /// Sent when the session is being renegotiated (e.g: ICE restart)
+    Updated,
+    /// Sent when the session is being renegotiated with the new JSEP SDP offer to answer
+    UpdatedWithJsep {
+        jsep: Jsep,
+    },
+    /// Sent back after a successful [stop](super::handle::StreamingHandle::stop) request
+    Stopping,
+    /// Sent when the subscription is over, e.g: the mountpoint was destroyed or the PeerConnection is gone
+    Stopped,
     Error {
         error_code: u16,
         error: String,
@@ -49,6 +102,9 @@ impl TryFrom<JaResponse> for PluginEvent {
     type Error = jarust_interface::Error;
 
     fn try_from(value: JaResponse) -> Result<Self, Self::Error> {
+        use StreamingEventResult as EventResult;
+        use StreamingStatus as Status;
+
         match value.janus {
             ResponseType::Event(JaHandleEvent::PluginEvent { plugin_data }) => {
                 let streaming_event = match plugin_data.data {
@@ -67,6 +123,25 @@ impl TryFrom<JaResponse> for PluginEvent {
                             Ok(StreamingEventDto::DestroyMountpoint { id }) => {
                                 StreamingEvent::MountpointDestroyed { id }
                             }
+                            Ok(StreamingEventDto::Event { result }) => match result {
+                                EventResult::Status { status } => match (status, value.jsep) {
+                                    (Status::Preparing, Some(jsep)) => {
+                                        StreamingEvent::PreparingWithJsep { jsep }
+                                    }
+                                    (Status::Preparing, None) => StreamingEvent::Preparing,
+                                    (Status::Starting, _) => StreamingEvent::Starting,
+                                    (Status::Started, _) => StreamingEvent::Started,
+                                    (Status::Pausing, _) => StreamingEvent::Paused,
+                                    (Status::Updating, Some(jsep)) => {
+                                        StreamingEvent::UpdatedWithJsep { jsep }
+                                    }
+                                    (Status::Updating, None) => StreamingEvent::Updated,
+                                    (Status::Stopping, _) => StreamingEvent::Stopping,
+                                    (Status::Stopped, _) => StreamingEvent::Stopped,
+                                },
+                                EventResult::Switched { id, .. } => StreamingEvent::Switched { id },
+                                EventResult::Configured { .. } => StreamingEvent::Configured,
+                            },
                             Err(_) => StreamingEvent::Other(data),
                         }
                     }
@@ -89,6 +164,8 @@ mod tests {
     use crate::JanusId;
     use jarust_interface::japrotocol::JaHandleEvent;
     use jarust_interface::japrotocol::JaResponse;
+    use jarust_interface::japrotocol::Jsep;
+    use jarust_interface::japrotocol::JsepType;
     use jarust_interface::japrotocol::PluginData;
     use jarust_interface::japrotocol::PluginInnerData;
     use jarust_interface::japrotocol::ResponseType;
@@ -148,6 +225,130 @@ mod tests {
         );
     }
 
+    #[test]
+    fn it_parse_preparing_with_jsep() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.streaming".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "streaming": "event",
+                        "result": {
+                            "status": "preparing"
+                        }
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Offer,
+                trickle: None,
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::StreamingEvent(StreamingEvent::PreparingWithJsep {
+                jsep: Jsep {
+                    jsep_type: JsepType::Offer,
+                    trickle: None,
+                    sdp: "test_sdp".to_string(),
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_status_events() {
+        let cases = [
+            ("starting", StreamingEvent::Starting),
+            ("started", StreamingEvent::Started),
+            ("pausing", StreamingEvent::Paused),
+            ("updating", StreamingEvent::Updated),
+            ("stopping", StreamingEvent::Stopping),
+            ("stopped", StreamingEvent::Stopped),
+        ];
+        for (status, expected) in cases {
+            let rsp = JaResponse {
+                janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                    plugin_data: PluginData {
+                        plugin: "janus.plugin.streaming".to_string(),
+                        data: PluginInnerData::Data(json!({
+                            "streaming": "event",
+                            "result": {
+                                "status": status
+                            }
+                        })),
+                    },
+                }),
+                jsep: None,
+                transaction: None,
+                session_id: None,
+                sender: None,
+            };
+            let event: PluginEvent = rsp.try_into().unwrap();
+            assert_eq!(event, PluginEvent::StreamingEvent(expected));
+        }
+    }
+
+    #[test]
+    fn it_parse_switched() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.streaming".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "streaming": "event",
+                        "result": {
+                            "switched": "ok",
+                            "id": 63807u64
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::StreamingEvent(StreamingEvent::Switched {
+                id: JanusId::Uint(63807u64.into()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_configured() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.streaming".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "streaming": "event",
+                        "result": {
+                            "event": "configured"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::StreamingEvent(StreamingEvent::Configured)
+        );
+    }
+
     #[test]
     fn it_parse_error() {
         let rsp = JaResponse {
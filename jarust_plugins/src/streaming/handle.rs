@@ -2,6 +2,7 @@ use crate::streaming::params::*;
 use crate::streaming::responses::*;
 use crate::JanusId;
 use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
 use jarust_rt::JaTask;
 use serde_json::json;
 use serde_json::Value;
@@ -88,14 +89,180 @@ impl StreamingHandle {
         Ok(response.info)
     }
 
-    // TODO:
-    // edit
-    // enable
-    // disable
-    // recording
+    /// Edit the properties of an existing mountpoint
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn edit_mountpoint(
+        &self,
+        params: StreamingEditParams,
+        timeout: Duration,
+    ) -> Result<MountpointEditedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending edit");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "edit".into();
+
+        self.handle
+            .send_waiton_rsp::<MountpointEditedRsp>(message, timeout)
+            .await
+    }
+
+    /// Enable a previously disabled mountpoint
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn enable_mountpoint(
+        &self,
+        params: StreamingEnableParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending enable");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "enable".into();
+
+        self.handle
+            .send_waiton_rsp::<StreamingOkRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Disable a mountpoint, viewers are not kicked but stop receiving media
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn disable_mountpoint(
+        &self,
+        params: StreamingDisableParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending disable");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "disable".into();
+
+        self.handle
+            .send_waiton_rsp::<StreamingOkRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Start or stop recording the streams of a mountpoint, per mid
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn recording(
+        &self,
+        params: StreamingRecordingParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending recording");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "recording".into();
+
+        self.handle
+            .send_waiton_rsp::<StreamingOkRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Kick all the viewers of a mountpoint
+    ///
+    /// The streaming plugin doesn't expose viewer ids, so there is no way to kick a single viewer.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn kick_all(
+        &self,
+        params: StreamingKickAllParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending kick_all");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "kick_all".into();
+
+        self.handle
+            .send_waiton_rsp::<StreamingOkRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
 }
 
-// TODO: async methods
+//
+// asynchronous methods
+//
+impl StreamingHandle {
+    /// Subscribe to a mountpoint
+    ///
+    /// Janus will answer with a [`StreamingEvent::PreparingWithJsep`](super::events::StreamingEvent::PreparingWithJsep)
+    /// event carrying the JSEP SDP offer to be answered with [`StreamingHandle::start`].
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn watch(
+        &self,
+        params: StreamingWatchParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending watch");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "watch".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Start (or resume) receiving media from the mountpoint
+    ///
+    /// This request MUST be accompanied by the JSEP SDP answer to the offer received after [`StreamingHandle::watch`].
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn start(
+        &self,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending start");
+        self.handle
+            .send_waiton_ack_with_jsep(json!({"request": "start"}), jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Pause the media delivery, use [`StreamingHandle::start`] to resume it
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn pause(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending pause");
+        self.handle
+            .send_waiton_ack(json!({"request": "pause"}), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Switch to a different mountpoint without renegotiating the PeerConnection
+    ///
+    /// The new mountpoint must have the same media configuration as the current one.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn switch(
+        &self,
+        params: StreamingSwitchParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending switch");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "switch".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Tweak the media delivery of the subscription per mid, e.g: enable/disable a stream or pick a simulcast/SVC layer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn configure(
+        &self,
+        params: StreamingConfigureParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending configure");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "configure".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Stop the media delivery and tear down the PeerConnection
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn stop(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "streaming", "Sending stop");
+        self.handle
+            .send_waiton_ack(json!({"request": "stop"}), timeout)
+            .await?;
+        Ok(())
+    }
+}
 
 impl PluginTask for StreamingHandle {
     fn assign_task(&mut self, task: JaTask) {
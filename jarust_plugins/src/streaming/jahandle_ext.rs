@@ -13,9 +13,18 @@ pub trait Streaming: Attach {
     async fn attach_streaming(
         &self,
         timeout: Duration,
+    ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
+        self.attach_streaming_with_params(AttachParams::default(), timeout)
+            .await
+    }
+
+    async fn attach_streaming_with_params(
+        &self,
+        params: AttachParams,
+        timeout: Duration,
     ) -> Result<(Self::Handle, mpsc::UnboundedReceiver<Self::Event>), jarust_interface::Error> {
         let (handle, mut receiver) = self
-            .attach("janus.plugin.streaming".to_string(), timeout)
+            .attach_with_params("janus.plugin.streaming".to_string(), params, timeout)
             .await?;
         let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
         let task = jarust_rt::spawn("streaming listener", async move {
@@ -1,3 +1,4 @@
+pub mod error_codes;
 pub mod events;
 pub mod handle;
 pub mod jahandle_ext;
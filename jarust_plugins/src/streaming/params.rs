@@ -2,12 +2,12 @@ use crate::JanusId;
 use serde::Serialize;
 
 // https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_streaming.c#L3311-L4175
-// TODO: only RTP type is supported
 make_dto!(
     StreamingCreateParams,
     required {
-        #[serde(rename = "type")]
-        mountpoint_type: StreamingMountpointType
+        /// mountpoint type and its type specific options, serialized with a `type` tag
+        #[serde(flatten)]
+        mountpoint: StreamingMountpoint
     },
     optional {
         admin_key: String,
@@ -20,21 +20,84 @@ make_dto!(
         /// pin required for viewers to access mountpoint
         pin: String,
         /// whether the mountpoint should be saved to the configuration file or not, default=false
-        permanent: bool,
-        // RTP only
-        media: Vec<StreamingRtpMedia>
+        permanent: bool
     }
 );
 
+#[allow(clippy::large_enum_variant)]
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
-#[serde(rename_all = "lowercase")]
-pub enum StreamingMountpointType {
-    RTP,
-    LIVE,
-    ONDEMAND,
-    RTSP,
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum StreamingMountpoint {
+    Rtp(StreamingRtpParams),
+    Live(StreamingFileParams),
+    Ondemand(StreamingFileParams),
+    Rtsp(StreamingRtspParams),
 }
 
+make_dto!(
+    StreamingRtpParams,
+    required { media: Vec<StreamingRtpMedia> }
+);
+
+// live and ondemand
+make_dto!(
+    StreamingFileParams,
+    required {
+        /// path to the local file to stream, only .opus, .alaw and .mulaw files are supported
+        filename: String
+    },
+    optional {
+        /// whether the mountpoint will have audio
+        audio: bool
+    }
+);
+
+make_dto!(
+    StreamingRtspParams,
+    required {
+        /// RTSP stream URL
+        url: String
+    },
+    optional {
+        /// RTSP authentication username, if needed
+        rtsp_user: String,
+        /// RTSP authentication password, if needed
+        rtsp_pwd: String,
+        /// whether the RTSP stream should be negotiated as a Plain RTSP (e.g. some ancient cameras), default=false
+        rtsp_quirk: bool,
+        /// whether the RTSP stream should be checked for failures and reconnected if needed, default=true
+        rtsp_failcheck: bool,
+        /// delay in seconds before trying to reconnect to the RTSP server, default=5
+        rtsp_reconnect_delay: u64,
+        /// RTSP session timeout in seconds, default is the one negotiated by the server
+        rtsp_session_timeout: u64,
+        /// communication timeout (CURLOPT_TIMEOUT) for cURL calls in seconds, default=10
+        rtsp_timeout: u64,
+        /// connection timeout (CURLOPT_CONNECTTIMEOUT) for cURL calls in seconds, default=5
+        rtsp_conn_timeout: u64,
+        /// network interface or IP address to bind to, if any (binds to all otherwise)
+        rtspiface: String,
+        /// whether the mountpoint will have audio
+        audio: bool,
+        /// RTP payload type of the RTSP audio stream
+        audiopt: u8,
+        /// RTP map of the RTSP audio stream
+        audiortpmap: String,
+        /// fmtp of the RTSP audio stream
+        audiofmtp: String,
+        /// whether the mountpoint will have video
+        video: bool,
+        /// RTP payload type of the RTSP video stream
+        videopt: u8,
+        /// RTP map of the RTSP video stream
+        videortpmap: String,
+        /// fmtp of the RTSP video stream
+        videofmtp: String,
+        /// whether the plugin should store the latest keyframe and send it immediately for new viewers
+        videobufferkf: bool
+    }
+);
+
 // https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_streaming.c#L1100
 make_dto!(
     StreamingRtpMedia,
@@ -54,7 +117,23 @@ make_dto!(
         pt: u8, // payload type is restricted to 0-127
         codec: String,
         fmtp: String,
-        skew: bool // missing video only and data only parameters ?
+        skew: bool,
+        // video only
+        /// whether the plugin should store the latest keyframe and send it immediately for new viewers
+        videobufferkf: bool,
+        /// whether the plugin should expect simulcast traffic for this video stream
+        simulcast: bool,
+        /// second local port for receiving video frames (only for simulcast)
+        port2: u16,
+        /// third local port for receiving video frames (only for simulcast)
+        port3: u16,
+        /// whether the plugin should expect VP9 SVC traffic for this video stream
+        svc: bool,
+        // data only
+        /// <text|binary>, type of data this mountpoint will relay, default=text
+        datatype: StreamingDataType,
+        /// whether the plugin should store the latest message and send it immediately for new viewers
+        databuffermsg: bool
     }
 );
 
@@ -66,6 +145,13 @@ pub enum StreamingRtpMediaType {
     DATA,
 }
 
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StreamingDataType {
+    Text,
+    Binary,
+}
+
 make_dto!(
     StreamingDestroyParams,
     required { id: JanusId },
@@ -76,3 +162,296 @@ make_dto!(
         permanent: bool
     }
 );
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_streaming.c#L4996-L5100
+make_dto!(
+    StreamingWatchParams,
+    required {
+        /// unique ID of the mountpoint to subscribe to
+        id: JanusId
+    },
+    optional {
+        /// PIN required to access the mountpoint, mandatory if configured
+        pin: String,
+        /// array of mids to subscribe to, missing or empty array subscribes to all mids
+        media: Vec<String>,
+        /// whether or not audio should be negotiated, true by default if the mountpoint has audio
+        offer_audio: bool,
+        /// whether or not video should be negotiated, true by default if the mountpoint has video
+        offer_video: bool,
+        /// whether or not datachannels should be negotiated, true by default if the mountpoint has datachannels
+        offer_data: bool
+    }
+);
+
+make_dto!(
+    StreamingSwitchParams,
+    required {
+        /// unique ID of the new mountpoint to switch to
+        id: JanusId
+    }
+);
+
+make_dto!(
+    StreamingConfigureParams,
+    required { streams: Vec<StreamingConfigureStream> }
+);
+
+make_dto!(
+    StreamingConfigureStream,
+    required {
+        /// mid of the m-line to tweak
+        mid: String
+    },
+    optional {
+        /// whether the media addressed by the mid should be relayed or not
+        send: bool,
+        /// substream to receive (0-2), in case simulcasting is enabled
+        substream: u8,
+        /// temporal layers to receive (0-2), in case simulcasting is enabled
+        temporal: u8,
+        /// how much time (in us, default 250000) without receiving packets will make us drop to the substream below
+        fallback: u64,
+        /// spatial layer to receive (0-1), in case SVC is enabled
+        spatial_layer: u8,
+        /// temporal layers to receive (0-2), in case SVC is enabled
+        temporal_layer: u8,
+        /// minimum delay to enforce via the playout-delay RTP extension, in blocks of 10ms
+        min_delay: u64,
+        /// maximum delay to enforce via the playout-delay RTP extension, in blocks of 10ms
+        max_delay: u64
+    }
+);
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_streaming.c#L4415-L4700
+make_dto!(
+    StreamingEditParams,
+    required { id: JanusId },
+    optional {
+        /// mountpoint secret, mandatory if configured
+        secret: String,
+        new_description: String,
+        new_metadata: String,
+        new_secret: String,
+        new_pin: String,
+        /// whether the mountpoint should be now listable
+        new_is_private: bool,
+        /// whether the mountpoint should be saved to configuration file or not, default=false
+        permanent: bool,
+        /// whether viewers should be notified with an "edited" event, default=false
+        edited_event: bool
+    }
+);
+
+make_dto!(
+    StreamingEnableParams,
+    required { id: JanusId },
+    optional {
+        /// mountpoint secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    StreamingDisableParams,
+    required { id: JanusId },
+    optional {
+        /// mountpoint secret, mandatory if configured
+        secret: String,
+        /// whether the recording should also be stopped or not, default=true
+        stop_recording: bool
+    }
+);
+
+make_dto!(
+    StreamingRecordingParams,
+    required {
+        id: JanusId,
+        action: StreamingRecordingAction,
+        media: Vec<StreamingRecordingMedia>
+    },
+    optional {
+        /// mountpoint secret, mandatory if configured
+        secret: String
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StreamingRecordingAction {
+    Start,
+    Stop,
+}
+
+make_dto!(
+    StreamingRecordingMedia,
+    required {
+        /// mid of the stream to start/stop recording
+        mid: String
+    },
+    optional {
+        /// base path/filename to use for the recording, only needed when starting a recording
+        filename: String
+    }
+);
+
+make_dto!(
+    StreamingKickAllParams,
+    required { id: JanusId },
+    optional {
+        /// mountpoint secret, mandatory if configured
+        secret: String
+    }
+);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use serde_json::Value;
+
+    #[test]
+    fn it_serializes_rtp_mountpoint() {
+        let params = StreamingCreateParams {
+            mountpoint: StreamingMountpoint::Rtp(StreamingRtpParams {
+                media: vec![StreamingRtpMedia {
+                    required: StreamingRtpMediaRequired {
+                        media_type: StreamingRtpMediaType::VIDEO,
+                        mid: String::from("v"),
+                        port: 5004,
+                    },
+                    optional: StreamingRtpMediaOptional {
+                        pt: Some(100),
+                        codec: Some(String::from("vp8")),
+                        ..Default::default()
+                    },
+                }],
+            }),
+            optional: StreamingCreateParamsOptional {
+                id: Some(JanusId::Uint(1337.into())),
+                name: Some(String::from("rtp")),
+                ..Default::default()
+            },
+        };
+        let value: Value = params.try_into().unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "type": "rtp",
+                "id": 1337,
+                "name": "rtp",
+                "media": [{ "type": "video", "mid": "v", "port": 5004, "pt": 100, "codec": "vp8" }]
+            })
+        );
+    }
+
+    #[test]
+    fn it_serializes_rtsp_mountpoint() {
+        let params = StreamingCreateParams {
+            mountpoint: StreamingMountpoint::Rtsp(StreamingRtspParams {
+                url: String::from("rtsp://camera.local/stream"),
+                optional: StreamingRtspParamsOptional {
+                    rtsp_user: Some(String::from("user")),
+                    rtsp_pwd: Some(String::from("pwd")),
+                    video: Some(true),
+                    videopt: Some(96),
+                    ..Default::default()
+                },
+            }),
+            optional: StreamingCreateParamsOptional::default(),
+        };
+        let value: Value = params.try_into().unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "type": "rtsp",
+                "url": "rtsp://camera.local/stream",
+                "rtsp_user": "user",
+                "rtsp_pwd": "pwd",
+                "video": true,
+                "videopt": 96
+            })
+        );
+    }
+
+    #[test]
+    fn it_serializes_file_mountpoints() {
+        let file = StreamingFileParams {
+            filename: String::from("/opt/music.opus"),
+            audio: Some(true),
+        };
+        let live: Value = StreamingCreateParams {
+            mountpoint: StreamingMountpoint::Live(file.clone()),
+            optional: StreamingCreateParamsOptional::default(),
+        }
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            live,
+            json!({ "type": "live", "filename": "/opt/music.opus", "audio": true })
+        );
+
+        let ondemand: Value = StreamingCreateParams {
+            mountpoint: StreamingMountpoint::Ondemand(file),
+            optional: StreamingCreateParamsOptional {
+                permanent: Some(false),
+                ..Default::default()
+            },
+        }
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            ondemand,
+            json!({
+                "type": "ondemand",
+                "filename": "/opt/music.opus",
+                "audio": true,
+                "permanent": false
+            })
+        );
+    }
+
+    #[test]
+    fn it_serializes_recording() {
+        let params = StreamingRecordingParams {
+            id: JanusId::Uint(1337.into()),
+            action: StreamingRecordingAction::Start,
+            media: vec![
+                StreamingRecordingMedia {
+                    mid: String::from("a"),
+                    filename: Some(String::from("/tmp/audio")),
+                },
+                StreamingRecordingMedia {
+                    mid: String::from("v"),
+                    filename: None,
+                },
+            ],
+            secret: None,
+        };
+        let value: Value = params.try_into().unwrap();
+        assert_eq!(
+            value,
+            json!({
+                "id": 1337,
+                "action": "start",
+                "media": [{ "mid": "a", "filename": "/tmp/audio" }, { "mid": "v" }]
+            })
+        );
+
+        let value: Value = StreamingRecordingParams {
+            id: JanusId::Uint(1337.into()),
+            action: StreamingRecordingAction::Stop,
+            media: vec![StreamingRecordingMedia {
+                mid: String::from("a"),
+                filename: None,
+            }],
+            secret: Some(String::from("s3cr3t")),
+        }
+        .try_into()
+        .unwrap();
+        assert_eq!(
+            value,
+            json!({ "id": 1337, "action": "stop", "media": [{ "mid": "a" }], "secret": "s3cr3t" })
+        );
+    }
+}
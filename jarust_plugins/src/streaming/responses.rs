@@ -136,3 +136,60 @@ pub struct RtpMediaInfo {
     pub age_ms: Option<u64>,
     pub recording: Option<String>,
 }
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_streaming.c#L4890-L4900
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct MountpointEditedRsp {
+    pub id: JanusId,
+    #[serde(default)]
+    pub permanent: bool,
+}
+
+/// Plain `{"streaming": "ok"}` response, returned by the enable, disable, recording and kick_all requests
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct StreamingOkRsp {
+    pub streaming: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::streaming::responses::MountpointEditedRsp;
+    use crate::JanusId;
+
+    #[test]
+    fn parse_mountpoint_edited() {
+        let source = "{
+            \"streaming\": \"edited\",
+            \"id\": 1337,
+            \"permanent\": true
+        }";
+
+        let dut: MountpointEditedRsp = serde_json::from_str(source).unwrap();
+
+        assert_eq!(
+            dut,
+            MountpointEditedRsp {
+                id: JanusId::Uint(1337.into()),
+                permanent: true,
+            }
+        );
+    }
+
+    #[test]
+    fn parse_mountpoint_edited_without_permanent() {
+        let source = "{
+            \"streaming\": \"edited\",
+            \"id\": \"camera\"
+        }";
+
+        let dut: MountpointEditedRsp = serde_json::from_str(source).unwrap();
+
+        assert_eq!(
+            dut,
+            MountpointEditedRsp {
+                id: JanusId::String("camera".to_string()),
+                permanent: false,
+            }
+        );
+    }
+}
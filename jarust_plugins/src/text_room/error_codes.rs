@@ -0,0 +1,19 @@
+jarust_interface::error_codes! {
+    /// TextRoom plugin error codes
+    TextRoomErrorCode {
+        NoMessage = 411,
+        InvalidJson = 412,
+        MissingElement = 413,
+        InvalidElement = 414,
+        InvalidRequest = 415,
+        AlreadySetup = 416,
+        NoSuchRoom = 417,
+        RoomExists = 418,
+        Unauthorized = 419,
+        UsernameExists = 420,
+        AlreadyInRoom = 421,
+        NotInRoom = 422,
+        NoSuchUser = 423,
+        UnknownError = 499,
+    }
+}
@@ -0,0 +1,337 @@
+use crate::JanusId;
+use jarust_interface::japrotocol::GenericEvent;
+use jarust_interface::japrotocol::JaHandleEvent;
+use jarust_interface::japrotocol::JaResponse;
+use jarust_interface::japrotocol::Jsep;
+use jarust_interface::japrotocol::PluginInnerData;
+use jarust_interface::japrotocol::ResponseType;
+use serde::Deserialize;
+use serde_json::from_value;
+use serde_json::Value;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "textroom")]
+enum TextRoomEventDto {
+    #[serde(rename = "message")]
+    Message {
+        room: JanusId,
+        from: String,
+        date: String,
+        text: String,
+        #[serde(default)]
+        whisper: bool,
+    },
+    #[serde(rename = "announcement")]
+    Announcement {
+        room: JanusId,
+        date: String,
+        text: String,
+    },
+    #[serde(rename = "join")]
+    Join {
+        room: JanusId,
+        username: String,
+        display: Option<String>,
+    },
+    #[serde(rename = "leave")]
+    Leave { room: JanusId, username: String },
+    #[serde(rename = "kicked")]
+    Kicked { room: JanusId, username: String },
+    #[serde(rename = "destroyed")]
+    Destroyed { room: JanusId },
+    #[serde(rename = "event")]
+    Event { result: String },
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PluginEvent {
+    TextRoomEvent(TextRoomEvent),
+    GenericEvent(GenericEvent),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum TextRoomEvent {
+    /// A message sent to the room, or whispered to us
+    Message {
+        room: JanusId,
+        /// username of the sender
+        from: String,
+        date: String,
+        text: String,
+        /// whether the message was sent to us only (or to a subset of the participants)
+        whisper: bool,
+    },
+    /// An announcement sent to all the participants of the room
+    Announcement {
+        room: JanusId,
+        date: String,
+        text: String,
+    },
+    /// Sent to all participants when a new participant joins
+    Join {
+        room: JanusId,
+        username: String,
+        display: Option<String>,
+    },
+    /// Sent to all participants when a participant leaves
+    Leave {
+        room: JanusId,
+        username: String,
+    },
+    /// Sent to all participants when a participant is kicked
+    Kicked {
+        room: JanusId,
+        username: String,
+    },
+    /// Sent to all participants when the room is destroyed
+    RoomDestroyed {
+        room: JanusId,
+    },
+    /// Sent back after a successful [ack](super::handle::TextRoomHandle::ack) request
+    Ok,
+    /// Sent back after a successful [setup](super::handle::TextRoomHandle::setup) request with the JSEP SDP offer to answer
+    OkWithJsep {
+        jsep: Jsep,
+    },
+    Error {
+        error_code: u16,
+        error: String,
+    },
+    Other(Value),
+}
+
+impl TryFrom<JaResponse> for PluginEvent {
+    type Error = jarust_interface::Error;
+
+    fn try_from(value: JaResponse) -> Result<Self, Self::Error> {
+        match value.janus {
+            ResponseType::Event(JaHandleEvent::PluginEvent { plugin_data }) => {
+                let textroom_event = match plugin_data.data {
+                    PluginInnerData::Error { error_code, error } => {
+                        TextRoomEvent::Error { error_code, error }
+                    }
+                    PluginInnerData::Data(data) => {
+                        match from_value::<TextRoomEventDto>(data.clone()) {
+                            Ok(TextRoomEventDto::Message {
+                                room,
+                                from,
+                                date,
+                                text,
+                                whisper,
+                            }) => TextRoomEvent::Message {
+                                room,
+                                from,
+                                date,
+                                text,
+                                whisper,
+                            },
+                            Ok(TextRoomEventDto::Announcement { room, date, text }) => {
+                                TextRoomEvent::Announcement { room, date, text }
+                            }
+                            Ok(TextRoomEventDto::Join {
+                                room,
+                                username,
+                                display,
+                            }) => TextRoomEvent::Join {
+                                room,
+                                username,
+                                display,
+                            },
+                            Ok(TextRoomEventDto::Leave { room, username }) => {
+                                TextRoomEvent::Leave { room, username }
+                            }
+                            Ok(TextRoomEventDto::Kicked { room, username }) => {
+                                TextRoomEvent::Kicked { room, username }
+                            }
+                            Ok(TextRoomEventDto::Destroyed { room }) => {
+                                TextRoomEvent::RoomDestroyed { room }
+                            }
+                            Ok(TextRoomEventDto::Event { result }) if result == "ok" => {
+                                match value.jsep {
+                                    Some(jsep) => TextRoomEvent::OkWithJsep { jsep },
+                                    None => TextRoomEvent::Ok,
+                                }
+                            }
+                            Ok(TextRoomEventDto::Event { .. }) | Err(_) => {
+                                TextRoomEvent::Other(data)
+                            }
+                        }
+                    }
+                };
+                Ok(PluginEvent::TextRoomEvent(textroom_event))
+            }
+            ResponseType::Event(JaHandleEvent::GenericEvent(event)) => {
+                Ok(PluginEvent::GenericEvent(event))
+            }
+            _ => Err(Self::Error::IncompletePacket),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginEvent;
+    use crate::text_room::events::TextRoomEvent;
+    use crate::JanusId;
+    use jarust_interface::japrotocol::JaHandleEvent;
+    use jarust_interface::japrotocol::JaResponse;
+    use jarust_interface::japrotocol::Jsep;
+    use jarust_interface::japrotocol::JsepType;
+    use jarust_interface::japrotocol::PluginData;
+    use jarust_interface::japrotocol::PluginInnerData;
+    use jarust_interface::japrotocol::ResponseType;
+    use serde_json::json;
+
+    #[test]
+    fn it_parse_message() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.textroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "textroom": "message",
+                        "room": 1234,
+                        "from": "alice",
+                        "date": "2024-08-25T10:12:03+0200",
+                        "text": "hello",
+                        "whisper": true
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::TextRoomEvent(TextRoomEvent::Message {
+                room: JanusId::Uint(1234.into()),
+                from: "alice".to_string(),
+                date: "2024-08-25T10:12:03+0200".to_string(),
+                text: "hello".to_string(),
+                whisper: true,
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_join() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.textroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "textroom": "join",
+                        "room": 1234,
+                        "username": "alice",
+                        "display": "Alice"
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::TextRoomEvent(TextRoomEvent::Join {
+                room: JanusId::Uint(1234.into()),
+                username: "alice".to_string(),
+                display: Some("Alice".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_kicked() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.textroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "textroom": "kicked",
+                        "room": 1234,
+                        "username": "alice"
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::TextRoomEvent(TextRoomEvent::Kicked {
+                room: JanusId::Uint(1234.into()),
+                username: "alice".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_setup_offer() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.textroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "textroom": "event",
+                        "result": "ok"
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Offer,
+                trickle: None,
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::TextRoomEvent(TextRoomEvent::OkWithJsep {
+                jsep: Jsep {
+                    jsep_type: JsepType::Offer,
+                    trickle: None,
+                    sdp: "test_sdp".to_string(),
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_error() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.textroom".to_string(),
+                    data: PluginInnerData::Error {
+                        error_code: 417,
+                        error: "No such room (1234)".to_string(),
+                    },
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::TextRoomEvent(TextRoomEvent::Error {
+                error_code: 417,
+                error: "No such room (1234)".to_string(),
+            })
+        );
+    }
+}
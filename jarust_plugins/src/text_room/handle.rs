@@ -0,0 +1,269 @@
+use super::params::*;
+use super::responses::*;
+use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
+use jarust_rt::JaTask;
+use serde_json::json;
+use serde_json::Value;
+use std::ops::Deref;
+use std::time::Duration;
+
+pub struct TextRoomHandle {
+    handle: JaHandle,
+    task: Option<JaTask>,
+}
+
+// sync
+impl TextRoomHandle {
+    /// Create a new text room dynamically, as an alternative to using the configuration file
+    ///
+    /// Random room number will be used if `room` is `None`
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn create_room(
+        &self,
+        params: TextRoomCreateParams,
+        timeout: Duration,
+    ) -> Result<TextRoomCreatedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending create room");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "create".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomCreatedRsp>(message, timeout)
+            .await
+    }
+
+    /// Allows you to dynamically edit some room properties (e.g., the PIN)
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn edit_room(
+        &self,
+        params: TextRoomEditParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending edit room");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "edit".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Removes a text room and destroys it, kicking all the users out as part of the process
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn destroy_room(
+        &self,
+        params: TextRoomDestroyParams,
+        timeout: Duration,
+    ) -> Result<TextRoomDestroyedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending destroy room");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "destroy".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomDestroyedRsp>(message, timeout)
+            .await
+    }
+
+    /// Lists all the available rooms
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_rooms(
+        &self,
+        timeout: Duration,
+    ) -> Result<Vec<TextRoomRoom>, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending list rooms");
+        let response = self
+            .handle
+            .send_waiton_rsp::<TextRoomListRoomsRsp>(json!({"request": "list"}), timeout)
+            .await?;
+        Ok(response.list)
+    }
+
+    /// Allows you to check whether a specific text room exists
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn exists(
+        &self,
+        params: TextRoomExistsParams,
+        timeout: Duration,
+    ) -> Result<bool, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending exists");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "exists".into();
+        let response = self
+            .handle
+            .send_waiton_rsp::<TextRoomExistsRsp>(message, timeout)
+            .await?;
+        Ok(response.exists)
+    }
+
+    /// Allows you to edit who's allowed to join a room via ad-hoc tokens
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn allowed(
+        &self,
+        params: TextRoomAllowedParams,
+        timeout: Duration,
+    ) -> Result<TextRoomAllowedRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending allowed");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "allowed".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomAllowedRsp>(message, timeout)
+            .await
+    }
+
+    /// Kicks a participant out of a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn kick(
+        &self,
+        params: TextRoomKickParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending kick");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "kick".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Sends an announcement to all the participants of a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn announcement(
+        &self,
+        params: TextRoomAnnouncementParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending announcement");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "announcement".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Lists all the participants of a specific room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list_participants(
+        &self,
+        params: TextRoomListParticipantsParams,
+        timeout: Duration,
+    ) -> Result<TextRoomListParticipantsRsp, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending list participants");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "listparticipants".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomListParticipantsRsp>(message, timeout)
+            .await
+    }
+
+    /// Join a text room, returns the list of participants already in the room
+    ///
+    /// Messages and notifications are relayed on the data channel, see [`TextRoomHandle::setup`].
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn join_room(
+        &self,
+        params: TextRoomJoinParams,
+        timeout: Duration,
+    ) -> Result<Vec<TextRoomParticipant>, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending join room");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "join".into();
+        let response = self
+            .handle
+            .send_waiton_rsp::<TextRoomJoinedRsp>(message, timeout)
+            .await?;
+        Ok(response.participants)
+    }
+
+    /// Leave a text room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn leave_room(
+        &self,
+        params: TextRoomLeaveParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending leave room");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "leave".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Send a message to a room, or whisper it to some of its participants
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn message(
+        &self,
+        params: TextRoomMessageParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending message");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "message".into();
+        self.handle
+            .send_waiton_rsp::<TextRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
+    }
+}
+
+// async
+impl TextRoomHandle {
+    /// Ask the plugin to negotiate the data channel used to relay messages and notifications
+    ///
+    /// Janus will answer with a [`TextRoomEvent::OkWithJsep`](super::events::TextRoomEvent::OkWithJsep)
+    /// event carrying the JSEP SDP offer to be answered with [`TextRoomHandle::ack`].
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn setup(&self, timeout: Duration) -> Result<String, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending setup");
+        self.handle
+            .send_waiton_ack(json!({"request": "setup"}), timeout)
+            .await
+    }
+
+    /// Complete the data channel negotiation with the JSEP SDP answer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn ack(
+        &self,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<String, jarust_interface::Error> {
+        tracing::info!(plugin = "textroom", "Sending ack");
+        self.handle
+            .send_waiton_ack_with_jsep(json!({"request": "ack"}), jsep, timeout)
+            .await
+    }
+}
+
+impl PluginTask for TextRoomHandle {
+    fn assign_task(&mut self, task: JaTask) {
+        self.task = Some(task);
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        };
+    }
+}
+
+impl From<JaHandle> for TextRoomHandle {
+    fn from(handle: JaHandle) -> Self {
+        Self { handle, task: None }
+    }
+}
+
+impl Deref for TextRoomHandle {
+    type Target = JaHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Drop for TextRoomHandle {
+    fn drop(&mut self) {
+        self.cancel_task();
+    }
+}
@@ -0,0 +1,6 @@
+pub mod error_codes;
+pub mod events;
+pub mod handle;
+pub mod jahandle_ext;
+pub mod params;
+pub mod responses;
@@ -0,0 +1,152 @@
+use crate::JanusId;
+use serde::Serialize;
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_textroom.c#L88-L275
+make_dto!(
+    TextRoomCreateParams,
+    optional {
+        /// unique numeric ID, chosen by plugin if missing
+        room: JanusId,
+        /// plugin administrator key, mandatory if configured
+        admin_key: String,
+        /// pretty name of the room
+        description: String,
+        /// password required to edit/destroy the room
+        secret: String,
+        /// password required to join the room
+        pin: String,
+        /// whether the room should appear in a list request
+        is_private: bool,
+        /// array of string tokens users can use to join this room
+        allowed: Vec<String>,
+        /// number of messages to store as a history, and send back to new participants (default=0, no history)
+        history: u64,
+        /// backend to contact via HTTP post for all incoming messages
+        post: String,
+        /// whether the room should be saved in the config file, default=false
+        permanent: bool
+    }
+);
+
+make_dto!(
+    TextRoomEditParams,
+    required { room: JanusId },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// new pretty name of the room
+        new_description: String,
+        /// new password required to edit/destroy the room
+        new_secret: String,
+        /// new password required to join the room
+        new_pin: String,
+        /// whether the room should appear in a list request
+        new_is_private: bool,
+        /// new backend to contact via HTTP post for all incoming messages
+        new_post: String,
+        /// whether the room should be also updated in the config file, default=false
+        permanent: bool
+    }
+);
+
+make_dto!(
+    TextRoomDestroyParams,
+    required { room: JanusId },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// whether the room should be also removed from the config file, default=false
+        permanent: bool
+    }
+);
+
+make_dto!(TextRoomExistsParams, required { room: JanusId });
+
+make_dto!(
+    TextRoomAllowedParams,
+    required {
+        room: JanusId,
+        action: TextRoomAllowAction,
+        /// Array of strings (tokens users might pass in "join", only for add|remove)
+        allowed: Vec<String>
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String
+    }
+);
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TextRoomAllowAction {
+    Enable,
+    Disable,
+    Add,
+    Remove,
+}
+
+make_dto!(
+    TextRoomKickParams,
+    required {
+        room: JanusId,
+        /// username of the participant to kick
+        username: String
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(
+    TextRoomAnnouncementParams,
+    required {
+        room: JanusId,
+        /// content of the announcement, as a string
+        text: String
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String
+    }
+);
+
+make_dto!(TextRoomListParticipantsParams, required { room: JanusId });
+
+make_dto!(
+    TextRoomJoinParams,
+    required {
+        room: JanusId,
+        /// unique username to have in the room
+        username: String
+    },
+    optional {
+        /// pin to join the room, mandatory if configured
+        pin: String,
+        /// display name to use in the room
+        display: String,
+        /// invitation token, in case the room has an ACL
+        token: String,
+        /// whether to retrieve the room history or not, default=true
+        history: bool
+    }
+);
+
+make_dto!(TextRoomLeaveParams, required { room: JanusId });
+
+make_dto!(
+    TextRoomMessageParams,
+    required {
+        room: JanusId,
+        /// content of the message to send, as a string
+        text: String
+    },
+    optional {
+        /// username to send the message to, whispers to a single participant
+        to: String,
+        /// array of usernames to send the message to, whispers to multiple participants
+        tos: Vec<String>,
+        /// whether the sender wants an ack for the sent message, default=true
+        ack: bool
+    }
+);
@@ -0,0 +1,68 @@
+use crate::JanusId;
+use serde::Deserialize;
+
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_textroom.c#L88-L275
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomCreatedRsp {
+    pub room: JanusId,
+    #[serde(default)]
+    pub permanent: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomDestroyedRsp {
+    pub room: JanusId,
+    #[serde(default)]
+    pub permanent: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomListRoomsRsp {
+    pub list: Vec<TextRoomRoom>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomRoom {
+    pub room: JanusId,
+    pub description: String,
+    pub pin_required: bool,
+    pub num_participants: u64,
+    pub history: Option<u64>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomExistsRsp {
+    pub room: JanusId,
+    pub exists: bool,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomAllowedRsp {
+    pub room: JanusId,
+    #[serde(default = "Vec::default")]
+    pub allowed: Vec<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomListParticipantsRsp {
+    pub room: JanusId,
+    pub participants: Vec<TextRoomParticipant>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomParticipant {
+    pub username: String,
+    pub display: Option<String>,
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomJoinedRsp {
+    #[serde(default = "Vec::default")]
+    pub participants: Vec<TextRoomParticipant>,
+}
+
+/// Plain `{"textroom": "success"}` response
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct TextRoomSuccessRsp {
+    pub textroom: String,
+}
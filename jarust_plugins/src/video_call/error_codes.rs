@@ -0,0 +1,20 @@
+jarust_interface::error_codes! {
+    /// VideoCall plugin error codes
+    VideoCallErrorCode {
+        NoMessage = 470,
+        InvalidJson = 471,
+        InvalidRequest = 472,
+        RegisterFirst = 473,
+        InvalidElement = 474,
+        MissingElement = 475,
+        UsernameTaken = 476,
+        AlreadyRegistered = 477,
+        NoSuchUsername = 478,
+        UseEchoTest = 479,
+        AlreadyInCall = 480,
+        NoCall = 481,
+        MissingSdp = 482,
+        InvalidSdp = 483,
+        UnknownError = 499,
+    }
+}
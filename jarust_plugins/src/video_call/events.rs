@@ -0,0 +1,318 @@
+use jarust_interface::japrotocol::GenericEvent;
+use jarust_interface::japrotocol::JaHandleEvent;
+use jarust_interface::japrotocol::JaResponse;
+use jarust_interface::japrotocol::Jsep;
+use jarust_interface::japrotocol::PluginInnerData;
+use jarust_interface::japrotocol::ResponseType;
+use serde::Deserialize;
+use serde_json::from_value;
+use serde_json::Value;
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "videocall")]
+enum VideoCallEventDto {
+    #[serde(rename = "event")]
+    Event { result: VideoCallEventResult },
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(untagged)]
+enum VideoCallEventResult {
+    List { list: Vec<String> },
+    Event(VideoCallEventType),
+}
+
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+#[serde(tag = "event", rename_all = "lowercase")]
+enum VideoCallEventType {
+    Registered {
+        username: String,
+    },
+    Calling,
+    IncomingCall {
+        username: String,
+    },
+    Accepted {
+        username: Option<String>,
+    },
+    Update,
+    Set,
+    Hangup {
+        username: Option<String>,
+        reason: String,
+    },
+    Simulcast {
+        videocodec: Option<String>,
+        substream: Option<u8>,
+        temporal: Option<u8>,
+    },
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum PluginEvent {
+    VideoCallEvent(VideoCallEvent),
+    GenericEvent(GenericEvent),
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum VideoCallEvent {
+    /// Sent back after a successful [list](super::handle::VideoCallHandle::list) request
+    List {
+        list: Vec<String>,
+    },
+    /// Sent back after a successful [register](super::handle::VideoCallHandle::register) request
+    Registered {
+        username: String,
+    },
+    /// Sent back after a successful [call](super::handle::VideoCallHandle::call) request
+    Calling,
+    IncomingCall {
+        username: String,
+    },
+    /// Incoming call with the JSEP SDP offer to answer
+    IncomingCallWithJsep {
+        username: String,
+        jsep: Jsep,
+    },
+    Accepted {
+        username: Option<String>,
+    },
+    /// Sent to the caller when the call is accepted, with the JSEP SDP answer
+    AcceptedWithJsep {
+        username: Option<String>,
+        jsep: Jsep,
+    },
+    /// The call was renegotiated
+    Update,
+    /// The call was renegotiated, with the JSEP SDP to apply
+    UpdateWithJsep {
+        jsep: Jsep,
+    },
+    /// Sent back after a successful [set](super::handle::VideoCallHandle::set) request
+    Set,
+    Hangup {
+        username: Option<String>,
+        reason: String,
+    },
+    /// The simulcast substream or temporal layer being received changed
+    Simulcast {
+        videocodec: Option<String>,
+        substream: Option<u8>,
+        temporal: Option<u8>,
+    },
+    Error {
+        error_code: u16,
+        error: String,
+    },
+    Other(Value),
+}
+
+impl TryFrom<JaResponse> for PluginEvent {
+    type Error = jarust_interface::Error;
+
+    fn try_from(value: JaResponse) -> Result<Self, Self::Error> {
+        use VideoCallEventResult as EventResult;
+        use VideoCallEventType as Event;
+
+        match value.janus {
+            ResponseType::Event(JaHandleEvent::PluginEvent { plugin_data }) => {
+                let videocall_event = match plugin_data.data {
+                    PluginInnerData::Error { error_code, error } => {
+                        VideoCallEvent::Error { error_code, error }
+                    }
+                    PluginInnerData::Data(data) => {
+                        match from_value::<VideoCallEventDto>(data.clone()) {
+                            Ok(VideoCallEventDto::Event { result }) => match result {
+                                EventResult::List { list } => VideoCallEvent::List { list },
+                                EventResult::Event(event) => match event {
+                                    Event::Registered { username } => {
+                                        VideoCallEvent::Registered { username }
+                                    }
+                                    Event::Calling => VideoCallEvent::Calling,
+                                    Event::IncomingCall { username } => match value.jsep {
+                                        Some(jsep) => {
+                                            VideoCallEvent::IncomingCallWithJsep { username, jsep }
+                                        }
+                                        None => VideoCallEvent::IncomingCall { username },
+                                    },
+                                    Event::Accepted { username } => match value.jsep {
+                                        Some(jsep) => {
+                                            VideoCallEvent::AcceptedWithJsep { username, jsep }
+                                        }
+                                        None => VideoCallEvent::Accepted { username },
+                                    },
+                                    Event::Update => match value.jsep {
+                                        Some(jsep) => VideoCallEvent::UpdateWithJsep { jsep },
+                                        None => VideoCallEvent::Update,
+                                    },
+                                    Event::Set => VideoCallEvent::Set,
+                                    Event::Hangup { username, reason } => {
+                                        VideoCallEvent::Hangup { username, reason }
+                                    }
+                                    Event::Simulcast {
+                                        videocodec,
+                                        substream,
+                                        temporal,
+                                    } => VideoCallEvent::Simulcast {
+                                        videocodec,
+                                        substream,
+                                        temporal,
+                                    },
+                                },
+                            },
+                            Err(_) => VideoCallEvent::Other(data),
+                        }
+                    }
+                };
+                Ok(PluginEvent::VideoCallEvent(videocall_event))
+            }
+            ResponseType::Event(JaHandleEvent::GenericEvent(event)) => {
+                Ok(PluginEvent::GenericEvent(event))
+            }
+            _ => Err(Self::Error::IncompletePacket),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PluginEvent;
+    use crate::video_call::events::VideoCallEvent;
+    use jarust_interface::japrotocol::JaHandleEvent;
+    use jarust_interface::japrotocol::JaResponse;
+    use jarust_interface::japrotocol::Jsep;
+    use jarust_interface::japrotocol::JsepType;
+    use jarust_interface::japrotocol::PluginData;
+    use jarust_interface::japrotocol::PluginInnerData;
+    use jarust_interface::japrotocol::ResponseType;
+    use serde_json::json;
+
+    #[test]
+    fn it_parse_list() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videocall".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videocall": "event",
+                        "result": {
+                            "list": ["alice", "bob"]
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoCallEvent(VideoCallEvent::List {
+                list: vec!["alice".to_string(), "bob".to_string()]
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_incoming_call_with_jsep() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videocall".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videocall": "event",
+                        "result": {
+                            "event": "incomingcall",
+                            "username": "alice"
+                        }
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Offer,
+                trickle: None,
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoCallEvent(VideoCallEvent::IncomingCallWithJsep {
+                username: "alice".to_string(),
+                jsep: Jsep {
+                    jsep_type: JsepType::Offer,
+                    trickle: None,
+                    sdp: "test_sdp".to_string(),
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_hangup() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videocall".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videocall": "event",
+                        "result": {
+                            "event": "hangup",
+                            "username": "alice",
+                            "reason": "Remote hangup"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoCallEvent(VideoCallEvent::Hangup {
+                username: Some("alice".to_string()),
+                reason: "Remote hangup".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_simulcast() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videocall".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videocall": "event",
+                        "result": {
+                            "event": "simulcast",
+                            "videocodec": "vp8",
+                            "substream": 1
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoCallEvent(VideoCallEvent::Simulcast {
+                videocodec: Some("vp8".to_string()),
+                substream: Some(1),
+                temporal: None,
+            })
+        );
+    }
+}
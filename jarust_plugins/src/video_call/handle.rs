@@ -0,0 +1,136 @@
+use super::params::*;
+use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
+use jarust_rt::JaTask;
+use serde_json::json;
+use serde_json::Value;
+use std::ops::Deref;
+use std::time::Duration;
+
+pub struct VideoCallHandle {
+    handle: JaHandle,
+    task: Option<JaTask>,
+}
+
+// async, the outcome of every request is notified with an event
+impl VideoCallHandle {
+    /// List the registered users, the result is notified with a
+    /// [`VideoCallEvent::List`](super::events::VideoCallEvent::List) event
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn list(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videocall", "Sending list");
+        self.handle
+            .send_waiton_ack(json!({"request": "list"}), timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Register a username, required before calling or being called
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn register(
+        &self,
+        params: VideoCallRegisterParams,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videocall", "Sending register");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "register".into();
+        self.handle.send_waiton_ack(message, timeout).await?;
+        Ok(())
+    }
+
+    /// Call a registered user, this request MUST be accompanied by a JSEP SDP offer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn call(
+        &self,
+        params: VideoCallCallParams,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videocall", "Sending call");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "call".into();
+        self.handle
+            .send_waiton_ack_with_jsep(message, jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Accept an incoming call, this request MUST be accompanied by a JSEP SDP answer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn accept(
+        &self,
+        jsep: Jsep,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videocall", "Sending accept");
+        self.handle
+            .send_waiton_ack_with_jsep(json!({"request": "accept"}), jsep, timeout)
+            .await?;
+        Ok(())
+    }
+
+    /// Tweak the media of the current call, optionally renegotiating it with a JSEP SDP offer
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn set(
+        &self,
+        params: VideoCallSetParams,
+        jsep: Option<Jsep>,
+        timeout: Duration,
+    ) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videocall", "Sending set");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "set".into();
+        match jsep {
+            None => self.handle.send_waiton_ack(message, timeout).await?,
+            Some(jsep) => {
+                self.handle
+                    .send_waiton_ack_with_jsep(message, jsep, timeout)
+                    .await?
+            }
+        };
+        Ok(())
+    }
+
+    /// Hang up the current call
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn hangup(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
+        tracing::info!(plugin = "videocall", "Sending hangup");
+        self.handle
+            .send_waiton_ack(json!({"request": "hangup"}), timeout)
+            .await?;
+        Ok(())
+    }
+}
+
+impl PluginTask for VideoCallHandle {
+    fn assign_task(&mut self, task: JaTask) {
+        self.task = Some(task);
+    }
+
+    fn cancel_task(&mut self) {
+        if let Some(task) = self.task.take() {
+            task.cancel();
+        };
+    }
+}
+
+impl From<JaHandle> for VideoCallHandle {
+    fn from(handle: JaHandle) -> Self {
+        Self { handle, task: None }
+    }
+}
+
+impl Deref for VideoCallHandle {
+    type Target = JaHandle;
+
+    fn deref(&self) -> &Self::Target {
+        &self.handle
+    }
+}
+
+impl Drop for VideoCallHandle {
+    fn drop(&mut self) {
+        self.cancel_task();
+    }
+}
@@ -0,0 +1,38 @@
+// https://github.com/meetecho/janus-gateway/blob/v1.2.4/src/plugins/janus_videocall.c#L39-L300
+make_dto!(
+    VideoCallRegisterParams,
+    required {
+        /// username to register with, must be unique
+        username: String
+    }
+);
+
+make_dto!(
+    VideoCallCallParams,
+    required {
+        /// username of the peer to call
+        username: String
+    }
+);
+
+make_dto!(
+    VideoCallSetParams,
+    optional {
+        /// whether audio should be relayed to the peer
+        audio: bool,
+        /// whether video should be relayed to the peer
+        video: bool,
+        /// bitrate cap to apply to the peer's video (bps), 0 means no limit
+        bitrate: u64,
+        /// whether the call should be recorded
+        record: bool,
+        /// base path/filename to use for the recording
+        filename: String,
+        /// substream to receive (0-2), in case simulcasting is enabled
+        substream: u8,
+        /// temporal layers to receive (0-2), in case simulcasting is enabled
+        temporal: u8,
+        /// how much time (in us, default 250000) without receiving packets will make us drop to the substream below
+        fallback: u64
+    }
+);
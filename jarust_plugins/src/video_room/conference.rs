@@ -0,0 +1,952 @@
+//! High-level VideoRoom conference
+//!
+//! A [`Conference`] owns a publisher handle and a single multistream subscriber handle. It keeps track of the
+//! room roster, subscribes to the streams of remote publishers as soon as they're published (relying on
+//! `autoupdate` for streams going away) and surfaces participant-level [`ConferenceEvent`]s instead of raw
+//! plugin events.
+
+use super::events::PluginEvent;
+use super::events::VideoRoomEvent;
+use super::handle::VideoRoomHandle;
+use super::jahandle_ext::VideoRoom;
+use super::params::VideoRoomPublishParams;
+use super::params::VideoRoomPublisherConfigureParams;
+use super::params::VideoRoomPublisherJoinParams;
+use super::params::VideoRoomSubscribeParams;
+use super::params::VideoRoomSubscriberJoinParams;
+use super::params::VideoRoomSubscriberJoinParamsOptional;
+use super::params::VideoRoomSubscriberJoinStream;
+use super::params::VideoRoomSubscriberJoinStreamOptional;
+use super::responses::AttachedStream;
+use super::responses::Publisher;
+use super::responses::Stream;
+use crate::JanusId;
+use jarust_core::prelude::*;
+use jarust_interface::japrotocol::Jsep;
+use jarust_rt::JaTask;
+use std::collections::BTreeMap;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::sync::Mutex;
+
+/// A remote participant of the conference
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub struct ConferenceParticipant {
+    /// unique ID of the participant
+    pub id: JanusId,
+    /// display name of the participant, if any
+    pub display: Option<String>,
+    /// streams currently published by the participant, empty if not publishing
+    pub streams: Vec<Stream>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Debug)]
+pub enum ConferenceEvent {
+    /// The local participant joined the room as a publisher
+    Joined {
+        room: JanusId,
+        id: JanusId,
+        private_id: u64,
+    },
+    /// The local publisher was configured, with the SDP answer if an offer was provided
+    Configured {
+        jsep: Option<Jsep>,
+    },
+    /// A remote participant joined the room
+    ParticipantJoined(ConferenceParticipant),
+    /// A remote participant started publishing or changed its published streams
+    ParticipantPublished(ConferenceParticipant),
+    /// A remote participant stopped publishing
+    ParticipantUnpublished {
+        id: JanusId,
+    },
+    /// A remote participant left the room or was kicked out of it
+    ParticipantLeft {
+        id: JanusId,
+    },
+    /// A remote participant started talking (only if audio levels are used)
+    Talking {
+        id: JanusId,
+        audio_level: i16,
+    },
+    /// A remote participant stopped talking (only if audio levels are used)
+    StoppedTalking {
+        id: JanusId,
+        audio_level: i16,
+    },
+    /// The subscriber received a new SDP offer, to be answered with [`Conference::answer`]
+    SubscriberOffer {
+        streams: Vec<AttachedStream>,
+        jsep: Jsep,
+    },
+    /// The subscription changed without requiring a renegotiation
+    SubscriberUpdated {
+        streams: Vec<AttachedStream>,
+    },
+    /// The local participant was kicked out of the room
+    Kicked,
+    /// The room was destroyed
+    RoomDestroyed,
+    /// A subscribe request couldn't be sent
+    SubscriptionFailed {
+        reason: String,
+    },
+    Error {
+        error_code: u16,
+        error: String,
+    },
+}
+
+/// Multistream VideoRoom conference, see the [module level docs](self)
+pub struct Conference {
+    publisher: Arc<VideoRoomHandle>,
+    subscriber: Arc<VideoRoomHandle>,
+    state: Arc<Mutex<ConferenceState>>,
+    timeout: Duration,
+    _tasks: Vec<JaTask>,
+}
+
+impl Conference {
+    /// Attach a publisher and a subscriber handle to the session and join the room as a publisher
+    ///
+    /// The returned receiver yields [`ConferenceEvent`]s for as long as the conference is alive.
+    /// `timeout` is used for the join request and for every subscription request sent on your behalf.
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
+    pub async fn join(
+        session: &JaSession,
+        params: VideoRoomPublisherJoinParams,
+        jsep: Option<Jsep>,
+        timeout: Duration,
+    ) -> Result<(Self, mpsc::UnboundedReceiver<ConferenceEvent>), jarust_interface::Error> {
+        tracing::info!(plugin = "videoroom", "Joining conference");
+        let (publisher, publisher_events) = session.attach_video_room(timeout).await?;
+        let (subscriber, subscriber_events) = session.attach_video_room(timeout).await?;
+        let publisher = Arc::new(publisher);
+        let subscriber = Arc::new(subscriber);
+        let state = Arc::new(Mutex::new(ConferenceState::new(params.room.clone())));
+        let (tx, rx) = mpsc::unbounded_channel();
+
+        let tasks = vec![
+            jarust_rt::spawn(
+                "conference publisher listener",
+                Self::listen(
+                    EventSource::Publisher,
+                    publisher_events,
+                    subscriber.clone(),
+                    state.clone(),
+                    tx.clone(),
+                    timeout,
+                ),
+            ),
+            jarust_rt::spawn(
+                "conference subscriber listener",
+                Self::listen(
+                    EventSource::Subscriber,
+                    subscriber_events,
+                    subscriber.clone(),
+                    state.clone(),
+                    tx,
+                    timeout,
+                ),
+            ),
+        ];
+
+        publisher.join_as_publisher(params, jsep, timeout).await?;
+
+        Ok((
+            Self {
+                publisher,
+                subscriber,
+                state,
+                timeout,
+                _tasks: tasks,
+            },
+            rx,
+        ))
+    }
+
+    /// Start publishing in the room, the SDP answer is delivered with [`ConferenceEvent::Configured`]
+    pub async fn publish(
+        &self,
+        params: VideoRoomPublishParams,
+        jsep: Jsep,
+    ) -> Result<(), jarust_interface::Error> {
+        self.publisher.publish(params, jsep, self.timeout).await
+    }
+
+    /// Tweak the properties of the local publisher
+    pub async fn configure(
+        &self,
+        params: VideoRoomPublisherConfigureParams,
+    ) -> Result<(), jarust_interface::Error> {
+        self.publisher
+            .configure_publisher(params, self.timeout)
+            .await
+    }
+
+    /// Stop publishing, while staying in the room
+    pub async fn unpublish(&self) -> Result<(), jarust_interface::Error> {
+        self.publisher.unpublish(self.timeout).await
+    }
+
+    /// Answer the last [`ConferenceEvent::SubscriberOffer`]
+    pub async fn answer(&self, jsep: Jsep) -> Result<(), jarust_interface::Error> {
+        self.subscriber.start(jsep, self.timeout).await
+    }
+
+    /// Leave the room, tearing down both the publisher and the subscriber
+    pub async fn leave(&self) -> Result<(), jarust_interface::Error> {
+        let detached = self.state.lock().await.subscriber == SubscriberState::Detached;
+        if !detached {
+            self.subscriber.leave(self.timeout).await?;
+        }
+        self.publisher.leave(self.timeout).await
+    }
+
+    /// Unique ID of the local participant, available once [`ConferenceEvent::Joined`] is received
+    pub async fn id(&self) -> Option<JanusId> {
+        self.state.lock().await.id.clone()
+    }
+
+    /// Snapshot of the remote participants currently in the room
+    pub async fn participants(&self) -> Vec<ConferenceParticipant> {
+        self.state
+            .lock()
+            .await
+            .participants
+            .values()
+            .cloned()
+            .collect()
+    }
+
+    /// Underlying publisher handle
+    pub fn publisher(&self) -> &VideoRoomHandle {
+        &self.publisher
+    }
+
+    /// Underlying subscriber handle
+    pub fn subscriber(&self) -> &VideoRoomHandle {
+        &self.subscriber
+    }
+
+    async fn listen(
+        source: EventSource,
+        mut events: mpsc::UnboundedReceiver<PluginEvent>,
+        subscriber: Arc<VideoRoomHandle>,
+        state: Arc<Mutex<ConferenceState>>,
+        tx: mpsc::UnboundedSender<ConferenceEvent>,
+        timeout: Duration,
+    ) {
+        while let Some(event) = events.recv().await {
+            let PluginEvent::VideoRoomEvent(event) = event else {
+                continue;
+            };
+            let (conference_events, action) = {
+                let mut state = state.lock().await;
+                match source {
+                    EventSource::Publisher => state.on_publisher_event(event),
+                    EventSource::Subscriber => state.on_subscriber_event(event),
+                }
+            };
+            for conference_event in conference_events {
+                let _ = tx.send(conference_event);
+            }
+            if let Some(action) = action {
+                let result = match action.clone() {
+                    SubscriptionAction::Join {
+                        room,
+                        private_id,
+                        streams,
+                    } => {
+                        let params = VideoRoomSubscriberJoinParams {
+                            room,
+                            optional: VideoRoomSubscriberJoinParamsOptional {
+                                private_id,
+                                autoupdate: Some(true),
+                                streams: Some(streams),
+                                ..Default::default()
+                            },
+                        };
+                        subscriber.join_as_subscriber(params, None, timeout).await
+                    }
+                    SubscriptionAction::Subscribe { streams } => {
+                        subscriber
+                            .subscribe(VideoRoomSubscribeParams { streams }, timeout)
+                            .await
+                    }
+                };
+                if let Err(error) = result {
+                    tracing::error!("Failed to update the conference subscription: {error}");
+                    state.lock().await.on_subscription_failed(&action);
+                    let _ = tx.send(ConferenceEvent::SubscriptionFailed {
+                        reason: error.to_string(),
+                    });
+                }
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+enum EventSource {
+    Publisher,
+    Subscriber,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+enum SubscriberState {
+    #[default]
+    Detached,
+    Joining,
+    Attached,
+}
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+enum SubscriptionAction {
+    Join {
+        room: JanusId,
+        private_id: Option<u32>,
+        streams: Vec<VideoRoomSubscriberJoinStream>,
+    },
+    Subscribe {
+        streams: Vec<VideoRoomSubscriberJoinStream>,
+    },
+}
+
+/// Roster and subscription bookkeeping, kept free of any I/O
+#[derive(Debug)]
+struct ConferenceState {
+    room: JanusId,
+    id: Option<JanusId>,
+    private_id: Option<u64>,
+    participants: BTreeMap<JanusId, ConferenceParticipant>,
+    subscriber: SubscriberState,
+    /// (feed, mid) pairs the subscriber is subscribed to, or about to be
+    subscriptions: BTreeSet<(JanusId, String)>,
+    /// streams waiting for the subscriber to be attached
+    pending: Vec<VideoRoomSubscriberJoinStream>,
+    /// streams of the subscribe requests janus hasn't confirmed yet
+    in_flight: Vec<VideoRoomSubscriberJoinStream>,
+}
+
+type Reaction = (Vec<ConferenceEvent>, Option<SubscriptionAction>);
+
+impl ConferenceState {
+    fn new(room: JanusId) -> Self {
+        Self {
+            room,
+            id: None,
+            private_id: None,
+            participants: BTreeMap::new(),
+            subscriber: SubscriberState::Detached,
+            subscriptions: BTreeSet::new(),
+            pending: Vec::new(),
+            in_flight: Vec::new(),
+        }
+    }
+
+    fn on_publisher_event(&mut self, event: VideoRoomEvent) -> Reaction {
+        let mut events = Vec::new();
+        let mut streams = Vec::new();
+        match event {
+            VideoRoomEvent::RoomJoined {
+                id,
+                room,
+                private_id,
+                publishers,
+                ..
+            } => {
+                self.on_joined(id, room, private_id, &mut events);
+                self.on_publishers(publishers, &mut events, &mut streams);
+            }
+            VideoRoomEvent::RoomJoinedWithJsep {
+                id,
+                room,
+                private_id,
+                publishers,
+                jsep,
+                ..
+            } => {
+                self.on_joined(id, room, private_id, &mut events);
+                events.push(ConferenceEvent::Configured { jsep: Some(jsep) });
+                self.on_publishers(publishers, &mut events, &mut streams);
+            }
+            VideoRoomEvent::NewPublisher { publishers, .. } => {
+                self.on_publishers(publishers, &mut events, &mut streams);
+            }
+            VideoRoomEvent::Configured { .. } => {
+                events.push(ConferenceEvent::Configured { jsep: None });
+            }
+            VideoRoomEvent::ConfiguredWithJsep { jsep, .. } => {
+                events.push(ConferenceEvent::Configured { jsep: Some(jsep) });
+            }
+            VideoRoomEvent::ParticipantJoining { id, display, .. }
+                if !self.is_local(&id) && !self.participants.contains_key(&id) =>
+            {
+                let participant = ConferenceParticipant {
+                    id: id.clone(),
+                    display,
+                    streams: vec![],
+                };
+                self.participants.insert(id, participant.clone());
+                events.push(ConferenceEvent::ParticipantJoined(participant));
+            }
+            VideoRoomEvent::Unpublished { id, .. } => {
+                if let Some(participant) = self.participants.get_mut(&id) {
+                    participant.streams.clear();
+                    self.subscriptions.retain(|(feed, _)| feed != &id);
+                    events.push(ConferenceEvent::ParticipantUnpublished { id });
+                }
+            }
+            VideoRoomEvent::ParticipantLeft { id, .. }
+            | VideoRoomEvent::Kicked {
+                participant: id, ..
+            } if self.participants.remove(&id).is_some() => {
+                self.subscriptions.retain(|(feed, _)| feed != &id);
+                events.push(ConferenceEvent::ParticipantLeft { id });
+            }
+            VideoRoomEvent::Leaving { reason, .. } if reason == "kicked" => {
+                events.push(ConferenceEvent::Kicked);
+            }
+            VideoRoomEvent::Talking {
+                id, audio_level, ..
+            } => events.push(ConferenceEvent::Talking { id, audio_level }),
+            VideoRoomEvent::StoppedTalking {
+                id, audio_level, ..
+            } => events.push(ConferenceEvent::StoppedTalking { id, audio_level }),
+            VideoRoomEvent::RoomDestroyed { .. } => events.push(ConferenceEvent::RoomDestroyed),
+            VideoRoomEvent::Error { error_code, error } => {
+                events.push(ConferenceEvent::Error { error_code, error })
+            }
+            _ => {}
+        }
+        let action = self.subscribe_to(streams);
+        (events, action)
+    }
+
+    fn on_subscriber_event(&mut self, event: VideoRoomEvent) -> Reaction {
+        let mut events = Vec::new();
+        let mut action = None;
+        match event {
+            VideoRoomEvent::SubscriberAttachedWithJsep { streams, jsep, .. } => {
+                action = self.on_attached();
+                events.push(ConferenceEvent::SubscriberOffer { streams, jsep });
+            }
+            VideoRoomEvent::SubscriberAttached { streams, .. } => {
+                action = self.on_attached();
+                events.push(ConferenceEvent::SubscriberUpdated { streams });
+            }
+            VideoRoomEvent::SubscriberUpdatedWithJsep { streams, jsep, .. } => {
+                self.in_flight.clear();
+                events.push(ConferenceEvent::SubscriberOffer { streams, jsep });
+            }
+            VideoRoomEvent::SubscriberUpdated { streams, .. } => {
+                self.in_flight.clear();
+                events.push(ConferenceEvent::SubscriberUpdated { streams });
+            }
+            VideoRoomEvent::SubscriberSwitched { streams, .. } => {
+                events.push(ConferenceEvent::SubscriberUpdated { streams });
+            }
+            VideoRoomEvent::Error { error_code, error } => {
+                match self.subscriber {
+                    SubscriberState::Joining => self.on_join_failed(),
+                    // The subscribe request was acked, janus rejects it asynchronously
+                    SubscriberState::Attached => {
+                        let streams = std::mem::take(&mut self.in_flight);
+                        self.forget(&streams);
+                    }
+                    SubscriberState::Detached => {}
+                }
+                events.push(ConferenceEvent::Error { error_code, error });
+            }
+            _ => {}
+        }
+        (events, action)
+    }
+
+    /// Forget about the streams of a failed subscription, so they can be retried later on
+    fn on_subscription_failed(&mut self, action: &SubscriptionAction) {
+        match action {
+            SubscriptionAction::Join { .. } => self.on_join_failed(),
+            SubscriptionAction::Subscribe { streams } => {
+                self.in_flight.retain(|stream| !streams.contains(stream));
+                self.forget(streams);
+            }
+        }
+    }
+
+    /// The next join subscribes to every known stream again, see [`Self::subscribe_to`]
+    fn on_join_failed(&mut self) {
+        if self.subscriber == SubscriberState::Joining {
+            self.subscriber = SubscriberState::Detached;
+            self.subscriptions.clear();
+            self.pending.clear();
+            self.in_flight.clear();
+        }
+    }
+
+    fn forget(&mut self, streams: &[VideoRoomSubscriberJoinStream]) {
+        for stream in streams {
+            if let Some(mid) = &stream.optional.mid {
+                self.subscriptions
+                    .remove(&(stream.feed.clone(), mid.clone()));
+            }
+        }
+    }
+
+    fn on_joined(
+        &mut self,
+        id: JanusId,
+        room: JanusId,
+        private_id: u64,
+        events: &mut Vec<ConferenceEvent>,
+    ) {
+        self.id = Some(id.clone());
+        self.private_id = Some(private_id);
+        events.push(ConferenceEvent::Joined {
+            room,
+            id,
+            private_id,
+        });
+    }
+
+    fn on_publishers(
+        &mut self,
+        publishers: Vec<Publisher>,
+        events: &mut Vec<ConferenceEvent>,
+        streams: &mut Vec<VideoRoomSubscriberJoinStream>,
+    ) {
+        for publisher in publishers {
+            if self.is_local(&publisher.id) {
+                continue;
+            }
+            if !self.participants.contains_key(&publisher.id) {
+                events.push(ConferenceEvent::ParticipantJoined(ConferenceParticipant {
+                    id: publisher.id.clone(),
+                    display: publisher.display.clone(),
+                    streams: vec![],
+                }));
+            }
+            for stream in publisher.streams.iter().filter(|s| !s.disabled) {
+                if self
+                    .subscriptions
+                    .insert((publisher.id.clone(), stream.mid.clone()))
+                {
+                    streams.push(VideoRoomSubscriberJoinStream {
+                        feed: publisher.id.clone(),
+                        optional: VideoRoomSubscriberJoinStreamOptional {
+                            mid: Some(stream.mid.clone()),
+                            ..Default::default()
+                        },
+                    });
+                }
+            }
+            let participant = ConferenceParticipant {
+                id: publisher.id.clone(),
+                display: publisher.display,
+                streams: publisher.streams,
+            };
+            self.participants.insert(publisher.id, participant.clone());
+            events.push(ConferenceEvent::ParticipantPublished(participant));
+        }
+    }
+
+    fn on_attached(&mut self) -> Option<SubscriptionAction> {
+        self.subscriber = SubscriberState::Attached;
+        let streams = std::mem::take(&mut self.pending);
+        self.subscribe_to(streams)
+    }
+
+    fn subscribe_to(
+        &mut self,
+        streams: Vec<VideoRoomSubscriberJoinStream>,
+    ) -> Option<SubscriptionAction> {
+        if streams.is_empty() {
+            return None;
+        }
+        match self.subscriber {
+            SubscriberState::Detached => {
+                self.subscriber = SubscriberState::Joining;
+                // Join with every stream of the roster, not only the new ones, as a previous join may have failed
+                let streams = self.roster_streams();
+                Some(SubscriptionAction::Join {
+                    room: self.room.clone(),
+                    private_id: self.private_id.and_then(|id| u32::try_from(id).ok()),
+                    streams,
+                })
+            }
+            SubscriberState::Joining => {
+                self.pending.extend(streams);
+                None
+            }
+            SubscriberState::Attached => {
+                self.in_flight.extend(streams.iter().cloned());
+                Some(SubscriptionAction::Subscribe { streams })
+            }
+        }
+    }
+
+    /// Every enabled stream of the remote participants, marked as subscribed
+    fn roster_streams(&mut self) -> Vec<VideoRoomSubscriberJoinStream> {
+        let mut streams = Vec::new();
+        for participant in self.participants.values() {
+            for stream in participant.streams.iter().filter(|s| !s.disabled) {
+                self.subscriptions
+                    .insert((participant.id.clone(), stream.mid.clone()));
+                streams.push(VideoRoomSubscriberJoinStream {
+                    feed: participant.id.clone(),
+                    optional: VideoRoomSubscriberJoinStreamOptional {
+                        mid: Some(stream.mid.clone()),
+                        ..Default::default()
+                    },
+                });
+            }
+        }
+        streams
+    }
+
+    fn is_local(&self, id: &JanusId) -> bool {
+        self.id.as_ref() == Some(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use jarust_interface::japrotocol::JsepType;
+
+    fn room() -> JanusId {
+        JanusId::Uint(1234.into())
+    }
+
+    fn publisher(id: u64, mids: &[&str]) -> Publisher {
+        Publisher {
+            id: JanusId::Uint(id.into()),
+            display: Some(format!("publisher-{id}")),
+            streams: mids
+                .iter()
+                .enumerate()
+                .map(|(mindex, mid)| Stream {
+                    media_type: "video".to_string(),
+                    mindex: mindex as u64,
+                    mid: mid.to_string(),
+                    disabled: false,
+                    codec: Some("vp8".to_string()),
+                    description: None,
+                    moderated: None,
+                    simulcast: None,
+                    svc: None,
+                    talking: None,
+                })
+                .collect(),
+        }
+    }
+
+    fn join_stream(feed: u64, mid: &str) -> VideoRoomSubscriberJoinStream {
+        VideoRoomSubscriberJoinStream {
+            feed: JanusId::Uint(feed.into()),
+            optional: VideoRoomSubscriberJoinStreamOptional {
+                mid: Some(mid.to_string()),
+                ..Default::default()
+            },
+        }
+    }
+
+    fn joined(publishers: Vec<Publisher>) -> VideoRoomEvent {
+        VideoRoomEvent::RoomJoined {
+            id: JanusId::Uint(1.into()),
+            room: room(),
+            description: None,
+            private_id: 42,
+            publishers,
+        }
+    }
+
+    fn offer() -> Jsep {
+        Jsep {
+            jsep_type: JsepType::Offer,
+            trickle: None,
+            sdp: "test_sdp".to_string(),
+        }
+    }
+
+    #[test]
+    fn it_joins_subscriber_with_existing_publishers() {
+        let mut state = ConferenceState::new(room());
+        let (events, action) = state.on_publisher_event(joined(vec![publisher(2, &["0", "1"])]));
+
+        assert_eq!(
+            events[0],
+            ConferenceEvent::Joined {
+                room: room(),
+                id: JanusId::Uint(1.into()),
+                private_id: 42
+            }
+        );
+        assert!(matches!(events[1], ConferenceEvent::ParticipantJoined(_)));
+        assert!(matches!(
+            events[2],
+            ConferenceEvent::ParticipantPublished(_)
+        ));
+        assert_eq!(
+            action,
+            Some(SubscriptionAction::Join {
+                room: room(),
+                private_id: Some(42),
+                streams: vec![join_stream(2, "0"), join_stream(2, "1")]
+            })
+        );
+    }
+
+    #[test]
+    fn it_does_not_subscribe_when_nobody_publishes() {
+        let mut state = ConferenceState::new(room());
+        let (_, action) = state.on_publisher_event(joined(vec![]));
+
+        assert_eq!(action, None);
+        assert_eq!(state.subscriber, SubscriberState::Detached);
+    }
+
+    #[test]
+    fn it_queues_new_streams_while_subscriber_is_joining() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+
+        let (_, action) = state.on_publisher_event(VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(3, &["0"])],
+        });
+        assert_eq!(action, None);
+
+        let (events, action) =
+            state.on_subscriber_event(VideoRoomEvent::SubscriberAttachedWithJsep {
+                room: room(),
+                streams: vec![],
+                jsep: offer(),
+            });
+        assert_eq!(
+            events,
+            vec![ConferenceEvent::SubscriberOffer {
+                streams: vec![],
+                jsep: offer()
+            }]
+        );
+        assert_eq!(
+            action,
+            Some(SubscriptionAction::Subscribe {
+                streams: vec![join_stream(3, "0")]
+            })
+        );
+    }
+
+    #[test]
+    fn it_only_subscribes_to_new_streams() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+        state.on_subscriber_event(VideoRoomEvent::SubscriberAttachedWithJsep {
+            room: room(),
+            streams: vec![],
+            jsep: offer(),
+        });
+
+        let (events, action) = state.on_publisher_event(VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(2, &["0", "1"])],
+        });
+        assert!(matches!(
+            events.as_slice(),
+            [ConferenceEvent::ParticipantPublished(_)]
+        ));
+        assert_eq!(
+            action,
+            Some(SubscriptionAction::Subscribe {
+                streams: vec![join_stream(2, "1")]
+            })
+        );
+    }
+
+    #[test]
+    fn it_ignores_the_local_publisher() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![]));
+
+        let (events, action) = state.on_publisher_event(VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(1, &["0"])],
+        });
+        assert_eq!(events, vec![]);
+        assert_eq!(action, None);
+    }
+
+    #[test]
+    fn it_tracks_unpublished_and_left_participants() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+
+        let (events, _) = state.on_publisher_event(VideoRoomEvent::Unpublished {
+            room: room(),
+            id: JanusId::Uint(2.into()),
+        });
+        assert_eq!(
+            events,
+            vec![ConferenceEvent::ParticipantUnpublished {
+                id: JanusId::Uint(2.into())
+            }]
+        );
+        assert!(state.subscriptions.is_empty());
+        assert!(state.participants[&JanusId::Uint(2.into())]
+            .streams
+            .is_empty());
+
+        let (events, _) = state.on_publisher_event(VideoRoomEvent::ParticipantLeft {
+            room: room(),
+            id: JanusId::Uint(2.into()),
+        });
+        assert_eq!(
+            events,
+            vec![ConferenceEvent::ParticipantLeft {
+                id: JanusId::Uint(2.into())
+            }]
+        );
+        assert!(state.participants.is_empty());
+    }
+
+    #[test]
+    fn it_retries_joining_after_a_failed_subscription() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+
+        let (events, _) = state.on_subscriber_event(VideoRoomEvent::Error {
+            error_code: 428,
+            error: "No such feed".to_string(),
+        });
+        assert_eq!(
+            events,
+            vec![ConferenceEvent::Error {
+                error_code: 428,
+                error: "No such feed".to_string()
+            }]
+        );
+        assert_eq!(state.subscriber, SubscriberState::Detached);
+
+        let (_, action) = state.on_publisher_event(VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(3, &["0"])],
+        });
+        assert_eq!(
+            action,
+            Some(SubscriptionAction::Join {
+                room: room(),
+                private_id: Some(42),
+                streams: vec![join_stream(2, "0"), join_stream(3, "0")]
+            })
+        );
+    }
+
+    #[test]
+    fn it_retries_streams_after_a_failed_subscribe() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+        state.on_subscriber_event(VideoRoomEvent::SubscriberAttachedWithJsep {
+            room: room(),
+            streams: vec![],
+            jsep: offer(),
+        });
+
+        let new_publisher = VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(3, &["0"])],
+        };
+        let (_, action) = state.on_publisher_event(new_publisher.clone());
+        let action = action.expect("Expected a subscribe action");
+        state.on_subscription_failed(&action);
+        assert_eq!(state.subscriber, SubscriberState::Attached);
+        assert!(!state
+            .subscriptions
+            .contains(&(JanusId::Uint(3.into()), "0".to_string())));
+        assert!(state
+            .subscriptions
+            .contains(&(JanusId::Uint(2.into()), "0".to_string())));
+
+        let (_, action) = state.on_publisher_event(new_publisher);
+        assert_eq!(
+            action,
+            Some(SubscriptionAction::Subscribe {
+                streams: vec![join_stream(3, "0")]
+            })
+        );
+    }
+
+    #[test]
+    fn it_retries_streams_rejected_after_the_ack() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+        state.on_subscriber_event(VideoRoomEvent::SubscriberAttachedWithJsep {
+            room: room(),
+            streams: vec![],
+            jsep: offer(),
+        });
+
+        let new_publisher = VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(3, &["0"])],
+        };
+        state.on_publisher_event(new_publisher.clone());
+        let (events, _) = state.on_subscriber_event(VideoRoomEvent::Error {
+            error_code: 428,
+            error: "No such feed".to_string(),
+        });
+        assert!(matches!(
+            events.as_slice(),
+            [ConferenceEvent::Error {
+                error_code: 428,
+                ..
+            }]
+        ));
+        assert_eq!(state.subscriber, SubscriberState::Attached);
+        assert!(!state
+            .subscriptions
+            .contains(&(JanusId::Uint(3.into()), "0".to_string())));
+        assert!(state
+            .subscriptions
+            .contains(&(JanusId::Uint(2.into()), "0".to_string())));
+
+        let (_, action) = state.on_publisher_event(new_publisher);
+        assert_eq!(
+            action,
+            Some(SubscriptionAction::Subscribe {
+                streams: vec![join_stream(3, "0")]
+            })
+        );
+    }
+
+    #[test]
+    fn it_keeps_confirmed_streams_on_a_later_error() {
+        let mut state = ConferenceState::new(room());
+        state.on_publisher_event(joined(vec![publisher(2, &["0"])]));
+        state.on_subscriber_event(VideoRoomEvent::SubscriberAttachedWithJsep {
+            room: room(),
+            streams: vec![],
+            jsep: offer(),
+        });
+        state.on_publisher_event(VideoRoomEvent::NewPublisher {
+            room: room(),
+            publishers: vec![publisher(3, &["0"])],
+        });
+        state.on_subscriber_event(VideoRoomEvent::SubscriberUpdatedWithJsep {
+            room: room(),
+            streams: vec![],
+            jsep: offer(),
+        });
+
+        state.on_subscriber_event(VideoRoomEvent::Error {
+            error_code: 424,
+            error: "Join first".to_string(),
+        });
+        assert!(state
+            .subscriptions
+            .contains(&(JanusId::Uint(3.into()), "0".to_string())));
+    }
+}
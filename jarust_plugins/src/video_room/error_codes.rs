@@ -0,0 +1,70 @@
+jarust_interface::error_codes! {
+    /// VideoRoom plugin error codes
+    VideoRoomErrorCode {
+        NoMessage = 421,
+        InvalidJson = 422,
+        InvalidRequest = 423,
+        JoinFirst = 424,
+        AlreadyJoined = 425,
+        NoSuchRoom = 426,
+        RoomExists = 427,
+        NoSuchFeed = 428,
+        MissingElement = 429,
+        InvalidElement = 430,
+        InvalidSdpType = 431,
+        PublishersFull = 432,
+        Unauthorized = 433,
+        AlreadyPublished = 434,
+        NotPublished = 435,
+        IdExists = 436,
+        InvalidSdp = 437,
+        UnknownError = 499,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::VideoRoomErrorCode;
+    use jarust_interface::Error;
+
+    #[test]
+    fn it_extracts_videoroom_error_code() {
+        let error = Error::PluginResponseError {
+            error_code: 426,
+            error: "No such room (1234)".to_string(),
+        };
+        assert_eq!(
+            error.plugin_error_code::<VideoRoomErrorCode>(),
+            Some(VideoRoomErrorCode::NoSuchRoom)
+        );
+    }
+
+    #[test]
+    fn it_matches_the_janus_error_codes() {
+        // From janus_videoroom.c
+        let codes = [
+            (VideoRoomErrorCode::NoMessage, 421),
+            (VideoRoomErrorCode::InvalidJson, 422),
+            (VideoRoomErrorCode::InvalidRequest, 423),
+            (VideoRoomErrorCode::JoinFirst, 424),
+            (VideoRoomErrorCode::AlreadyJoined, 425),
+            (VideoRoomErrorCode::NoSuchRoom, 426),
+            (VideoRoomErrorCode::RoomExists, 427),
+            (VideoRoomErrorCode::NoSuchFeed, 428),
+            (VideoRoomErrorCode::MissingElement, 429),
+            (VideoRoomErrorCode::InvalidElement, 430),
+            (VideoRoomErrorCode::InvalidSdpType, 431),
+            (VideoRoomErrorCode::PublishersFull, 432),
+            (VideoRoomErrorCode::Unauthorized, 433),
+            (VideoRoomErrorCode::AlreadyPublished, 434),
+            (VideoRoomErrorCode::NotPublished, 435),
+            (VideoRoomErrorCode::IdExists, 436),
+            (VideoRoomErrorCode::InvalidSdp, 437),
+            (VideoRoomErrorCode::UnknownError, 499),
+        ];
+        for (variant, code) in codes {
+            assert_eq!(variant.code(), code);
+            assert_eq!(VideoRoomErrorCode::from(code), variant);
+        }
+    }
+}
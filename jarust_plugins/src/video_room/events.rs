@@ -90,6 +90,18 @@ enum VideoRoomEventEventType {
         leaving: String,
         reason: String,
     },
+    LeavingRsp {
+        room: JanusId,
+        leaving: LeavingOk,
+    },
+    ParticipantLeaving {
+        room: JanusId,
+        leaving: JanusId,
+    },
+    ParticipantJoining {
+        room: JanusId,
+        joining: Attendee,
+    },
     Kicked {
         kicked: JanusId,
         room: JanusId,
@@ -113,6 +125,13 @@ enum VideoRoomEventEventType {
     },
 }
 
+/// The `"ok"` janus replies with when a publisher leaves on its own
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+enum LeavingOk {
+    #[serde(rename = "ok")]
+    Ok,
+}
+
 #[derive(Clone, PartialEq, Eq, Hash, Debug)]
 pub enum PluginEvent {
     VideoRoomEvent(VideoRoomEvent),
@@ -157,6 +176,20 @@ pub enum VideoRoomEvent {
         room: JanusId,
         reason: String,
     },
+    /// Sent to all participants when a participant leaves the room
+    ParticipantLeft {
+        room: JanusId,
+        /// unique ID of the participant that left
+        id: JanusId,
+    },
+    /// Sent to all participants when a new participant joins the room, if the room was configured with `notify_joining`
+    ParticipantJoining {
+        room: JanusId,
+        /// unique ID of the participant that joined
+        id: JanusId,
+        /// display name of the participant that joined, if any
+        display: Option<String>,
+    },
     Kicked {
         room: JanusId,
         participant: JanusId,
@@ -176,6 +209,12 @@ pub enum VideoRoomEvent {
         room: JanusId,
         streams: Vec<AttachedStream>,
     },
+    /// Sent back to a subscriber session when its subscription changed and a renegotiation is needed
+    SubscriberUpdatedWithJsep {
+        room: JanusId,
+        streams: Vec<AttachedStream>,
+        jsep: Jsep,
+    },
     SubscriberSwitched {
         room: JanusId,
         changes: i64,
@@ -307,7 +346,15 @@ impl TryFrom<JaResponse> for PluginEvent {
                                     }
                                 }
                                 EventDto::SubscriberUpdated { room, streams } => {
-                                    VideoRoomEvent::SubscriberUpdated { room, streams }
+                                    if let Some(jsep) = value.jsep {
+                                        VideoRoomEvent::SubscriberUpdatedWithJsep {
+                                            room,
+                                            streams,
+                                            jsep,
+                                        }
+                                    } else {
+                                        VideoRoomEvent::SubscriberUpdated { room, streams }
+                                    }
                                 }
                                 EventDto::Talking {
                                     room,
@@ -366,6 +413,19 @@ impl TryFrom<JaResponse> for PluginEvent {
                                 EventDto::Event(Event::Leaving { room, reason, .. }) => {
                                     VideoRoomEvent::Leaving { room, reason }
                                 }
+                                EventDto::Event(Event::LeavingRsp { room, .. }) => {
+                                    VideoRoomEvent::LeftAsyncRsp { room }
+                                }
+                                EventDto::Event(Event::ParticipantLeaving { room, leaving }) => {
+                                    VideoRoomEvent::ParticipantLeft { room, id: leaving }
+                                }
+                                EventDto::Event(Event::ParticipantJoining { room, joining }) => {
+                                    VideoRoomEvent::ParticipantJoining {
+                                        room,
+                                        id: joining.id,
+                                        display: joining.display,
+                                    }
+                                }
                                 EventDto::Event(Event::Kicked { kicked, room }) => {
                                     VideoRoomEvent::Kicked {
                                         room,
@@ -553,6 +613,158 @@ mod tests {
         )
     }
 
+    #[test]
+    fn it_parse_participant_left() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videoroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videoroom": "event",
+                        "room": 8146468u64,
+                        "leaving": 1337
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoRoomEvent(VideoRoomEvent::ParticipantLeft {
+                room: JanusId::Uint(8146468.into()),
+                id: JanusId::Uint(1337.into()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_participant_left_with_string_id() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videoroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videoroom": "event",
+                        "room": "room-id",
+                        "leaving": "participant-id"
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoRoomEvent(VideoRoomEvent::ParticipantLeft {
+                room: JanusId::String("room-id".to_string()),
+                id: JanusId::String("participant-id".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_publisher_left_rsp() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videoroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videoroom": "event",
+                        "room": 8146468u64,
+                        "leaving": "ok"
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoRoomEvent(VideoRoomEvent::LeftAsyncRsp {
+                room: JanusId::Uint(8146468.into()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_participant_joining() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videoroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videoroom": "event",
+                        "room": 8146468u64,
+                        "joining": {
+                            "id": 1337,
+                            "display": "Alice"
+                        }
+                    })),
+                },
+            }),
+            jsep: None,
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoRoomEvent(VideoRoomEvent::ParticipantJoining {
+                room: JanusId::Uint(8146468.into()),
+                id: JanusId::Uint(1337.into()),
+                display: Some("Alice".to_string()),
+            })
+        );
+    }
+
+    #[test]
+    fn it_parse_subscriber_updated_with_jsep() {
+        let rsp = JaResponse {
+            janus: ResponseType::Event(JaHandleEvent::PluginEvent {
+                plugin_data: PluginData {
+                    plugin: "janus.plugin.videoroom".to_string(),
+                    data: PluginInnerData::Data(json!({
+                        "videoroom": "updated",
+                        "room": 8146468u64,
+                        "streams": []
+                    })),
+                },
+            }),
+            jsep: Some(Jsep {
+                jsep_type: JsepType::Offer,
+                trickle: Some(false),
+                sdp: "test_sdp".to_string(),
+            }),
+            transaction: None,
+            session_id: None,
+            sender: None,
+        };
+        let event: PluginEvent = rsp.try_into().unwrap();
+        assert_eq!(
+            event,
+            PluginEvent::VideoRoomEvent(VideoRoomEvent::SubscriberUpdatedWithJsep {
+                room: JanusId::Uint(8146468.into()),
+                streams: vec![],
+                jsep: Jsep {
+                    jsep_type: JsepType::Offer,
+                    trickle: Some(false),
+                    sdp: "test_sdp".to_string(),
+                },
+            })
+        );
+    }
+
     #[test]
     fn it_parse_error() {
         let rsp = JaResponse {
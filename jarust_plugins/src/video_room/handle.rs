@@ -128,7 +128,6 @@ impl VideoRoomHandle {
     }
 
     /// Allows you to edit who's allowed to join a room via ad-hoc tokens
-    #[cfg(feature = "__experimental")]
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn allowed(
         &self,
@@ -155,7 +154,6 @@ impl VideoRoomHandle {
     }
 
     /// Kicks a participants out of a room
-    #[cfg(feature = "__experimental")]
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn kick(
         &self,
@@ -166,11 +164,13 @@ impl VideoRoomHandle {
         let mut message: Value = params.try_into()?;
         message["request"] = "kick".into();
 
-        self.handle.send_waiton_rsp::<()>(message, timeout).await
+        self.handle
+            .send_waiton_rsp::<VideoRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
     }
 
     /// Enable or disable recording on all participants while the conference is in progress
-    #[cfg(feature = "__experimental")]
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn enable_recording(
         &self,
@@ -181,11 +181,13 @@ impl VideoRoomHandle {
         let mut message: Value = params.try_into()?;
         message["request"] = "enable_recording".into();
 
-        self.handle.send_waiton_rsp::<()>(message, timeout).await
+        self.handle
+            .send_waiton_rsp::<VideoRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
     }
 
     /// Get a list of the participants in a specific room
-    #[cfg(feature = "__experimental")]
     #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn list_participants(
         &self,
@@ -200,39 +202,48 @@ impl VideoRoomHandle {
             .await
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Mute or unmute a specific stream of a publisher, forcing the plugin to stop relaying it
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn moderate(
         &self,
         params: VideoRoomModerateParams,
         timeout: Duration,
     ) -> Result<(), jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        tracing::info!(plugin = "videoroom", "Sending moderate");
+        let mut message: Value = params.try_into()?;
         message["request"] = "moderate".into();
 
-        self.handle.send_waiton_rsp::<()>(message, timeout).await
+        self.handle
+            .send_waiton_rsp::<VideoRoomSuccessRsp>(message, timeout)
+            .await?;
+        Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Get a list of all the RTP forwarders in a room
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn list_forwarders(
         &self,
         params: VideoRoomListForwardersParams,
         timeout: Duration,
     ) -> Result<VideoRoomListForwardersRsp, jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
-        message["request"] = "list_forwarders".into();
+        tracing::info!(plugin = "videoroom", "Sending list forwarders");
+        let mut message: Value = params.try_into()?;
+        message["request"] = "listforwarders".into();
 
         self.handle
             .send_waiton_rsp::<VideoRoomListForwardersRsp>(message, timeout)
             .await
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Forward the streams of a publisher to a remote RTP receiver
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn rtp_forward(
         &self,
         params: VideoRoomRtpForwardParams,
         timeout: Duration,
     ) -> Result<VideoRoomRtpForwardRsp, jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        tracing::info!(plugin = "videoroom", "Sending rtp forward");
+        let mut message: Value = params.try_into()?;
         message["request"] = "rtp_forward".into();
 
         self.handle
@@ -240,13 +251,15 @@ impl VideoRoomHandle {
             .await
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Stop a previously created RTP forwarder
+    #[tracing::instrument(level = tracing::Level::DEBUG, skip_all)]
     pub async fn stop_rtp_forward(
         &self,
         params: VideoRoomStopRtpForward,
         timeout: Duration,
     ) -> Result<VideoRoomStopRtpForwardRsp, jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        tracing::info!(plugin = "videoroom", "Sending stop rtp forward");
+        let mut message: Value = params.try_into()?;
         message["request"] = "stop_rtp_forward".into();
         self.handle
             .send_waiton_rsp::<VideoRoomStopRtpForwardRsp>(message, timeout)
@@ -301,7 +314,6 @@ impl VideoRoomHandle {
     /// can be removed/updated accordingly as well. As such, these subscriber sessions are dependent on feedback
     /// obtained by publishers, and can't exist on their own, unless you feed them the right info out of band
     /// (which is impossible in rooms configured with require_pvtid).
-    #[cfg(feature = "__experimental")]
     pub async fn join_as_subscriber(
         &self,
         params: VideoRoomSubscriberJoinParams,
@@ -341,7 +353,6 @@ impl VideoRoomHandle {
 
     /// This request allows subscribers to dynamically change some properties associated to their media subscription,
     /// e.g., in terms of what should and should not be sent at a specific time.
-    #[cfg(feature = "__experimental")]
     pub async fn configure_subscriber(
         &self,
         params: VideoRoomConfigureSubscriberParams,
@@ -379,7 +390,6 @@ impl VideoRoomHandle {
     /// The plugin will match it to the room configuration (e.g., to make sure the codecs you negotiated are allowed in the room),
     /// and will reply with a JSEP SDP answer to close the circle and complete the setup of the PeerConnection.
     /// As soon as the PeerConnection has been established, the publisher will become active, and a new active feed other participants can subscribe to.
-    #[cfg(feature = "__experimental")]
     pub async fn publish(
         &self,
         params: VideoRoomPublishParams,
@@ -397,7 +407,6 @@ impl VideoRoomHandle {
     /// Stop publishing and tear down the related PeerConnection
     ///
     /// This request requires no arguments as the context is implicit.
-    #[cfg(feature = "__experimental")]
     pub async fn unpublish(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
         self.handle
             .send_waiton_ack(json!({"request": "unpublish"}), timeout)
@@ -420,43 +429,43 @@ impl VideoRoomHandle {
         Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Add new streams to an existing subscription, triggering a renegotiation
     pub async fn subscribe(
         &self,
         params: VideoRoomSubscribeParams,
         timeout: Duration,
     ) -> Result<(), jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        let mut message: Value = params.try_into()?;
         message["request"] = "subscribe".into();
         self.handle.send_waiton_ack(message, timeout).await?;
         Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Remove streams from an existing subscription, triggering a renegotiation
     pub async fn unsubscribe(
         &self,
         params: VideoRoomUnsubscribeParams,
         timeout: Duration,
     ) -> Result<(), jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        let mut message: Value = params.try_into()?;
         message["request"] = "unsubscribe".into();
         self.handle.send_waiton_ack(message, timeout).await?;
         Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Subscribe to and unsubscribe from streams in a single request, resulting in a single renegotiation
     pub async fn update(
         &self,
         params: VideoRoomCombinedUpdateParams,
         timeout: Duration,
     ) -> Result<(), jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        let mut message: Value = params.try_into()?;
         message["request"] = "update".into();
         self.handle.send_waiton_ack(message, timeout).await?;
         Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Temporarily pause the delivery of media to a subscriber, resumed with [`VideoRoomHandle::start`]
     pub async fn pause(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
         self.handle
             .send_waiton_ack(json!({"request": "pause"}), timeout)
@@ -464,19 +473,19 @@ impl VideoRoomHandle {
         Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Change the source of one or more subscription streams, without renegotiating the PeerConnection
     pub async fn switch(
         &self,
         params: VideoRoomSwitchParams,
         timeout: Duration,
     ) -> Result<(), jarust_interface::Error> {
-        let mut message = serde_json::to_value(params)?;
+        let mut message: Value = params.try_into()?;
         message["request"] = "switch".into();
         self.handle.send_waiton_ack(message, timeout).await?;
         Ok(())
     }
 
-    #[cfg(feature = "__experimental")]
+    /// Leave the room, as either a publisher or a subscriber
     pub async fn leave(&self, timeout: Duration) -> Result<(), jarust_interface::Error> {
         self.handle
             .send_waiton_ack(json!({"request": "leave"}), timeout)
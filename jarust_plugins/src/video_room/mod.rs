@@ -1,3 +1,5 @@
+pub mod conference;
+pub mod error_codes;
 pub mod events;
 pub mod handle;
 pub mod jahandle_ext;
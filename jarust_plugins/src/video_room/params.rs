@@ -244,6 +244,8 @@ make_dto!(
     VideoRoomKickParams,
     required {
         room: JanusId,
+        /// unique ID of the participant to kick
+        #[serde(rename = "id")]
         participant: JanusId
     },
     optional {
@@ -256,8 +258,13 @@ make_dto!(
     VideoRoomModerateParams,
     required {
         room: JanusId,
+        /// unique ID of the participant to moderate
+        #[serde(rename = "id")]
         participant: JanusId,
-        m_line: u64
+        /// unique mid of the stream to moderate
+        mid: String,
+        /// whether the stream should be muted (true) or unmuted (false)
+        mute: bool
     },
     optional {
         /// room secret, mandatory if configured
@@ -348,7 +355,7 @@ make_dto!(
         /// unique mid of the publisher stream to subscribe to
         mid: String,
         /// id to map this subscription with entries in streams list
-        crossrefid: u64,
+        crossrefid: String,
 
         send: bool,
         substream: u64,
@@ -367,8 +374,8 @@ make_dto!(
         feed: JanusId,
         /// unique mid of the publisher stream to subscribe to
         mid: String,
-        /// id to map this subscription with entries in streams list
-        sub_mid: u64
+        /// unique mid of the subscription stream to remove
+        sub_mid: String
     }
 );
 
@@ -397,6 +404,12 @@ make_dto!(
         room: JanusId,
         publisher_id: JanusId,
         stream_id: u64,
+    },
+    optional {
+        /// room secret, mandatory if configured
+        secret: String,
+        /// If `lock_rtp_forward` is set in the plugin settings, the `admin_key` (also configured in plugin settings) has to be supplied with RTP forwarding requests
+        admin_key: String
     }
 );
 
@@ -411,7 +424,11 @@ make_dto!(VideoRoomUnsubscribeParams, required { streams: Vec<VideoRoomSubscribe
 make_dto!(
     VideoRoomCombinedUpdateParams,
     required {
+        /// list of streams to subscribe to
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         subscribe: Vec<VideoRoomSubscriberJoinStream>,
+        /// list of streams to unsubscribe from
+        #[serde(skip_serializing_if = "Vec::is_empty")]
         unsubscribe: Vec<VideoRoomSubscriberUnsubscribeStream>
     }
 );
@@ -498,7 +515,7 @@ make_dto!(
         /// How much time (in us, default 250000) without receiving packets will make us drop to the substream below
         fallback: u64,
         /// spatial layer to receive (0-2), in case SVC is enabled
-        spacial_layer: u64,
+        spatial_layer: u64,
         /// temporal layers to receive (0-2), in case SVC is enabled
         temporal_layer: u64,
         /// if provided, overrides the room `audio_level_average` for this user
@@ -538,15 +555,16 @@ make_dto!(
         publisher_id: JanusId,
         /// host address to forward the RTP and data packets to
         host: String,
-        /// ipv4|ipv6, if we need to resolve the host address to an IP; by default, whatever we get
+        /// list of publisher streams to forward
         #[serde(skip_serializing_if = "Vec::is_empty")]
         streams: Vec<VideoRoomRtpForwardStreamParams>
     },
     optional {
+        /// room secret, mandatory if configured
         secret: String,
         /// If `lock_rtp_forward` is set in the plugin settings, the `admin_key` (also configured in plugin settings) has to be supplied with RTP forwarding requests
         admin_key: String,
-        /// length of authentication tag (32 or 80)
+        /// ipv4|ipv6, if we need to resolve the host address to an IP; by default, whatever we get
         host_family: String,
         /// length of authentication tag (32 or 80)
         srtp_suite: u16,
@@ -564,16 +582,16 @@ make_dto!(
         port: u16
     },
     optional {
-        secret: String,
         /// host address to forward the packets to, will use global one if missing
         host: String,
+        /// ipv4|ipv6, if we need to resolve the host address to an IP; by default, whatever we get
         host_family: String,
         /// port to contact to receive RTCP feedback from the recipient, and only for RTP streams, not data
-        rtcp_port: String,
+        rtcp_port: u16,
         /// SSRC to use when forwarding, and only for RTP streams, not data
-        ssrc: String,
+        ssrc: u32,
         /// payload type to use when forwarding, and only for RTP streams, not data
-        pt: String,
+        pt: u8,
         /// set to true if the source is simulcast and you want the forwarder to act as a regular viewer
         /// (single stream being forwarded) or false otherwise (substreams forwarded separately), default=false
         simulcast: bool,
@@ -582,14 +600,14 @@ make_dto!(
         /// if video and simulcasting, port to forward the packets from the second substream/layer to
         port_2: u16,
         /// if video and simulcasting, SSRC to use the second substream/layer
-        ssrc_2: String,
+        ssrc_2: u32,
         /// if video and simulcasting, payload type to use the second substream/layer
-        pt_2: String,
+        pt_2: u8,
         /// if video and simulcasting, port to forward the packets from the third substream/layer to
         port_3: u16,
         /// if video and simulcasting, SSRC to use the third substream/layer
-        ssrc_3: String,
+        ssrc_3: u32,
         /// if video and simulcasting, payload type to use the third substream/layer
-        pt_3: String
+        pt_3: u8
     }
 );
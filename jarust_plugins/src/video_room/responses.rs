@@ -77,6 +77,9 @@ pub struct Publisher {
     pub id: JanusId,
     /// display name of active publisher
     pub display: Option<String>,
+    /// streams published by this publisher
+    #[serde(default)]
+    pub streams: Vec<Stream>,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
@@ -89,10 +92,11 @@ pub struct Stream {
     pub mindex: u64,
 
     /// unique mid of published stream
-    pub mid: u64,
+    pub mid: String,
 
     /// if true, it means this stream is currently inactive/disabled
     /// (and so codec, description, etc. will be missing)
+    #[serde(default)]
     pub disabled: bool,
 
     /// codec used for published stream
@@ -143,15 +147,15 @@ pub struct RtpForwarderStream {
     /// local port this forwarder is using to get RTCP feedback, if any
     pub local_rtcp_port: Option<u64>,
     /// remote port this forwarder is getting RTCP feedback from, if any
-    pub remote_rtmp_port: Option<u64>,
+    pub remote_rtcp_port: Option<u64>,
     /// SSRC this forwarder is using, same as request if configured
-    pub ssrc: Option<String>,
+    pub ssrc: Option<u32>,
     /// payload type this forwarder is using, same as request if configured
-    pub pt: Option<String>,
+    pub pt: Option<u8>,
     /// video substream this video forwarder is relaying
     pub substream: Option<u64>,
     /// whether the RTP stream is encrypted (not used for data)
-    pub strp: Option<bool>,
+    pub srtp: Option<bool>,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
@@ -299,7 +303,7 @@ pub struct VideoRoomListForwardersRsp {
     pub room: JanusId,
 
     /// Array of publishers with RTP forwarders
-    pub publisher: Vec<RtpForwarderPublisher>,
+    pub publishers: Vec<RtpForwarderPublisher>,
 }
 
 #[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
@@ -325,11 +329,58 @@ pub struct VideoRoomStopRtpForwardRsp {
     pub stream_id: u64,
 }
 
+/// Plain `{"videoroom": "success"}` response, returned by the kick, moderate and enable_recording requests
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug, Deserialize)]
+pub struct VideoRoomSuccessRsp {
+    pub videoroom: String,
+}
+
 #[cfg(test)]
 mod tests {
     use crate::video_room::responses::AttachedStream;
+    use crate::video_room::responses::Publisher;
+    use crate::video_room::responses::Stream;
     use crate::JanusId;
 
+    #[test]
+    fn parse_publisher_with_streams() {
+        let source = "{
+            \"id\": 1337,
+            \"display\": \"Alice\",
+            \"streams\": [
+                {
+                    \"type\": \"video\",
+                    \"mindex\": 0,
+                    \"mid\": \"0\",
+                    \"codec\": \"vp8\",
+                    \"description\": \"front camera\"
+                }
+            ]
+        }";
+
+        let dut: Publisher = serde_json::from_str(source).unwrap();
+
+        assert_eq!(
+            dut,
+            Publisher {
+                id: JanusId::Uint(1337.into()),
+                display: Some("Alice".to_string()),
+                streams: vec![Stream {
+                    media_type: "video".to_string(),
+                    mindex: 0,
+                    mid: "0".to_string(),
+                    disabled: false,
+                    codec: Some("vp8".to_string()),
+                    description: Some("front camera".to_string()),
+                    moderated: None,
+                    simulcast: None,
+                    svc: None,
+                    talking: None,
+                }],
+            }
+        )
+    }
+
     #[test]
     fn parse_attached_stream() {
         let source = "{